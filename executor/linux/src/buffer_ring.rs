@@ -0,0 +1,131 @@
+use crate::{IOURing, Result};
+use uring::{
+    io_uring_buf, io_uring_buf_ring, io_uring_buf_ring_add, io_uring_buf_ring_advance,
+    io_uring_buf_ring_mask, linux::sys::mman::munmap,
+};
+
+/// A ring of buffers provided to the kernel for operations which select their own buffer, such as
+/// multishot receives
+///
+/// Dropping the ring frees its memory, so it must be unregistered with [`BufferRing::unregister`]
+/// or the `io_uring` it was registered with must have exited first.
+pub struct BufferRing {
+    /// The ring shared with the kernel
+    ring: *mut io_uring_buf_ring,
+
+    /// The buffer group ID the ring is registered under
+    group: u16,
+
+    /// The number of buffers in the ring
+    entries: u32,
+
+    /// The size of each buffer
+    buffer_size: usize,
+
+    /// The memory backing the buffers
+    buffers: Box<[u8]>,
+}
+
+impl BufferRing {
+    /// Creates and registers a new [`BufferRing`] with `entries` buffers of `buffer_size` bytes
+    /// under `group`
+    ///
+    /// # Panic
+    /// This function will panic if `entries` is not a power of two or is more than 32768, or if
+    /// `buffer_size` does not fit in a [`u32`].
+    pub(crate) fn new(
+        io_uring: &mut IOURing,
+        group: u16,
+        entries: u32,
+        buffer_size: usize,
+    ) -> Result<Self> {
+        assert!(entries.is_power_of_two() && entries <= 32768);
+        assert!(buffer_size <= u32::MAX as usize);
+
+        let ring = io_uring.setup_buf_ring(entries, group)?;
+        let buffers = vec![0; entries as usize * buffer_size].into_boxed_slice();
+
+        let mut buffer_ring = BufferRing {
+            ring,
+            group,
+            entries,
+            buffer_size,
+            buffers,
+        };
+
+        for id in 0..entries {
+            buffer_ring.add(id as u16, id as _);
+        }
+        unsafe { io_uring_buf_ring_advance(buffer_ring.ring, entries as _) };
+
+        Ok(buffer_ring)
+    }
+
+    /// Gets the buffer group ID to select buffers from in an SQE
+    pub fn group(&self) -> u16 {
+        self.group
+    }
+
+    /// Gets the size of each buffer
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Gets the contents of the buffer with `id`
+    ///
+    /// # Panic
+    /// This function will panic if `id` is not a buffer in this ring
+    pub fn get(&self, id: u16) -> &[u8] {
+        assert!((id as u32) < self.entries);
+
+        let start = id as usize * self.buffer_size;
+        &self.buffers[start..start + self.buffer_size]
+    }
+
+    /// Returns the buffer with `id` to the kernel so it can be selected again
+    ///
+    /// # Panic
+    /// This function will panic if `id` is not a buffer in this ring
+    pub fn recycle(&mut self, id: u16) {
+        assert!((id as u32) < self.entries);
+
+        self.add(id, 0);
+        unsafe { io_uring_buf_ring_advance(self.ring, 1) };
+    }
+
+    /// Unregisters the ring from the kernel, after which it can be dropped
+    pub(crate) fn unregister(&self, io_uring: &mut IOURing) {
+        io_uring.unregister_buf_ring(self.group);
+    }
+
+    /// Adds the buffer with `id` to the ring at `offset` past the current tail
+    fn add(&mut self, id: u16, offset: i32) {
+        let start = id as usize * self.buffer_size;
+
+        unsafe {
+            io_uring_buf_ring_add(
+                self.ring,
+                self.buffers[start..].as_mut_ptr().cast(),
+                self.buffer_size as _,
+                id,
+                io_uring_buf_ring_mask(self.entries),
+                offset,
+            )
+        };
+    }
+}
+
+impl Drop for BufferRing {
+    fn drop(&mut self) {
+        // `io_uring_setup_buf_ring` mapped the ring with room for an `io_uring_buf` per entry
+        unsafe {
+            munmap(
+                self.ring.cast(),
+                self.entries as usize * std::mem::size_of::<io_uring_buf>(),
+            )
+        };
+    }
+}
+
+impl !Send for BufferRing {}
+impl !Sync for BufferRing {}
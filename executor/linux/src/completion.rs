@@ -0,0 +1,33 @@
+use std::ffi::c_int;
+use uring::io_uring_cqe;
+
+/// The result of a single completed operation, saved for when an event produces multiple
+/// completions
+#[derive(Clone, Copy)]
+pub struct Completion {
+    /// The result code of the operation
+    result: c_int,
+
+    /// The flags set by the kernel on completion
+    flags: u32,
+}
+
+impl Completion {
+    /// Creates a new [`Completion`] from `cqe`
+    pub fn new(cqe: &io_uring_cqe) -> Self {
+        Completion {
+            result: cqe.res,
+            flags: cqe.flags,
+        }
+    }
+
+    /// Gets the result code of the operation
+    pub fn result(&self) -> c_int {
+        self.result
+    }
+
+    /// Gets the flags set by the kernel on completion
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+}
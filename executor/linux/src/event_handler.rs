@@ -1,5 +1,5 @@
-use crate::WaitQueue;
-use std::{cell::RefCell, rc::Rc};
+use crate::{Completion, WaitQueue};
+use std::{any::Any, cell::RefCell, collections::VecDeque, rc::Rc};
 use uring::io_uring_cqe;
 
 /// A handler called when an event signals completion
@@ -19,6 +19,15 @@ pub enum EventHandler {
         Rc<RefCell<WaitQueue>>,
         fn(cqe: &mut io_uring_cqe, wait_queue: &mut WaitQueue),
     ),
+
+    /// A queue of [`Completion`]s is associated with the event
+    Queue(
+        VecDeque<Completion>,
+        fn(cqe: &mut io_uring_cqe, queue: &mut VecDeque<Completion>),
+    ),
+
+    /// Data is kept alive as long as the event, such as a buffer the kernel is still using
+    Owned(Rc<dyn Any>, fn(cqe: &mut io_uring_cqe)),
 }
 
 impl EventHandler {
@@ -55,6 +64,16 @@ impl EventHandler {
         EventHandler::WaitQueue(wait_queue, handler)
     }
 
+    /// Creates a new [`EventHandler`] with an associated empty queue of [`Completion`]s
+    pub fn queue(handler: fn(&mut io_uring_cqe, &mut VecDeque<Completion>)) -> Self {
+        EventHandler::Queue(VecDeque::new(), handler)
+    }
+
+    /// Creates a new [`EventHandler`] which keeps `data` alive until the event is deregistered
+    pub fn owned(handler: fn(&mut io_uring_cqe), data: Rc<dyn Any>) -> Self {
+        EventHandler::Owned(data, handler)
+    }
+
     /// Gets the boolean value associated with the event if there is one
     pub fn as_boolean_opt(&self) -> Option<bool> {
         match self {
@@ -83,6 +102,21 @@ impl EventHandler {
             .expect("Attempted to get an integer from a non-integer event")
     }
 
+    /// Mutably gets the queue of [`Completion`]s associated with the event if there is one
+    pub fn as_queue_mut_opt(&mut self) -> Option<&mut VecDeque<Completion>> {
+        match self {
+            EventHandler::Queue(queue, _) => Some(queue),
+            _ => None,
+        }
+    }
+
+    /// Mutably gets the queue of [`Completion`]s associated with the event, panicking if there
+    /// isn't one.
+    pub fn as_queue_mut(&mut self) -> &mut VecDeque<Completion> {
+        self.as_queue_mut_opt()
+            .expect("Attempted to get a queue from a non-queue event")
+    }
+
     /// Sets the boolean associated with the event, panicking if the event doesn't contain a
     /// boolean.
     pub fn set_boolean(&mut self, new_value: bool) {
@@ -110,6 +144,8 @@ impl EventHandler {
             EventHandler::WaitQueue(wait_queue, handler) => {
                 (handler)(cqe, &mut *wait_queue.borrow_mut())
             }
+            EventHandler::Queue(queue, handler) => (handler)(cqe, queue),
+            EventHandler::Owned(_, handler) => (handler)(cqe),
         }
     }
 }
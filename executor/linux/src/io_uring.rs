@@ -1,8 +1,10 @@
 use crate::{Error, Result};
-use std::ptr::null_mut;
+use std::{ffi::c_int, ptr::null_mut};
 use uring::{
-    io_uring, io_uring_cq_ready, io_uring_cqe, io_uring_cqe_seen, io_uring_get_sqe,
-    io_uring_queue_exit, io_uring_queue_init, io_uring_sqe, io_uring_submit, io_uring_wait_cqe,
+    io_uring, io_uring_buf_ring, io_uring_cq_ready, io_uring_cqe, io_uring_cqe_seen,
+    io_uring_get_sqe, io_uring_queue_exit, io_uring_queue_init, io_uring_setup_buf_ring,
+    io_uring_sq_space_left, io_uring_sqe, io_uring_submit, io_uring_unregister_buf_ring,
+    io_uring_wait_cqe,
 };
 
 /// `io_uring` submission and completion queues
@@ -37,6 +39,11 @@ impl IOURing {
         }
     }
 
+    /// Gets the number of [`io_uring_sqe`]s which can be taken from the ring
+    pub(crate) fn space_left(&self) -> u32 {
+        unsafe { io_uring_sq_space_left(&self.inner) }
+    }
+
     /// Submits an [`io_uring_sqe`] to poll for completion
     #[allow(unused_variables)]
     pub(crate) fn submit_sqe(&mut self, sqe: *mut io_uring_sqe) -> Result<()> {
@@ -63,6 +70,28 @@ impl IOURing {
         unsafe { io_uring_cqe_seen(&mut self.inner, cqe) }
     }
 
+    /// Allocates and registers a ring of provided buffers with `entries` entries under `group`
+    pub(crate) fn setup_buf_ring(
+        &mut self,
+        entries: u32,
+        group: u16,
+    ) -> Result<*mut io_uring_buf_ring> {
+        let mut result = 0;
+        let ring = unsafe {
+            io_uring_setup_buf_ring(&mut self.inner, entries, group as c_int, 0, &mut result)
+        };
+        if ring == null_mut() {
+            Err(Error::new(-result))
+        } else {
+            Ok(ring)
+        }
+    }
+
+    /// Unregisters the ring of provided buffers under `group` so the kernel stops selecting from it
+    pub(crate) fn unregister_buf_ring(&mut self, group: u16) {
+        unsafe { io_uring_unregister_buf_ring(&mut self.inner, group as c_int) };
+    }
+
     /// Gets the number of events that have already been triggered
     pub(crate) fn available_events(&self) -> u32 {
         unsafe { io_uring_cq_ready(&self.inner) }
@@ -7,15 +7,22 @@
 #![warn(rustdoc::broken_intra_doc_links)]
 #![feature(negative_impls)]
 
+mod buffer_ring;
+mod completion;
 mod event_handler;
 mod io_uring;
 mod manager;
+mod runtime_clock;
 mod sqe;
+mod timer_wheel;
 mod wait_queue;
 
+pub use buffer_ring::BufferRing;
+pub use completion::Completion;
 pub use event_handler::EventHandler;
 pub use manager::LocalEventManager;
 pub use sqe::SQE;
+pub use timer_wheel::{TimerKey, TimerWheel};
 pub use wait_queue::WaitQueue;
 
 // Platform re-export
@@ -25,3 +32,4 @@ pub use uring::{
 };
 
 use io_uring::IOURing;
+use runtime_clock::RuntimeClock;
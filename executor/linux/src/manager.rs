@@ -1,7 +1,29 @@
-use crate::{Error, EventHandler, IOURing, Result, SQE};
+use crate::{BufferRing, Error, EventHandler, IOURing, Result, RuntimeClock, TimerWheel, SQE};
 use executor_common::{Event, EventID, List};
-use std::{num::NonZeroUsize, ptr::null_mut};
-use uring::{io_uring_cqe_get_data64, io_uring_sqe_set_data64};
+use std::{
+    num::NonZeroUsize,
+    ptr::null_mut,
+    time::{Duration, Instant},
+};
+use uring::{
+    io_uring_cqe_get_data64, io_uring_prep_cancel64, io_uring_prep_timeout,
+    io_uring_prep_timeout_update, io_uring_sqe_set_data64, linux::time::__kernel_timespec,
+    IORING_CQE_F_MORE,
+};
+
+/// The user data for the timeout driving the timer wheel. Event IDs never have an index this
+/// large, so it can't collide with one.
+const TIMER_USER_DATA: u64 = u64::MAX;
+
+/// The user data for updates to the timeout driving the timer wheel
+const TIMER_UPDATE_USER_DATA: u64 = u64::MAX - 1;
+
+/// The user data for timeouts linked to operations by [`LocalEventManager::get_sqe_with_deadline`].
+/// The operation's own event receives the result, so these completions are ignored.
+pub(crate) const LINK_TIMEOUT_USER_DATA: u64 = u64::MAX - 2;
+
+/// The user data for cancellations submitted by [`LocalEventManager::cancel`]
+const CANCEL_USER_DATA: u64 = u64::MAX - 3;
 
 /// The manager of events on a thread
 pub struct LocalEventManager {
@@ -10,6 +32,24 @@ pub struct LocalEventManager {
 
     /// The communication for I/O with the kernel
     io_uring: IOURing,
+
+    /// Rings of provided buffers, indexed by their buffer group ID
+    ///
+    /// This must be dropped after `io_uring` so the kernel stops using the buffers before they are
+    /// freed. Exiting the `io_uring` unregisters any rings still in here.
+    buffer_rings: Vec<Option<BufferRing>>,
+
+    /// The clock `timers` are measured against
+    clock: RuntimeClock,
+
+    /// Timers driven by a single kernel timeout
+    timers: TimerWheel,
+
+    /// The deadline the kernel timeout for `timers` is armed for, if it is armed
+    timer_deadline: Option<Instant>,
+
+    /// Events whose owners are gone, deregistered once their last completion arrives
+    orphans: Vec<EventID>,
 }
 
 impl LocalEventManager {
@@ -24,12 +64,20 @@ impl LocalEventManager {
 
         let io_uring = IOURing::new((size.get() / 2) as _)?;
 
-        Ok(LocalEventManager { events, io_uring })
+        Ok(LocalEventManager {
+            events,
+            io_uring,
+            buffer_rings: Vec::new(),
+            clock: RuntimeClock::new(),
+            timers: TimerWheel::new(),
+            timer_deadline: None,
+            orphans: Vec::new(),
+        })
     }
 
-    /// Gets the number of outstanding events
+    /// Gets the number of outstanding events, including timers which haven't fired
     pub fn len(&self) -> usize {
-        self.events.len()
+        self.events.len() + self.timers.len()
     }
 
     /// Mutably gets an event
@@ -51,35 +99,271 @@ impl LocalEventManager {
         Ok(SQE::new(sqe, &mut self.io_uring))
     }
 
+    /// Gets an [`SQE`] for I/O submission which is linked to a timeout cancelling it if `deadline`
+    /// passes first
+    ///
+    /// A cancelled operation completes with `ECANCELED`. `deadline` is measured against the
+    /// executor's clock, while the kernel counts down the time remaining until it in real time.
+    /// Both submissions are reserved up front, so if there isn't room for both this fails with
+    /// `ENOSPC` without taking either. If `deadline` is `None`, this is the same as
+    /// [`LocalEventManager::get_sqe`].
+    pub fn get_sqe_with_deadline(
+        &mut self,
+        event_id: EventID,
+        deadline: Option<Instant>,
+    ) -> Result<SQE> {
+        let deadline = match deadline {
+            Some(deadline) => deadline,
+            None => return self.get_sqe(event_id),
+        };
+
+        if self.io_uring.space_left() < 2 {
+            return Err(Error::ENOSPC);
+        }
+
+        // The timeout must directly follow the operation in the ring to be linked to it
+        let sqe = self.io_uring.get_sqe().ok_or(Error::ENOSPC)?;
+        let timeout_sqe = self.io_uring.get_sqe().ok_or(Error::ENOSPC)?;
+
+        unsafe { io_uring_sqe_set_data64(sqe, event_id.into_u64()) };
+
+        let timeout = deadline.saturating_duration_since(self.clock.now());
+        Ok(SQE::with_link_timeout(
+            sqe,
+            &mut self.io_uring,
+            timeout_sqe,
+            timeout,
+        ))
+    }
+
     /// Deregisters an event based on its [`EventID`]
     pub fn deregister(&mut self, event_id: EventID) {
         self.events.remove(event_id);
     }
 
+    /// Keeps an event registered after its owner is dropped, until the kernel posts its last
+    /// completion
+    ///
+    /// `handler` replaces the event's handler so it can clean up anything the remaining
+    /// completions deliver. The event is deregistered after the first completion without
+    /// `IORING_CQE_F_MORE`, so this must only be called while one is still expected.
+    pub fn orphan(&mut self, event_id: EventID, handler: EventHandler) {
+        if let Some(event) = self.events.get_mut(event_id) {
+            event.set_data(handler);
+            event.set_waker(None);
+            self.orphans.push(event_id);
+        }
+    }
+
+    /// Submits a request to cancel the operation submitted under `event_id`
+    ///
+    /// Unlike preparing a cancellation on an [`SQE`] from [`LocalEventManager::get_sqe`], the
+    /// completion of the request itself is not delivered to the event.
+    pub fn cancel(&mut self, event_id: EventID) -> Result<()> {
+        let sqe = self.io_uring.get_sqe().ok_or(Error::ENOSPC)?;
+
+        unsafe {
+            io_uring_prep_cancel64(sqe, event_id.into_u64(), 0);
+            io_uring_sqe_set_data64(sqe, CANCEL_USER_DATA);
+        }
+
+        self.io_uring.submit_sqe(sqe)
+    }
+
+    /// Registers a new [`BufferRing`] with `entries` buffers of `buffer_size` bytes, returning its
+    /// buffer group ID
+    ///
+    /// # Panic
+    /// This function will panic if `entries` is not a power of two or is more than 32768
+    pub fn register_buffer_ring(&mut self, entries: u32, buffer_size: usize) -> Result<u16> {
+        let group = match self.buffer_rings.iter().position(Option::is_none) {
+            Some(group) => group,
+            None => {
+                if self.buffer_rings.len() > u16::MAX as usize {
+                    return Err(Error::ENOSPC);
+                }
+
+                self.buffer_rings.push(None);
+                self.buffer_rings.len() - 1
+            }
+        };
+
+        let buffer_ring = BufferRing::new(&mut self.io_uring, group as u16, entries, buffer_size)?;
+        self.buffer_rings[group] = Some(buffer_ring);
+        Ok(group as u16)
+    }
+
+    /// Mutably gets a [`BufferRing`] based on its buffer group ID
+    pub fn get_buffer_ring_mut(&mut self, group: u16) -> Option<&mut BufferRing> {
+        self.buffer_rings
+            .get_mut(group as usize)
+            .and_then(Option::as_mut)
+    }
+
+    /// Unregisters and frees a [`BufferRing`] based on its buffer group ID
+    pub fn deregister_buffer_ring(&mut self, group: u16) {
+        if let Some(buffer_ring) = self
+            .buffer_rings
+            .get_mut(group as usize)
+            .and_then(Option::take)
+        {
+            buffer_ring.unregister(&mut self.io_uring);
+        }
+    }
+
+    /// Gets the [`TimerWheel`] for this thread
+    pub fn timers(&self) -> &TimerWheel {
+        &self.timers
+    }
+
+    /// Mutably gets the [`TimerWheel`] for this thread
+    pub fn timers_mut(&mut self) -> &mut TimerWheel {
+        &mut self.timers
+    }
+
+    /// Gets the current instant on the executor's clock, which timers are measured against
+    ///
+    /// This is the same as [`Instant::now`] unless the clock has been paused or advanced.
+    pub fn now(&self) -> Instant {
+        self.clock.now()
+    }
+
+    /// Is the executor's clock paused?
+    pub fn is_paused(&self) -> bool {
+        self.clock.is_paused()
+    }
+
+    /// Stops the executor's clock, so it only moves when advanced
+    ///
+    /// While the clock is paused and every task is waiting, the clock automatically advances to
+    /// the next timer instead of waiting for it. This includes tasks waiting on I/O, so the timers
+    /// can fire before I/O which would have completed first in real time.
+    pub fn pause(&mut self) {
+        self.clock.pause()
+    }
+
+    /// Restarts the executor's clock from the instant it was paused at
+    pub fn resume(&mut self) {
+        self.clock.resume()
+    }
+
+    /// Moves the executor's clock forward by `duration`, firing any timers it passes
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+        self.timers.process(self.clock.now());
+    }
+
     /// Sleeps until an event is triggered
+    ///
+    /// If the clock is paused, no I/O has completed, and timers are waiting, the clock is advanced
+    /// until one fires instead.
     pub fn poll(&mut self) -> Result<()> {
+        if self.clock.is_paused() && self.io_uring.available_events() == 0 {
+            if self.advance_to_next_timer() {
+                return Ok(());
+            }
+        } else {
+            self.arm_timers()?;
+        }
+
         let mut cqe = null_mut();
 
         loop {
             self.io_uring.wait(&mut cqe)?;
 
             let user_data = unsafe { io_uring_cqe_get_data64(cqe) };
-            let event_id = unsafe { EventID::from_u64(user_data) };
+            match user_data {
+                TIMER_USER_DATA => self.timer_deadline = None,
+                TIMER_UPDATE_USER_DATA | LINK_TIMEOUT_USER_DATA | CANCEL_USER_DATA => {}
+                _ => {
+                    let event_id = unsafe { EventID::from_u64(user_data) };
 
-            match self.events.get_mut(event_id) {
-                Some(event) => {
-                    event.data_mut().run(unsafe { &mut *cqe });
-                    event.wake()
+                    match self.events.get_mut(event_id) {
+                        Some(event) => {
+                            event.data_mut().run(unsafe { &mut *cqe });
+                            event.wake()
+                        }
+                        None => {}
+                    }
+
+                    if unsafe { (*cqe).flags } & IORING_CQE_F_MORE == 0 {
+                        if let Some(index) = self.orphans.iter().position(|id| *id == event_id) {
+                            self.orphans.swap_remove(index);
+                            self.events.remove(event_id);
+                        }
+                    }
                 }
-                None => {}
             }
 
             self.io_uring.seen(cqe);
 
             if self.io_uring.available_events() == 0 {
+                break;
+            }
+        }
+
+        self.timers.process(self.clock.now());
+        Ok(())
+    }
+
+    /// Advances the paused clock until a timer fires, returning `false` if there are no timers
+    fn advance_to_next_timer(&mut self) -> bool {
+        let pending = self.timers.len();
+
+        while self.timers.len() == pending {
+            let deadline = match self.timers.next_deadline() {
+                Some(deadline) => deadline,
+                None => return false,
+            };
+
+            let now = self.clock.now();
+            if deadline > now {
+                self.clock.advance(deadline - now);
+            }
+
+            self.timers.process(self.clock.now());
+        }
+
+        true
+    }
+
+    /// Arms the kernel timeout for the next deadline in the timer wheel, moving it earlier if it
+    /// is already armed for a later deadline
+    fn arm_timers(&mut self) -> Result<()> {
+        let deadline = match self.timers.next_deadline() {
+            Some(deadline) => deadline,
+            None => return Ok(()),
+        };
+
+        if let Some(armed) = self.timer_deadline {
+            if armed <= deadline {
                 return Ok(());
             }
         }
+
+        let timeout = deadline.saturating_duration_since(self.clock.now());
+        let mut timespec = __kernel_timespec {
+            sec: timeout.as_secs() as _,
+            nsec: timeout.subsec_nanos() as _,
+        };
+
+        let sqe = self.io_uring.get_sqe().ok_or(Error::ENOSPC)?;
+        match self.timer_deadline {
+            Some(_) => unsafe {
+                io_uring_prep_timeout_update(sqe, &mut timespec, TIMER_USER_DATA, 0);
+                io_uring_sqe_set_data64(sqe, TIMER_UPDATE_USER_DATA);
+            },
+            None => unsafe {
+                io_uring_prep_timeout(sqe, &mut timespec, 0, 0);
+                io_uring_sqe_set_data64(sqe, TIMER_USER_DATA);
+            },
+        }
+
+        // The kernel copies the timespec during submission, so it doesn't need to outlive this
+        self.io_uring.submit_sqe(sqe)?;
+        self.timer_deadline = Some(deadline);
+
+        Ok(())
     }
 }
 
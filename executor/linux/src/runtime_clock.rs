@@ -0,0 +1,56 @@
+use std::time::{Duration, Instant};
+
+/// The clock timers in the executor are measured against
+///
+/// The clock follows [`Instant::now`] until it is paused. While paused it only moves when
+/// advanced, and once resumed it runs on from wherever it was left.
+pub(crate) struct RuntimeClock {
+    /// The instant on this clock when it was last resumed, or the instant it is paused at
+    base: Instant,
+
+    /// The real instant this clock was last resumed at, `None` if it is paused
+    resumed_at: Option<Instant>,
+}
+
+impl RuntimeClock {
+    /// Creates a new running [`RuntimeClock`] which reads the same as [`Instant::now`]
+    pub(crate) fn new() -> Self {
+        let now = Instant::now();
+
+        RuntimeClock {
+            base: now,
+            resumed_at: Some(now),
+        }
+    }
+
+    /// Gets the current instant on this clock
+    pub(crate) fn now(&self) -> Instant {
+        match self.resumed_at {
+            Some(resumed_at) => self.base + resumed_at.elapsed(),
+            None => self.base,
+        }
+    }
+
+    /// Is this clock paused?
+    pub(crate) fn is_paused(&self) -> bool {
+        self.resumed_at.is_none()
+    }
+
+    /// Stops this clock at its current instant
+    pub(crate) fn pause(&mut self) {
+        self.base = self.now();
+        self.resumed_at = None;
+    }
+
+    /// Restarts this clock from the instant it was paused at
+    pub(crate) fn resume(&mut self) {
+        if self.is_paused() {
+            self.resumed_at = Some(Instant::now());
+        }
+    }
+
+    /// Moves this clock forward by `duration`
+    pub(crate) fn advance(&mut self, duration: Duration) {
+        self.base += duration;
+    }
+}
@@ -1,16 +1,44 @@
-use crate::{IOURing, Result};
-use uring::io_uring_sqe;
+use crate::{manager::LINK_TIMEOUT_USER_DATA, IOURing, Result};
+use std::time::Duration;
+use uring::{
+    io_uring_prep_link_timeout, io_uring_sqe, io_uring_sqe_set_data64, io_uring_sqe_set_flags,
+    linux::time::__kernel_timespec, IOSQE_IO_LINK,
+};
 
 /// A wrapper for [`io_uring_sqe`] that allows easy submission
 pub struct SQE<'a> {
     inner: *mut io_uring_sqe,
     ring: &'a mut IOURing,
+
+    /// The reserved SQE for a timeout linked to this one and the time until it expires
+    link_timeout: Option<(*mut io_uring_sqe, Duration)>,
 }
 
 impl<'a> SQE<'a> {
     /// Creates a new [`SQE`] from `inner`
     pub(crate) fn new(inner: *mut io_uring_sqe, ring: &'a mut IOURing) -> Self {
-        SQE { inner, ring }
+        SQE {
+            inner,
+            ring,
+            link_timeout: None,
+        }
+    }
+
+    /// Creates a new [`SQE`] from `inner`, which is linked to a timeout in `timeout_sqe` expiring
+    /// after `timeout` when submitted
+    ///
+    /// `timeout_sqe` must be the SQE taken from the ring directly after `inner`.
+    pub(crate) fn with_link_timeout(
+        inner: *mut io_uring_sqe,
+        ring: &'a mut IOURing,
+        timeout_sqe: *mut io_uring_sqe,
+        timeout: Duration,
+    ) -> Self {
+        SQE {
+            inner,
+            ring,
+            link_timeout: Some((timeout_sqe, timeout)),
+        }
     }
 
     /// Gets the pointer to the underlying [`io_uring_sqe`]
@@ -19,7 +47,27 @@ impl<'a> SQE<'a> {
     }
 
     /// Submits the [`SQE`] to be polled for completion
+    ///
+    /// If this has a linked timeout, it is prepared here, as preparing the [`SQE`] clears the link.
     pub fn submit(self) -> Result<()> {
+        let (timeout_sqe, timeout) = match self.link_timeout {
+            Some(link_timeout) => link_timeout,
+            None => return self.ring.submit_sqe(self.inner),
+        };
+
+        let mut timespec = __kernel_timespec {
+            sec: timeout.as_secs() as _,
+            nsec: timeout.subsec_nanos() as _,
+        };
+
+        unsafe {
+            io_uring_sqe_set_flags(self.inner, (*self.inner).flags as u32 | IOSQE_IO_LINK);
+
+            io_uring_prep_link_timeout(timeout_sqe, &mut timespec, 0);
+            io_uring_sqe_set_data64(timeout_sqe, LINK_TIMEOUT_USER_DATA);
+        }
+
+        // The kernel copies the timespec during submission, so it doesn't need to outlive this
         self.ring.submit_sqe(self.inner)
     }
 }
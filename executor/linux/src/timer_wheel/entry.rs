@@ -0,0 +1,57 @@
+use std::task::Waker;
+
+/// The state of an [`Entry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum EntryState {
+    /// The entry is not assigned to a timer
+    Free,
+
+    /// The timer is waiting in a slot of the wheel
+    Pending,
+
+    /// The timer's deadline has passed
+    Fired,
+}
+
+/// An entry for a timer in the wheel
+pub(super) struct Entry {
+    /// Incremented each time the entry is freed so stale keys don't match
+    pub(super) generation: u32,
+
+    /// The state of the entry
+    pub(super) state: EntryState,
+
+    /// The tick the timer fires at
+    pub(super) when: u64,
+
+    /// The [`Waker`] for the task waiting on the timer
+    pub(super) waker: Option<Waker>,
+
+    /// The level of the slot the timer is in
+    pub(super) level: usize,
+
+    /// The slot the timer is in
+    pub(super) slot: usize,
+
+    /// The previous timer in the slot
+    pub(super) prev: Option<usize>,
+
+    /// The next timer in the slot, or the next free entry if this is free
+    pub(super) next: Option<usize>,
+}
+
+impl Entry {
+    /// Creates a new free [`Entry`]
+    pub(super) const fn new() -> Self {
+        Entry {
+            generation: 0,
+            state: EntryState::Free,
+            when: 0,
+            waker: None,
+            level: 0,
+            slot: 0,
+            prev: None,
+            next: None,
+        }
+    }
+}
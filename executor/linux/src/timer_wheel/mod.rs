@@ -0,0 +1,334 @@
+use entry::{Entry, EntryState};
+use std::{
+    task::Waker,
+    time::{Duration, Instant},
+};
+
+mod entry;
+mod timer_key;
+
+pub use timer_key::TimerKey;
+
+/// The number of bits of a tick each level covers
+const LEVEL_BITS: usize = 6;
+
+/// The number of slots in each level
+const SLOTS: usize = 1 << LEVEL_BITS;
+
+/// The mask for the slot within a level
+const SLOT_MASK: u64 = SLOTS as u64 - 1;
+
+/// The number of levels in the wheel
+const LEVELS: usize = 6;
+
+/// The furthest ahead, in ticks, a timer can be placed. Timers further out are placed at the end
+/// of the wheel and moved once it reaches them.
+const MAX_TICKS: u64 = 1 << (LEVEL_BITS * LEVELS);
+
+/// The length of a tick in nanoseconds
+const TICK_NANOS: u64 = 1_000_000;
+
+/// A hierarchical timer wheel, tracking many timers so a single kernel timeout can drive them
+///
+/// Each level has 64 slots and each slot of a level covers a full rotation of the level below,
+/// with the first level having a slot per millisecond. Inserting and removing timers is O(1) and
+/// timers are cascaded down to lower levels as their deadline approaches. Deadlines are rounded up
+/// to the next millisecond so timers never fire early.
+pub struct TimerWheel {
+    /// The instant tick zero starts at
+    origin: Instant,
+
+    /// The last tick processed
+    elapsed: u64,
+
+    /// The entries for the timers
+    entries: Vec<Entry>,
+
+    /// The index of the first free entry
+    first_free: Option<usize>,
+
+    /// The first timer in each slot of each level
+    slots: [[Option<usize>; SLOTS]; LEVELS],
+
+    /// A bit for each slot of each level, set if the slot contains a timer
+    occupied: [u64; LEVELS],
+
+    /// The number of timers which haven't fired yet
+    pending: usize,
+}
+
+impl TimerWheel {
+    /// The furthest ahead a timer can be placed without being moved later, a little over two years
+    pub const MAX_DURATION: Duration = Duration::from_millis(MAX_TICKS);
+
+    /// Creates a new empty [`TimerWheel`]
+    pub(crate) fn new() -> Self {
+        TimerWheel {
+            origin: Instant::now(),
+            elapsed: 0,
+            entries: Vec::new(),
+            first_free: None,
+            slots: [[None; SLOTS]; LEVELS],
+            occupied: [0; LEVELS],
+            pending: 0,
+        }
+    }
+
+    /// Gets the number of timers which haven't fired yet
+    pub fn len(&self) -> usize {
+        self.pending
+    }
+
+    /// Inserts a new timer which fires at `deadline`
+    ///
+    /// If `deadline` has already been processed, the timer starts out fired.
+    pub fn insert(&mut self, deadline: Instant) -> TimerKey {
+        let index = match self.first_free {
+            Some(index) => {
+                self.first_free = self.entries[index].next;
+                index
+            }
+            None => {
+                self.entries.push(Entry::new());
+                self.entries.len() - 1
+            }
+        };
+
+        self.schedule(index, deadline);
+
+        TimerKey::new(index, self.entries[index].generation)
+    }
+
+    /// Moves the timer for `key` to fire at `deadline`, even if it has already fired
+    ///
+    /// If `deadline` has already been processed, the timer fires immediately.
+    pub fn reset(&mut self, key: TimerKey, deadline: Instant) {
+        let index = match self.index(key) {
+            Some(index) => index,
+            None => return,
+        };
+
+        if self.entries[index].state == EntryState::Pending {
+            self.unlink(index);
+            self.pending -= 1;
+        }
+
+        self.schedule(index, deadline);
+    }
+
+    /// Removes the timer for `key`, freeing its entry
+    pub fn remove(&mut self, key: TimerKey) {
+        let index = match self.index(key) {
+            Some(index) => index,
+            None => return,
+        };
+
+        if self.entries[index].state == EntryState::Pending {
+            self.unlink(index);
+            self.pending -= 1;
+        }
+
+        let entry = &mut self.entries[index];
+        entry.generation = entry.generation.wrapping_add(1);
+        entry.state = EntryState::Free;
+        entry.waker = None;
+        entry.prev = None;
+        entry.next = self.first_free;
+        self.first_free = Some(index);
+    }
+
+    /// Has the timer for `key` fired?
+    pub fn is_fired(&self, key: TimerKey) -> bool {
+        match self.index(key) {
+            Some(index) => self.entries[index].state == EntryState::Fired,
+            None => false,
+        }
+    }
+
+    /// Checks if the timer for `key` has fired, setting `waker` to be woken when it does if not
+    pub fn poll(&mut self, key: TimerKey, waker: &Waker) -> bool {
+        let index = match self.index(key) {
+            Some(index) => index,
+            None => return false,
+        };
+
+        let entry = &mut self.entries[index];
+        if entry.state == EntryState::Fired {
+            return true;
+        }
+
+        match &entry.waker {
+            Some(current) if current.will_wake(waker) => {}
+            _ => entry.waker = Some(waker.clone()),
+        }
+
+        false
+    }
+
+    /// Gets the instant the next slot with timers in it needs to be processed
+    ///
+    /// Timers in higher levels are cascaded when their slot is processed, so this can be before the
+    /// deadline of any timer.
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.next_expiration()
+            .map(|(_, _, tick)| self.origin + Duration::from_nanos(tick * TICK_NANOS))
+    }
+
+    /// Fires every timer with a deadline at or before `now`, waking their tasks
+    pub(crate) fn process(&mut self, now: Instant) {
+        let now = self.tick_floor(now);
+
+        while let Some((level, slot, tick)) = self.next_expiration() {
+            if tick > now {
+                break;
+            }
+
+            self.elapsed = tick;
+
+            let mut next = self.slots[level][slot].take();
+            self.occupied[level] &= !(1 << slot);
+
+            while let Some(index) = next {
+                next = self.entries[index].next;
+
+                if self.entries[index].when <= now {
+                    self.fire(index);
+                } else {
+                    self.link(index);
+                }
+            }
+        }
+
+        self.elapsed = self.elapsed.max(now);
+    }
+
+    /// Sets the deadline of the entry at `index` and places it in the wheel
+    fn schedule(&mut self, index: usize, deadline: Instant) {
+        let when = self.tick_ceil(deadline);
+
+        let entry = &mut self.entries[index];
+        entry.when = when;
+        entry.prev = None;
+        entry.next = None;
+
+        if when <= self.elapsed {
+            entry.state = EntryState::Fired;
+
+            if let Some(waker) = entry.waker.take() {
+                waker.wake();
+            }
+            return;
+        }
+
+        entry.state = EntryState::Pending;
+        self.pending += 1;
+        self.link(index);
+    }
+
+    /// Places the pending entry at `index` in the slot for its deadline
+    fn link(&mut self, index: usize) {
+        let when = self.entries[index].when.min(self.elapsed + MAX_TICKS - 1);
+        let level = level_for(self.elapsed, when);
+        let slot = ((when >> (level * LEVEL_BITS)) & SLOT_MASK) as usize;
+
+        let head = self.slots[level][slot].replace(index);
+        if let Some(head) = head {
+            self.entries[head].prev = Some(index);
+        }
+        self.occupied[level] |= 1 << slot;
+
+        let entry = &mut self.entries[index];
+        entry.level = level;
+        entry.slot = slot;
+        entry.prev = None;
+        entry.next = head;
+    }
+
+    /// Removes the pending entry at `index` from its slot
+    fn unlink(&mut self, index: usize) {
+        let entry = &self.entries[index];
+        let (level, slot, prev, next) = (entry.level, entry.slot, entry.prev, entry.next);
+
+        match prev {
+            Some(prev) => self.entries[prev].next = next,
+            None => self.slots[level][slot] = next,
+        }
+
+        if let Some(next) = next {
+            self.entries[next].prev = prev;
+        }
+
+        if self.slots[level][slot].is_none() {
+            self.occupied[level] &= !(1 << slot);
+        }
+    }
+
+    /// Marks the entry at `index`, which has been taken out of its slot, as fired
+    fn fire(&mut self, index: usize) {
+        let entry = &mut self.entries[index];
+        entry.state = EntryState::Fired;
+        entry.prev = None;
+        entry.next = None;
+        self.pending -= 1;
+
+        if let Some(waker) = entry.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Finds the next slot with timers in it, returning its level, slot, and the tick it starts at
+    fn next_expiration(&self) -> Option<(usize, usize, u64)> {
+        for level in 0..LEVELS {
+            if self.occupied[level] == 0 {
+                continue;
+            }
+
+            let slot_range = 1u64 << (level * LEVEL_BITS);
+            let level_range = slot_range << LEVEL_BITS;
+
+            // Search from the slot the current tick is in, wrapping around the level
+            let current = ((self.elapsed >> (level * LEVEL_BITS)) & SLOT_MASK) as u32;
+            let offset = self.occupied[level].rotate_right(current).trailing_zeros();
+            let slot = ((current + offset) as usize) % SLOTS;
+
+            let mut tick = (self.elapsed & !(level_range - 1)) + slot as u64 * slot_range;
+            if tick < self.elapsed {
+                tick += level_range;
+            }
+
+            return Some((level, slot, tick));
+        }
+
+        None
+    }
+
+    /// Gets the index of the entry for `key` if it is still assigned
+    fn index(&self, key: TimerKey) -> Option<usize> {
+        self.entries
+            .get(key.index())
+            .filter(|entry| entry.state != EntryState::Free && entry.generation == key.generation())
+            .map(|_| key.index())
+    }
+
+    /// Converts `instant` into the tick it is in
+    fn tick_floor(&self, instant: Instant) -> u64 {
+        (instant.saturating_duration_since(self.origin).as_nanos() / TICK_NANOS as u128) as u64
+    }
+
+    /// Converts `instant` into the first tick starting at or after it
+    fn tick_ceil(&self, instant: Instant) -> u64 {
+        let nanos = instant.saturating_duration_since(self.origin).as_nanos();
+        nanos.div_ceil(TICK_NANOS as u128) as u64
+    }
+}
+
+impl !Send for TimerWheel {}
+impl !Sync for TimerWheel {}
+
+/// Gets the level a timer firing at `when` is placed in, based on the highest bit which differs
+/// from `elapsed`
+fn level_for(elapsed: u64, when: u64) -> usize {
+    let masked = ((elapsed ^ when) | SLOT_MASK).min(MAX_TICKS - 1);
+    let significant = 63 - masked.leading_zeros() as usize;
+    significant / LEVEL_BITS
+}
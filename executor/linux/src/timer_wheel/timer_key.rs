@@ -0,0 +1,29 @@
+/// An ID which uniquely identifies a timer in a [`TimerWheel`](super::TimerWheel)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerKey {
+    /// The index of the timer's entry
+    index: u32,
+
+    /// The generation of the entry this timer was assigned
+    generation: u32,
+}
+
+impl TimerKey {
+    /// Creates a new [`TimerKey`]
+    pub(super) fn new(index: usize, generation: u32) -> Self {
+        TimerKey {
+            index: index as u32,
+            generation,
+        }
+    }
+
+    /// Gets the index of the timer's entry
+    pub(super) fn index(&self) -> usize {
+        self.index as usize
+    }
+
+    /// Gets the generation of the entry this timer was assigned
+    pub(super) fn generation(&self) -> u32 {
+        self.generation
+    }
+}
@@ -1,27 +1,80 @@
 use std::{collections::VecDeque, task::Waker};
 
 /// A list of tasks waiting to queued
-pub struct WaitQueue(VecDeque<Waker>);
+///
+/// Each task is given a key when pushed, which can be used to update or remove it from anywhere in
+/// the queue.
+pub struct WaitQueue {
+    /// The waiting tasks and their keys, in the order they were pushed
+    tasks: VecDeque<(u64, Waker)>,
+
+    /// The key for the next task pushed
+    next_key: u64,
+}
 
 impl WaitQueue {
     /// Creates a new empty [`WaitQueue`]
     pub const fn new() -> Self {
-        WaitQueue(VecDeque::new())
+        WaitQueue {
+            tasks: VecDeque::new(),
+            next_key: 0,
+        }
     }
 
     /// Gets the number of tasks waiting in this queue
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.tasks.len()
     }
 
-    /// Pushes `waker` to the back of the queue
-    pub fn push(&mut self, waker: Waker) {
-        self.0.push_back(waker);
+    /// Pushes `waker` to the back of the queue, returning its key
+    pub fn push(&mut self, waker: Waker) -> u64 {
+        let key = self.next_key;
+        self.next_key += 1;
+
+        self.tasks.push_back((key, waker));
+        key
     }
 
     /// Pops off the next waiting task if there is one
     pub fn pop(&mut self) -> Option<Waker> {
-        self.0.pop_front()
+        self.tasks.pop_front().map(|(_, waker)| waker)
+    }
+
+    /// Gets the key of the next waiting task if there is one
+    pub fn front(&self) -> Option<u64> {
+        self.tasks.front().map(|(key, _)| *key)
+    }
+
+    /// Is the task with `key` still waiting?
+    pub fn contains(&self, key: u64) -> bool {
+        self.tasks.iter().any(|(task, _)| *task == key)
+    }
+
+    /// Wakes the next waiting task without removing it from the queue
+    pub fn wake_front(&self) {
+        if let Some((_, waker)) = self.tasks.front() {
+            waker.wake_by_ref();
+        }
+    }
+
+    /// Replaces the [`Waker`] for the task with `key`, if it is still waiting
+    pub fn set_waker(&mut self, key: u64, waker: &Waker) {
+        if let Some((_, current)) = self.tasks.iter_mut().find(|(task, _)| *task == key) {
+            if !current.will_wake(waker) {
+                *current = waker.clone();
+            }
+        }
+    }
+
+    /// Removes the task with `key` from the queue, returning `true` if it was waiting
+    pub fn remove(&mut self, key: u64) -> bool {
+        match self.tasks.iter().position(|(task, _)| *task == key) {
+            Some(index) => {
+                self.tasks.remove(index);
+                true
+            }
+            None => false,
+        }
     }
 }
 
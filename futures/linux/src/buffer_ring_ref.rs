@@ -0,0 +1,34 @@
+use executor::{Error, EventManager, Result};
+use std::ops::Deref;
+
+/// A container for the buffer group ID of a provided buffer ring which deregisters on drop
+pub(crate) struct BufferRingRef(u16);
+
+impl BufferRingRef {
+    /// Registers a new ring of `entries` buffers of `buffer_size` bytes with the local event
+    /// manager and returns a [`BufferRingRef`] to it
+    ///
+    /// Fails with `EINVAL` if `entries` is not a power of two or is more than 32768.
+    pub(crate) fn register(entries: u32, buffer_size: usize) -> Result<Self> {
+        if !entries.is_power_of_two() || entries > 32768 || buffer_size > u32::MAX as usize {
+            return Err(Error::EINVAL);
+        }
+
+        EventManager::get_local_mut(|manager| manager.register_buffer_ring(entries, buffer_size))
+            .map(|group| BufferRingRef(group))
+    }
+}
+
+impl Deref for BufferRingRef {
+    type Target = u16;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for BufferRingRef {
+    fn drop(&mut self) {
+        EventManager::get_local_mut(|manager| manager.deregister_buffer_ring(self.0));
+    }
+}
@@ -11,6 +11,13 @@ impl EventRef {
             .map(|event_id| EventRef(event_id))
             .ok_or(Error::ENOSPC)
     }
+
+    /// Hands the event over to the local event manager, which runs `handler` for the remaining
+    /// completions and deregisters it after the last one
+    pub(crate) fn orphan(self, handler: EventHandler) {
+        EventManager::get_local_mut(|manager| manager.orphan(self.0, handler));
+        std::mem::forget(self);
+    }
 }
 
 impl Deref for EventRef {
@@ -1,7 +1,10 @@
 use std::ffi::c_int;
 
 /// An object which has an underlying file descriptor
-pub(crate) trait AsFD {
+pub trait AsFD {
     /// Gets the underlying file descriptor
+    ///
+    /// # SAFETY
+    /// It is up to the caller to correctly use this file descriptor
     unsafe fn fd(&self) -> c_int;
 }
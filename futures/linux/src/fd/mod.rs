@@ -2,6 +2,6 @@ mod as_fd;
 mod read;
 mod write;
 
-pub(crate) use as_fd::AsFD;
+pub use as_fd::AsFD;
 pub(crate) use read::FDRead;
 pub(crate) use write::FDWrite;
@@ -11,6 +11,7 @@ use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Instant,
 };
 
 /// A future which yields aftering reading bytes from a [`Read`]
@@ -24,6 +25,9 @@ pub(crate) struct FDRead<'a, R: AsFD> {
     /// The buffer to read into
     buffer: &'a mut [u8],
 
+    /// The instant the read is cancelled at, if it has one
+    deadline: Option<Instant>,
+
     /// Has the SQE been submitted?
     sqe_submitted: bool,
 }
@@ -40,23 +44,40 @@ fn read_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
 impl<'a, R: AsFD> FDRead<'a, R> {
     /// Creates a new [`FDRead`] future
     pub(crate) fn new(source: &'a mut R, buffer: &'a mut [u8]) -> Self {
+        Self::with_deadline(source, buffer, None)
+    }
+
+    /// Creates a new [`FDRead`] future which fails with `ECANCELED` if `deadline` passes first
+    pub(crate) fn with_deadline(
+        source: &'a mut R,
+        buffer: &'a mut [u8],
+        deadline: Option<Instant>,
+    ) -> Self {
         let event_id = EventRef::register(EventHandler::integer(read_callback));
 
         FDRead {
             source,
             event_id,
             buffer,
+            deadline,
             sqe_submitted: false,
         }
     }
 
-    /// Projects pinned self into `(self.source, self.event_id, self.buffer, self.sqe_submitted)`
+    /// Projects pinned self into `(self.source, self.event_id, self.buffer, self.deadline,
+    /// self.sqe_submitted)`
     ///
     /// # SAFTEY
     /// This is the only way to access the contained `buffer`, do not access it directly.
     unsafe fn project(
         self: Pin<&mut Self>,
-    ) -> (&mut R, Result<EventID>, Pin<&mut [u8]>, &mut bool) {
+    ) -> (
+        &mut R,
+        Result<EventID>,
+        Pin<&mut [u8]>,
+        Option<Instant>,
+        &mut bool,
+    ) {
         let this = self.get_unchecked_mut();
 
         (
@@ -66,6 +87,7 @@ impl<'a, R: AsFD> FDRead<'a, R> {
                 .map(|event_id| **event_id)
                 .map_err(|error| *error),
             Pin::new(&mut this.buffer),
+            this.deadline,
             &mut this.sqe_submitted,
         )
     }
@@ -75,7 +97,7 @@ impl<'a, R: AsFD> Future for FDRead<'a, R> {
     type Output = Result<usize>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (source, event_id, buffer, sqe_submitted) = unsafe { self.project() };
+        let (source, event_id, buffer, deadline, sqe_submitted) = unsafe { self.project() };
 
         let event_id = match event_id {
             Ok(event_id) => event_id,
@@ -85,7 +107,10 @@ impl<'a, R: AsFD> Future for FDRead<'a, R> {
         EventManager::get_local_mut(|manager| {
             // Submit the SQE if one hasn't been submitted yet
             if !*sqe_submitted {
-                let sqe = manager.get_sqe(event_id).unwrap();
+                let sqe = match manager.get_sqe_with_deadline(event_id, deadline) {
+                    Ok(sqe) => sqe,
+                    Err(error) => return Poll::Ready(Err(error)),
+                };
                 let length = buffer.len();
 
                 unsafe {
@@ -11,6 +11,7 @@ use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
+    time::Instant,
 };
 
 /// A future which yields aftering writing bytes to a [`Write`]
@@ -24,6 +25,9 @@ pub(crate) struct FDWrite<'a, W: AsFD> {
     /// The buffer to write from
     buffer: &'a [u8],
 
+    /// The instant the write is cancelled at, if it has one
+    deadline: Option<Instant>,
+
     /// Has the SQE been submitted?
     sqe_submitted: bool,
 }
@@ -40,21 +44,40 @@ fn write_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
 impl<'a, W: AsFD> FDWrite<'a, W> {
     /// Creates a new [`FDWrite`] future
     pub(crate) fn new(source: &'a mut W, buffer: &'a [u8]) -> Self {
+        Self::with_deadline(source, buffer, None)
+    }
+
+    /// Creates a new [`FDWrite`] future which fails with `ECANCELED` if `deadline` passes first
+    pub(crate) fn with_deadline(
+        source: &'a mut W,
+        buffer: &'a [u8],
+        deadline: Option<Instant>,
+    ) -> Self {
         let event_id = EventRef::register(EventHandler::integer(write_callback));
 
         FDWrite {
             source,
             event_id,
             buffer,
+            deadline,
             sqe_submitted: false,
         }
     }
 
-    /// Projects pinned self into `(self.source, self.event_id, self.buffer, self.sqe_submitted)`
+    /// Projects pinned self into `(self.source, self.event_id, self.buffer, self.deadline,
+    /// self.sqe_submitted)`
     ///
     /// # SAFTEY
     /// This is the only way to access the contained `buffer`, do not access it directly.
-    unsafe fn project(self: Pin<&mut Self>) -> (&mut W, Result<EventID>, Pin<&[u8]>, &mut bool) {
+    unsafe fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        &mut W,
+        Result<EventID>,
+        Pin<&[u8]>,
+        Option<Instant>,
+        &mut bool,
+    ) {
         let this = self.get_unchecked_mut();
 
         (
@@ -64,6 +87,7 @@ impl<'a, W: AsFD> FDWrite<'a, W> {
                 .map(|event_id| **event_id)
                 .map_err(|error| *error),
             Pin::new(&this.buffer),
+            this.deadline,
             &mut this.sqe_submitted,
         )
     }
@@ -73,7 +97,7 @@ impl<'a, W: AsFD> Future for FDWrite<'a, W> {
     type Output = Result<usize>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (source, event_id, buffer, sqe_submitted) = unsafe { self.project() };
+        let (source, event_id, buffer, deadline, sqe_submitted) = unsafe { self.project() };
 
         let event_id = match event_id {
             Ok(event_id) => event_id,
@@ -83,7 +107,10 @@ impl<'a, W: AsFD> Future for FDWrite<'a, W> {
         EventManager::get_local_mut(|manager| {
             // Submit the SQE if one hasn't been submitted yet
             if !*sqe_submitted {
-                let sqe = manager.get_sqe(event_id).unwrap();
+                let sqe = match manager.get_sqe_with_deadline(event_id, deadline) {
+                    Ok(sqe) => sqe,
+                    Err(error) => return Poll::Ready(Err(error)),
+                };
 
                 unsafe {
                     io_uring_prep_write(
@@ -0,0 +1,42 @@
+use executor::platform::linux::fcntl::{
+    POSIX_FADV_DONTNEED, POSIX_FADV_NOREUSE, POSIX_FADV_NORMAL, POSIX_FADV_RANDOM,
+    POSIX_FADV_SEQUENTIAL, POSIX_FADV_WILLNEED,
+};
+use std::ffi::c_int;
+
+/// An expected access pattern for a region of a file, used to allow the kernel to optimize
+/// caching
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Advice {
+    /// No special treatment
+    Normal,
+
+    /// The data will be accessed sequentially from lower to higher offsets
+    Sequential,
+
+    /// The data will be accessed in a random order
+    Random,
+
+    /// The data will only be accessed once
+    NoReuse,
+
+    /// The data will be accessed in the near future
+    WillNeed,
+
+    /// The data will not be accessed in the near future
+    DontNeed,
+}
+
+impl Advice {
+    /// Gets the `POSIX_FADV_*` value for this advice
+    pub(super) fn as_raw(self) -> c_int {
+        match self {
+            Advice::Normal => POSIX_FADV_NORMAL,
+            Advice::Sequential => POSIX_FADV_SEQUENTIAL,
+            Advice::Random => POSIX_FADV_RANDOM,
+            Advice::NoReuse => POSIX_FADV_NOREUSE,
+            Advice::WillNeed => POSIX_FADV_WILLNEED,
+            Advice::DontNeed => POSIX_FADV_DONTNEED,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    fd::AsFD,
+    fs::{CreateDir, FileStat, Open, OpenDir, OpenOptions, ReadDir, Remove, Rename},
+};
+use executor::{
+    platform::linux::{fcntl::AT_REMOVEDIR, unistd::close},
+    Result,
+};
+use std::{ffi::c_int, path::Path};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use std::future::Future;
+
+/// An open directory on the filesystem
+///
+/// Operations on a [`Dir`] are performed relative to it, allowing access to be restricted to a
+/// single directory tree.
+pub struct Dir(c_int);
+
+impl Dir {
+    /// Creates a new [`Dir`] for `fd`
+    pub(super) fn new(fd: c_int) -> Self {
+        Dir(fd)
+    }
+
+    /// Opens the directory at `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> OpenDir<'static> {
+        OpenDir::new(path.as_ref())
+    }
+
+    /// Opens the directory at `path` relative to this directory
+    pub fn open_dir_at<P: AsRef<Path>>(&self, path: P) -> OpenDir {
+        OpenDir::new_at(self, path.as_ref())
+    }
+
+    /// Opens the file at `path` relative to this directory with read permissions
+    ///
+    /// To open a file with other permissions, use [`OpenOptions::open_at`].
+    pub fn open_at<P: AsRef<Path>>(&self, path: P) -> Open {
+        OpenOptions::new().read(true).open_at(self, path)
+    }
+
+    /// Creates a new, empty directory at `path` relative to this directory
+    pub fn create_dir_at<P: AsRef<Path>>(&self, path: P) -> CreateDir {
+        CreateDir::new_at(self, path.as_ref())
+    }
+
+    /// Removes the file at `path` relative to this directory
+    pub fn remove_at<P: AsRef<Path>>(&self, path: P) -> Remove {
+        Remove::new_at(self, path.as_ref(), 0)
+    }
+
+    /// Removes the empty directory at `path` relative to this directory
+    pub fn remove_dir_at<P: AsRef<Path>>(&self, path: P) -> Remove {
+        Remove::new_at(self, path.as_ref(), AT_REMOVEDIR)
+    }
+
+    /// Renames the file or directory at `from` to `to`, both relative to this directory
+    pub fn rename_at<P: AsRef<Path>, Q: AsRef<Path>>(&self, from: P, to: Q) -> Rename {
+        Rename::new_at(self, from.as_ref(), to.as_ref())
+    }
+
+    /// Returns a [`Future`] which gets the metadata about the file at `path` relative to this
+    /// directory
+    pub fn metadata_at<P: AsRef<Path>>(&self, path: P) -> FileStat {
+        FileStat::new_at(self, path.as_ref())
+    }
+
+    /// Returns an iterator over the entries in this directory
+    pub fn read_dir(&self) -> Result<ReadDir> {
+        ReadDir::new(self)
+    }
+}
+
+impl AsFD for Dir {
+    unsafe fn fd(&self) -> c_int {
+        self.0
+    }
+}
+
+impl Drop for Dir {
+    fn drop(&mut self) {
+        unsafe { close(self.0) };
+    }
+}
@@ -1,10 +1,33 @@
 use crate::{
     fd::{AsFD, FDRead, FDWrite},
-    fs::{FileStat, Open, OpenOptions},
+    fs::{
+        xattr::XattrTarget, Advice, Advise, Allocate, FileStat, FileSync, GetXattr, Lock, Open,
+        OpenOptions, SetLen, SetXattr,
+    },
     io::{Read, Write},
+    time::DeadlineError,
+};
+use executor::{
+    platform::{
+        linux::{
+            fcntl::{
+                FALLOC_FL_KEEP_SIZE, FALLOC_FL_PUNCH_HOLE, SYNC_FILE_RANGE_WAIT_AFTER,
+                SYNC_FILE_RANGE_WAIT_BEFORE, SYNC_FILE_RANGE_WRITE,
+            },
+            sys::file::{flock, LOCK_EX, LOCK_NB, LOCK_SH, LOCK_UN},
+            try_linux,
+            unistd::close,
+        },
+        uring::IORING_FSYNC_DATASYNC,
+    },
+    Error, Result,
+};
+use std::{
+    ffi::{c_int, OsStr, OsString},
+    future::Future,
+    path::Path,
+    time::Instant,
 };
-use executor::{platform::linux::unistd::close, Result};
-use std::{ffi::c_int, path::Path};
 
 /// An open file on the filesystem
 pub struct File(c_int);
@@ -15,8 +38,15 @@ impl File {
         File(fd)
     }
 
+    /// Consumes this [`File`], returning the underlying file descriptor without closing it
+    pub(super) fn into_fd(self) -> c_int {
+        let fd = self.0;
+        std::mem::forget(self);
+        fd
+    }
+
     /// Creates a [`File`] at `path` and opens it with write permissions
-    pub fn create<P: AsRef<Path>>(path: P) -> Open {
+    pub fn create<P: AsRef<Path>>(path: P) -> Open<'static> {
         OpenOptions::new()
             .write(true)
             .create(true)
@@ -26,7 +56,7 @@ impl File {
 
     /// Creates a new [`File`] at `path` and opens it with read and write permissions. This
     /// function fails if the file exists already.
-    pub fn create_new<P: AsRef<Path>>(path: P) -> Open {
+    pub fn create_new<P: AsRef<Path>>(path: P) -> Open<'static> {
         OpenOptions::new()
             .read(true)
             .write(true)
@@ -35,7 +65,7 @@ impl File {
     }
 
     /// Opens the [`File`] at `path` with read permissions
-    pub fn open<P: AsRef<Path>>(path: P) -> Open {
+    pub fn open<P: AsRef<Path>>(path: P) -> Open<'static> {
         OpenOptions::new().read(true).open(path)
     }
 
@@ -48,6 +78,153 @@ impl File {
     pub fn metadata(&self) -> FileStat {
         FileStat::new(self)
     }
+
+    /// Returns a [`Future`] which flushes all data and metadata of this file to disk
+    pub fn sync_all(&self) -> FileSync {
+        FileSync::new(self, 0)
+    }
+
+    /// Returns a [`Future`] which flushes the data of this file to disk, skipping metadata which
+    /// isn't needed to read the data back
+    pub fn sync_data(&self) -> FileSync {
+        FileSync::new(self, IORING_FSYNC_DATASYNC)
+    }
+
+    /// Returns a [`Future`] which flushes `length` bytes of data starting at `offset` to disk
+    ///
+    /// A `length` of 0 flushes everything from `offset` to the end of the file. This does not
+    /// flush any metadata, so it is only safe to use for regions which have already been
+    /// allocated.
+    pub fn sync_range(&self, offset: u64, length: u32) -> FileSync {
+        FileSync::new_range(
+            self,
+            offset,
+            length,
+            (SYNC_FILE_RANGE_WAIT_BEFORE | SYNC_FILE_RANGE_WRITE | SYNC_FILE_RANGE_WAIT_AFTER) as _,
+        )
+    }
+
+    /// Returns a [`Future`] which truncates or extends this file to `length` bytes
+    pub fn set_len(&self, length: u64) -> SetLen {
+        SetLen::new(self, length)
+    }
+
+    /// Returns a [`Future`] which allocates disk space for `length` bytes starting at `offset`,
+    /// extending the file if needed
+    pub fn allocate(&self, offset: u64, length: u64) -> Allocate {
+        Allocate::new(self, 0, offset, length)
+    }
+
+    /// Returns a [`Future`] which deallocates the disk space for `length` bytes starting at
+    /// `offset`. The region will read back as zeros and the length of the file is unchanged.
+    pub fn punch_hole(&self, offset: u64, length: u64) -> Allocate {
+        Allocate::new(
+            self,
+            FALLOC_FL_PUNCH_HOLE | FALLOC_FL_KEEP_SIZE,
+            offset,
+            length,
+        )
+    }
+
+    /// Returns a [`Future`] which advises the kernel that `length` bytes starting at `offset` will
+    /// be accessed according to `advice`
+    ///
+    /// A `length` of 0 applies the advice to everything from `offset` to the end of the file.
+    pub fn advise(&self, advice: Advice, offset: u64, length: u64) -> Advise {
+        Advise::new(self, advice, offset, length)
+    }
+
+    /// Returns a [`Future`] which acquires a shared lock on this file, waiting until any exclusive
+    /// lock is released
+    ///
+    /// The lock is associated with this [`File`] and is released when it is dropped or
+    /// [`File::unlock`] is called.
+    pub fn lock_shared(&self) -> Lock {
+        Lock::new(self, LOCK_SH)
+    }
+
+    /// Returns a [`Future`] which acquires an exclusive lock on this file, waiting until any other
+    /// lock is released
+    ///
+    /// The lock is associated with this [`File`] and is released when it is dropped or
+    /// [`File::unlock`] is called.
+    pub fn lock_exclusive(&self) -> Lock {
+        Lock::new(self, LOCK_EX)
+    }
+
+    /// Attempts to acquire an exclusive lock on this file without waiting, returning `false` if
+    /// another lock is held
+    pub fn try_lock(&self) -> Result<bool> {
+        self.try_lock_raw(LOCK_EX)
+    }
+
+    /// Attempts to acquire a shared lock on this file without waiting, returning `false` if an
+    /// exclusive lock is held
+    pub fn try_lock_shared(&self) -> Result<bool> {
+        self.try_lock_raw(LOCK_SH)
+    }
+
+    /// Releases any lock held on this file
+    pub fn unlock(&self) -> Result<()> {
+        try_linux!(flock(self.0, LOCK_UN)).map(|_| ())
+    }
+
+    /// Returns a [`Future`] which yields the value of the extended attribute `name`
+    pub fn get_xattr<N: AsRef<OsStr>>(&self, name: N) -> GetXattr {
+        GetXattr::new(XattrTarget::FD(self.0), name.as_ref())
+    }
+
+    /// Returns a [`Future`] which sets the extended attribute `name` to `value`
+    pub fn set_xattr<'a, N: AsRef<OsStr>>(&'a self, name: N, value: &'a [u8]) -> SetXattr<'a> {
+        SetXattr::new(XattrTarget::FD(self.0), name.as_ref(), value)
+    }
+
+    /// Gets the names of the extended attributes on this file
+    ///
+    /// There is no `io_uring` operation for listing extended attributes, so this is performed
+    /// synchronously.
+    pub fn list_xattr(&self) -> Result<Vec<OsString>> {
+        XattrTarget::FD(self.0).list()
+    }
+
+    /// Removes the extended attribute `name` from this file
+    ///
+    /// There is no `io_uring` operation for removing extended attributes, so this is performed
+    /// synchronously.
+    pub fn remove_xattr<N: AsRef<OsStr>>(&self, name: N) -> Result<()> {
+        XattrTarget::FD(self.0).remove(name.as_ref())
+    }
+
+    /// Returns a [`Future`] which reads from this file into `buf`, failing with
+    /// [`DeadlineError::Elapsed`] if nothing is read before `deadline`
+    pub fn read_with_deadline<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+        deadline: Instant,
+    ) -> impl Future<Output = std::result::Result<usize, DeadlineError>> + 'a {
+        let read = FDRead::with_deadline(self, buf, Some(deadline));
+        async move { read.await.map_err(DeadlineError::from_linked) }
+    }
+
+    /// Returns a [`Future`] which writes `buf` into this file, failing with
+    /// [`DeadlineError::Elapsed`] if nothing is written before `deadline`
+    pub fn write_with_deadline<'a>(
+        &'a mut self,
+        buf: &'a [u8],
+        deadline: Instant,
+    ) -> impl Future<Output = std::result::Result<usize, DeadlineError>> + 'a {
+        let write = FDWrite::with_deadline(self, buf, Some(deadline));
+        async move { write.await.map_err(DeadlineError::from_linked) }
+    }
+
+    /// Performs the non-blocking `flock` `operation`
+    fn try_lock_raw(&self, operation: c_int) -> Result<bool> {
+        match try_linux!(flock(self.0, operation | LOCK_NB)) {
+            Ok(_) => Ok(true),
+            Err(error) if error == Error::EWOULDBLOCK => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
 }
 
 impl AsFD for File {
@@ -1,7 +1,7 @@
 use crate::{
     event_ref::EventRef,
     fd::AsFD,
-    fs::{File, Metadata},
+    fs::{Dir, File, Metadata},
 };
 use executor::{
     platform::{
@@ -15,8 +15,10 @@ use executor::{
     Error, EventID, EventManager, Result,
 };
 use std::{
-    ffi::c_int,
+    ffi::{c_int, CString},
     future::Future,
+    marker::PhantomData,
+    path::Path,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -25,10 +27,17 @@ use std::{
 #[allow(unused_imports)]
 use executor::platform::linux::sys::stat::statx;
 
-/// A [`Future`] which yields the [`Metadata`] for an open [`File`]
+/// A [`Future`] which yields the [`Metadata`] for an open [`File`] or for a path relative to a
+/// [`Dir`]
 pub struct FileStat<'a> {
-    /// The [`File`] to get [`Metadata`] for
-    file: &'a File,
+    /// The file descriptor of the [`File`] or [`Dir`]
+    fd: c_int,
+
+    /// The path relative to `fd`, empty when getting [`Metadata`] for `fd` itself
+    path: Result<CString>,
+
+    /// The flags passed to [`statx`]
+    flags: c_int,
 
     /// The buffer for the output of the [`statx`] call
     buffer: Statx,
@@ -38,6 +47,9 @@ pub struct FileStat<'a> {
 
     /// Has the SQE been submitted?
     sqe_submitted: bool,
+
+    /// A marker for the lifetime of the [`File`] or [`Dir`]
+    _lifetime: PhantomData<&'a ()>,
 }
 
 /// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
@@ -50,29 +62,58 @@ fn stat_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
 }
 
 impl<'a> FileStat<'a> {
-    /// Creates a new [`FileStat`] [`Future`]
+    /// Creates a new [`FileStat`] [`Future`] for `file`
     pub(super) fn new(file: &'a File) -> Self {
+        FileStat::new_raw(
+            unsafe { file.fd() },
+            Ok(CString::default()),
+            AT_EMPTY_PATH | AT_NO_AUTOMOUNT,
+        )
+    }
+
+    /// Creates a new [`FileStat`] [`Future`] for `path` relative to `dir`
+    pub(super) fn new_at(dir: &'a Dir, path: &Path) -> Self {
+        let path = CString::new(path.as_os_str().as_encoded_bytes()).map_err(|_| Error::EINVAL);
+
+        FileStat::new_raw(unsafe { dir.fd() }, path, AT_NO_AUTOMOUNT)
+    }
+
+    /// Creates a new [`FileStat`] [`Future`] for `path` relative to `fd` with `flags`
+    fn new_raw(fd: c_int, path: Result<CString>, flags: c_int) -> Self {
         let event_id = EventRef::register(EventHandler::integer(stat_callback));
 
         FileStat {
-            file,
+            fd,
+            path,
+            flags,
             buffer: Statx::default(),
             event_id,
             sqe_submitted: false,
+            _lifetime: PhantomData,
         }
     }
 
-    /// Projects pinned self into `(self.file, self.event_id, self.buffer, self.sqe_submitted)`
+    /// Projects pinned self into `(self.fd, self.path, self.flags, self.event_id, self.buffer,
+    /// self.sqe_submitted)`
     ///
     /// # SAFTEY
     /// This is the only way to access the contained `buffer`, do not access it directly.
     unsafe fn project(
         self: Pin<&mut Self>,
-    ) -> (&File, Result<EventID>, Pin<&mut Statx>, &mut bool) {
+    ) -> (
+        c_int,
+        &Result<CString>,
+        c_int,
+        Result<EventID>,
+        Pin<&mut Statx>,
+        &mut bool,
+    ) {
         let this = self.get_unchecked_mut();
 
         (
-            this.file,
+            this.fd,
+            &this.path,
+            this.flags,
             this.event_id
                 .as_ref()
                 .map(|event_id| **event_id)
@@ -87,7 +128,7 @@ impl<'a> Future for FileStat<'a> {
     type Output = Result<Metadata>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (file, event_id, buffer, sqe_submitted) = unsafe { self.project() };
+        let (fd, path, flags, event_id, buffer, sqe_submitted) = unsafe { self.project() };
 
         let event_id = match event_id {
             Ok(event_id) => event_id,
@@ -99,14 +140,19 @@ impl<'a> Future for FileStat<'a> {
 
             // Submit the SQE if one hasn't been submitted yet
             if !*sqe_submitted {
+                let path = match path {
+                    Ok(path) => path.as_ptr(),
+                    Err(error) => return Poll::Ready(Err(*error)),
+                };
+
                 let sqe = manager.get_sqe(event_id).unwrap();
 
                 unsafe {
                     io_uring_prep_statx(
                         sqe.as_ptr(),
-                        file.fd(),
-                        b"\0".as_ptr().cast(),
-                        AT_EMPTY_PATH | AT_NO_AUTOMOUNT,
+                        fd,
+                        path,
+                        flags,
                         STATX_BASIC_STATS as _,
                         buffer,
                     )
@@ -0,0 +1,138 @@
+use crate::{fd::AsFD, fs::File, EventRef};
+use executor::{
+    platform::{
+        uring::{
+            io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_fsync,
+            io_uring_prep_sync_file_range,
+        },
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields when the data of a [`File`] has been flushed to disk
+pub struct FileSync<'a> {
+    /// The [`File`] to flush
+    file: &'a File,
+
+    /// The range of the file to flush as `(offset, length)`, or the entire file if [`None`]
+    range: Option<(u64, u32)>,
+
+    /// The flags passed to the sync
+    flags: u32,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the sync.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a sync is completed
+fn sync_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> FileSync<'a> {
+    /// Creates a new [`FileSync`] [`Future`] which flushes all of `file` with the fsync `flags`
+    pub(super) fn new(file: &'a File, flags: u32) -> Self {
+        FileSync::new_raw(file, None, flags)
+    }
+
+    /// Creates a new [`FileSync`] [`Future`] which flushes `length` bytes of `file` starting at
+    /// `offset` with the `sync_file_range` `flags`
+    pub(super) fn new_range(file: &'a File, offset: u64, length: u32, flags: u32) -> Self {
+        FileSync::new_raw(file, Some((offset, length)), flags)
+    }
+
+    /// Creates a new [`FileSync`] [`Future`]
+    fn new_raw(file: &'a File, range: Option<(u64, u32)>, flags: u32) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(sync_callback));
+
+        FileSync {
+            file,
+            range,
+            flags,
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+}
+
+impl<'a> Future for FileSync<'a> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                match self.range {
+                    Some((offset, length)) => unsafe {
+                        io_uring_prep_sync_file_range(
+                            sqe.as_ptr(),
+                            self.file.fd(),
+                            length,
+                            offset,
+                            self.flags as _,
+                        )
+                    },
+                    None => unsafe {
+                        io_uring_prep_fsync(sqe.as_ptr(), self.file.fd(), self.flags)
+                    },
+                }
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(()))
+        })
+    }
+}
+
+impl<'a> Drop for FileSync<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
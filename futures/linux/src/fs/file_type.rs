@@ -1,3 +1,7 @@
+use executor::platform::linux::sys::stat::{
+    s_isblk, s_ischr, s_isdir, s_isfifo, s_islnk, s_isreg, s_issock, Statx,
+};
+
 /// The type of a file
 ///
 /// This uses integers internally instead of an enum to make this an opaque, unconstructable type.
@@ -13,6 +17,21 @@ const DIRECTORY: u8 = 1;
 /// The value representing a symbolic link
 const SYMLINK: u8 = 2;
 
+/// The value representing a named pipe
+const FIFO: u8 = 3;
+
+/// The value representing a unix domain socket
+const SOCKET: u8 = 4;
+
+/// The value representing a character device
+const CHAR_DEVICE: u8 = 5;
+
+/// The value representing a block device
+const BLOCK_DEVICE: u8 = 6;
+
+/// The value representing any other type of file
+const OTHER: u8 = 7;
+
 impl FileType {
     /// The file is a file
     pub(super) const FILE: Self = FileType(FILE);
@@ -23,6 +42,42 @@ impl FileType {
     /// The file is a symlink
     pub(super) const SYMLINK: Self = FileType(SYMLINK);
 
+    /// The file is a named pipe
+    pub(super) const FIFO: Self = FileType(FIFO);
+
+    /// The file is a unix domain socket
+    pub(super) const SOCKET: Self = FileType(SOCKET);
+
+    /// The file is a character device
+    pub(super) const CHAR_DEVICE: Self = FileType(CHAR_DEVICE);
+
+    /// The file is a block device
+    pub(super) const BLOCK_DEVICE: Self = FileType(BLOCK_DEVICE);
+
+    /// The file is of a type not listed above
+    pub(super) const OTHER: Self = FileType(OTHER);
+
+    /// Gets the type of the file described by `statx`
+    pub(super) fn from_statx(statx: &Statx) -> Self {
+        if s_isreg(statx.mode) {
+            FileType::FILE
+        } else if s_isdir(statx.mode) {
+            FileType::DIRECTORY
+        } else if s_islnk(statx.mode) {
+            FileType::SYMLINK
+        } else if s_isfifo(statx.mode) {
+            FileType::FIFO
+        } else if s_issock(statx.mode) {
+            FileType::SOCKET
+        } else if s_ischr(statx.mode) {
+            FileType::CHAR_DEVICE
+        } else if s_isblk(statx.mode) {
+            FileType::BLOCK_DEVICE
+        } else {
+            FileType::OTHER
+        }
+    }
+
     /// Is the file a file?
     pub fn is_file(&self) -> bool {
         self.0 == FILE
@@ -37,4 +92,24 @@ impl FileType {
     pub fn is_symlink(&self) -> bool {
         self.0 == SYMLINK
     }
+
+    /// Is the file a named pipe?
+    pub fn is_fifo(&self) -> bool {
+        self.0 == FIFO
+    }
+
+    /// Is the file a unix domain socket?
+    pub fn is_socket(&self) -> bool {
+        self.0 == SOCKET
+    }
+
+    /// Is the file a character device?
+    pub fn is_char_device(&self) -> bool {
+        self.0 == CHAR_DEVICE
+    }
+
+    /// Is the file a block device?
+    pub fn is_block_device(&self) -> bool {
+        self.0 == BLOCK_DEVICE
+    }
 }
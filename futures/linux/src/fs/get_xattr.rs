@@ -0,0 +1,172 @@
+use crate::{fs::xattr::XattrTarget, EventRef};
+use executor::{
+    platform::{
+        uring::{
+            io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_fgetxattr, io_uring_prep_getxattr,
+        },
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::{c_int, CString, OsStr},
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    ptr::null_mut,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields the value of an extended attribute
+pub struct GetXattr<'a> {
+    /// The file to get the attribute from
+    target: XattrTarget,
+
+    /// The name of the attribute
+    name: Result<CString>,
+
+    /// The buffer for the value of the attribute
+    buffer: Vec<u8>,
+
+    /// Has `buffer` been sized for the value?
+    sized: bool,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+
+    /// A marker for the lifetime of the file
+    _lifetime: PhantomData<&'a ()>,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the get.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a get is completed
+fn get_xattr_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> GetXattr<'a> {
+    /// Creates a new [`GetXattr`] [`Future`] which gets the attribute `name` from `target`
+    pub(super) fn new(target: XattrTarget, name: &OsStr) -> Self {
+        let name = CString::new(name.as_encoded_bytes()).map_err(|_| Error::EINVAL);
+
+        let event_id = EventRef::register(EventHandler::integer(get_xattr_callback));
+
+        GetXattr {
+            target,
+            name,
+            buffer: Vec::new(),
+            sized: false,
+            event_id,
+            sqe_submitted: false,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'a> Future for GetXattr<'a> {
+    type Output = Result<Vec<u8>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| loop {
+            // Submit the SQE if one hasn't been submitted yet. Until the buffer is sized, this
+            // queries the length of the value.
+            if !self.sqe_submitted {
+                let name = match self.name.as_ref() {
+                    Ok(name) => name.as_ptr(),
+                    Err(error) => return Poll::Ready(Err(*error)),
+                };
+
+                let length = self.buffer.len();
+                let value = if self.sized {
+                    self.buffer.as_mut_ptr().cast()
+                } else {
+                    null_mut()
+                };
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                match &self.target {
+                    XattrTarget::FD(fd) => unsafe {
+                        io_uring_prep_fgetxattr(sqe.as_ptr(), *fd, name, value, length as _)
+                    },
+                    XattrTarget::Path(path) => {
+                        let path = match path {
+                            Ok(path) => path.as_ptr(),
+                            Err(error) => return Poll::Ready(Err(*error)),
+                        };
+
+                        unsafe {
+                            io_uring_prep_getxattr(sqe.as_ptr(), name, value, path, length as _)
+                        }
+                    }
+                }
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            event.data_mut().set_integer(0);
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                let error = Error::new(-result);
+
+                // The value grew between querying the length and reading it
+                if error == Error::ERANGE && self.sized {
+                    self.sized = false;
+                    continue;
+                }
+
+                return Poll::Ready(Err(error));
+            }
+
+            if !self.sized {
+                self.buffer = vec![0; result as usize];
+                self.sized = true;
+
+                if result > 0 {
+                    continue;
+                }
+            }
+
+            let mut buffer = std::mem::take(&mut self.buffer);
+            buffer.truncate(result as usize);
+            return Poll::Ready(Ok(buffer));
+        })
+    }
+}
+
+impl<'a> Drop for GetXattr<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{fd::AsFD, fs::File, TimerRef};
+use executor::{
+    platform::linux::{
+        sys::file::{flock, LOCK_NB},
+        try_linux,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+/// A [`Future`] which yields when a lock on a [`File`] has been acquired
+///
+/// There is no `io_uring` operation for locking files, and a blocking `flock` can't be cancelled
+/// without leaving a thread behind which may take the lock after the future is gone. Instead, a
+/// contended lock is retried on a timer, doubling the delay between attempts up to 64ms. Because of
+/// this, waiters don't take the lock in the order they started waiting.
+pub struct Lock<'a> {
+    /// The [`File`] to lock
+    file: &'a File,
+
+    /// The `flock` operation to perform
+    operation: c_int,
+
+    /// The delay before the next attempt
+    backoff: Duration,
+
+    /// The timer for the next attempt, if the lock has been contended
+    timer: Option<TimerRef>,
+}
+
+/// The delay before retrying a contended lock for the first time
+const MIN_BACKOFF: Duration = Duration::from_millis(1);
+
+/// The longest delay between attempts to take a contended lock
+const MAX_BACKOFF: Duration = Duration::from_millis(64);
+
+impl<'a> Lock<'a> {
+    /// Creates a new [`Lock`] [`Future`] which performs the `flock` `operation` on `file`
+    pub(super) fn new(file: &'a File, operation: c_int) -> Self {
+        Lock {
+            file,
+            operation,
+            backoff: MIN_BACKOFF,
+            timer: None,
+        }
+    }
+}
+
+impl<'a> Future for Lock<'a> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            // Wait for the next attempt
+            if let Some(timer) = &self.timer {
+                let fired = EventManager::get_local_mut(|manager| {
+                    manager.timers_mut().poll(**timer, cx.waker())
+                });
+
+                if !fired {
+                    return Poll::Pending;
+                }
+            }
+
+            match try_linux!(flock(self.file.fd(), self.operation | LOCK_NB)) {
+                Ok(_) => {
+                    self.timer = None;
+                    return Poll::Ready(Ok(()));
+                }
+                Err(error) if error == Error::EWOULDBLOCK => {}
+                Err(error) => return Poll::Ready(Err(error)),
+            }
+
+            let deadline = crate::time::now() + self.backoff;
+            match &self.timer {
+                Some(timer) => EventManager::get_local_mut(|manager| {
+                    manager.timers_mut().reset(**timer, deadline)
+                }),
+                None => self.timer = Some(TimerRef::insert(deadline)),
+            }
+
+            self.backoff = (self.backoff * 2).min(MAX_BACKOFF);
+        }
+    }
+}
+
+impl<'a> !Send for Lock<'a> {}
+impl<'a> !Sync for Lock<'a> {}
@@ -1,4 +1,4 @@
-use executor::platform::linux::sys::stat::{s_isdir, s_islnk, Statx};
+use executor::platform::linux::sys::stat::Statx;
 
 use crate::fs::FileType;
 
@@ -16,13 +16,7 @@ impl Metadata {
     pub(super) fn new(statx: &Statx) -> Self {
         let length = statx.size;
 
-        let file_type = if s_isdir(statx.mode) {
-            FileType::DIRECTORY
-        } else if s_islnk(statx.mode) {
-            FileType::SYMLINK
-        } else {
-            FileType::FILE
-        };
+        let file_type = FileType::from_statx(statx);
 
         Metadata { file_type, length }
     }
@@ -1,17 +1,47 @@
 //! Futures for interacting with the filesystem
 
+mod advice;
+mod advise;
+mod allocate;
+mod create_dir;
+mod dir;
 mod file;
 mod file_stat;
+mod file_sync;
 mod file_type;
+mod get_xattr;
+mod lock;
 mod metadata;
 mod open;
+mod open_dir;
 mod open_options;
 mod read;
+mod read_dir;
+mod remove;
+mod rename;
+mod set_len;
+mod set_xattr;
+mod xattr;
 
+pub use advice::Advice;
+pub use advise::Advise;
+pub use allocate::Allocate;
+pub use create_dir::CreateDir;
+pub use dir::Dir;
 pub use file::File;
 pub use file_stat::FileStat;
+pub use file_sync::FileSync;
 pub use file_type::FileType;
+pub use get_xattr::GetXattr;
+pub use lock::Lock;
 pub use metadata::Metadata;
 pub use open::Open;
+pub use open_dir::OpenDir;
 pub use open_options::OpenOptions;
 pub use read::read;
+pub use read_dir::{DirEntry, ReadDir};
+pub use remove::Remove;
+pub use rename::Rename;
+pub use set_len::SetLen;
+pub use set_xattr::SetXattr;
+pub use xattr::{get_xattr, list_xattr, remove_xattr, set_xattr};
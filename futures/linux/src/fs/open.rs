@@ -1,4 +1,8 @@
-use crate::{fs::File, EventRef};
+use crate::{
+    fd::AsFD,
+    fs::{Dir, File},
+    EventRef,
+};
 use executor::{
     platform::{
         linux::fcntl::AT_FDCWD,
@@ -10,13 +14,17 @@ use executor::{
 use std::{
     ffi::{c_int, CString},
     future::Future,
+    marker::PhantomData,
     path::Path,
     pin::Pin,
     task::{Context, Poll},
 };
 
 /// A [`Future`] which yields when a file open is complete
-pub struct Open {
+pub struct Open<'a> {
+    /// The directory `path` is relative to
+    dir: c_int,
+
     /// The path to open
     path: Result<CString>,
 
@@ -28,6 +36,9 @@ pub struct Open {
 
     /// Has the SQE been submitted?
     sqe_submitted: bool,
+
+    /// A marker for the lifetime of the directory
+    _dir: PhantomData<&'a Dir>,
 }
 
 /// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
@@ -39,23 +50,39 @@ fn open_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
     *value = (cqe.res as usize) | SIGNAL_BIT;
 }
 
-impl Open {
+impl Open<'static> {
     /// Creates a new [`Open`] [`Future`] to open the file at `path` with `options`
     pub(super) fn new(path: &Path, options: Result<c_int>) -> Self {
+        Open::new_raw(AT_FDCWD, path, options)
+    }
+}
+
+impl<'a> Open<'a> {
+    /// Creates a new [`Open`] [`Future`] to open the file at `path` relative to `dir` with
+    /// `options`
+    pub(super) fn new_at(dir: &'a Dir, path: &Path, options: Result<c_int>) -> Self {
+        Open::new_raw(unsafe { dir.fd() }, path, options)
+    }
+
+    /// Creates a new [`Open`] [`Future`] to open the file at `path` relative to the directory file
+    /// descriptor `dir` with `options`
+    fn new_raw(dir: c_int, path: &Path, options: Result<c_int>) -> Self {
         let path = CString::new(path.as_os_str().as_encoded_bytes()).map_err(|_| Error::EINVAL);
 
         let event_id = EventRef::register(EventHandler::integer(open_callback));
 
         Open {
+            dir,
             path,
             options,
             event_id,
             sqe_submitted: false,
+            _dir: PhantomData,
         }
     }
 }
 
-impl Future for Open {
+impl<'a> Future for Open<'a> {
     type Output = Result<File>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
@@ -79,7 +106,7 @@ impl Future for Open {
 
                 let sqe = manager.get_sqe(event_id).unwrap();
 
-                unsafe { io_uring_prep_openat(sqe.as_ptr(), AT_FDCWD, path, options, 0o777) };
+                unsafe { io_uring_prep_openat(sqe.as_ptr(), self.dir, path, options, 0o777) };
 
                 sqe.submit().unwrap();
                 self.sqe_submitted = true;
@@ -104,7 +131,7 @@ impl Future for Open {
     }
 }
 
-impl Drop for Open {
+impl<'a> Drop for Open<'a> {
     fn drop(&mut self) {
         if let Ok(event_id) = &self.event_id {
             if self.sqe_submitted {
@@ -0,0 +1,38 @@
+use crate::fs::{Dir, Open};
+use executor::{
+    platform::linux::fcntl::{O_DIRECTORY, O_RDONLY},
+    Result,
+};
+use std::{
+    future::Future,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields when a directory open is complete
+pub struct OpenDir<'a>(Open<'a>);
+
+impl OpenDir<'static> {
+    /// Creates a new [`OpenDir`] [`Future`] to open the directory at `path`
+    pub(super) fn new(path: &Path) -> Self {
+        OpenDir(Open::new(path, Ok(O_RDONLY | O_DIRECTORY)))
+    }
+}
+
+impl<'a> OpenDir<'a> {
+    /// Creates a new [`OpenDir`] [`Future`] to open the directory at `path` relative to `dir`
+    pub(super) fn new_at(dir: &'a Dir, path: &Path) -> Self {
+        OpenDir(Open::new_at(dir, path, Ok(O_RDONLY | O_DIRECTORY)))
+    }
+}
+
+impl<'a> Future for OpenDir<'a> {
+    type Output = Result<Dir>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|result| result.map(|file| Dir::new(file.into_fd())))
+    }
+}
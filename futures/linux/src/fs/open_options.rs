@@ -1,4 +1,4 @@
-use crate::fs::Open;
+use crate::fs::{Dir, Open};
 use executor::{
     platform::linux::fcntl::{O_APPEND, O_CREAT, O_EXCL, O_RDONLY, O_RDWR, O_TRUNC, O_WRONLY},
     Error, Result,
@@ -29,10 +29,15 @@ impl OpenOptions {
     }
 
     /// Opens the file at `path` with the options specified in `self`
-    pub fn open<P: AsRef<Path>>(&self, path: P) -> Open {
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> Open<'static> {
         Open::new(path.as_ref(), self.get_options())
     }
 
+    /// Opens the file at `path` relative to `dir` with the options specified in `self`
+    pub fn open_at<'a, P: AsRef<Path>>(&self, dir: &'a Dir, path: P) -> Open<'a> {
+        Open::new_at(dir, path.as_ref(), self.get_options())
+    }
+
     /// Sets the read access for the file
     pub fn read(&mut self, read: bool) -> &mut Self {
         self.read = read;
@@ -0,0 +1,208 @@
+use crate::{
+    fd::AsFD,
+    fs::{Dir, FileType},
+};
+use executor::{
+    platform::linux::{
+        fcntl::{openat, AT_NO_AUTOMOUNT, AT_SYMLINK_NOFOLLOW, O_CLOEXEC, O_DIRECTORY, O_RDONLY},
+        sys::{
+            stat::{statx, Statx, STATX_TYPE},
+            syscall::{syscall, SYS_getdents64},
+        },
+        try_linux,
+        unistd::close,
+    },
+    Error, Result,
+};
+use std::{
+    ffi::{c_int, OsStr, OsString},
+    os::unix::ffi::OsStrExt,
+};
+
+/// An iterator over the entries in a [`Dir`]
+///
+/// There is no `io_uring` operation for reading directories, so entries are read synchronously in
+/// batches.
+pub struct ReadDir {
+    /// The file descriptor being read, independent of the [`Dir`] it came from
+    fd: c_int,
+
+    /// The buffer of entries returned from the kernel
+    buffer: Box<[u8]>,
+
+    /// The position of the next entry in `buffer`
+    position: usize,
+
+    /// The number of valid bytes in `buffer`
+    length: usize,
+}
+
+/// An entry in a [`Dir`]
+pub struct DirEntry {
+    /// The name of the entry
+    name: OsString,
+
+    /// The type of the entry
+    file_type: FileType,
+}
+
+/// The size of the buffer used to read entries
+const BUFFER_SIZE: usize = 4096;
+
+/// The offset of `d_reclen` in `linux_dirent64`
+const RECLEN_OFFSET: usize = 16;
+
+/// The offset of `d_type` in `linux_dirent64`
+const TYPE_OFFSET: usize = 18;
+
+/// The offset of `d_name` in `linux_dirent64`
+const NAME_OFFSET: usize = 19;
+
+/// The `d_type` of a named pipe
+const DT_FIFO: u8 = 1;
+
+/// The `d_type` of a character device
+const DT_CHR: u8 = 2;
+
+/// The `d_type` of a directory
+const DT_DIR: u8 = 4;
+
+/// The `d_type` of a block device
+const DT_BLK: u8 = 6;
+
+/// The `d_type` of a regular file
+const DT_REG: u8 = 8;
+
+/// The `d_type` of a symbolic link
+const DT_LNK: u8 = 10;
+
+/// The `d_type` of a unix domain socket
+const DT_SOCK: u8 = 12;
+
+impl ReadDir {
+    /// Creates a new [`ReadDir`] over the entries of `dir`
+    pub(super) fn new(dir: &Dir) -> Result<Self> {
+        // Open a new descriptor so the read position isn't shared with `dir`
+        let fd = try_linux!(openat(
+            dir.fd(),
+            b".\0".as_ptr().cast(),
+            O_RDONLY | O_DIRECTORY | O_CLOEXEC,
+            0
+        ))?;
+
+        Ok(ReadDir {
+            fd,
+            buffer: vec![0; BUFFER_SIZE].into_boxed_slice(),
+            position: 0,
+            length: 0,
+        })
+    }
+
+    /// Reads the next batch of entries into the buffer, returning `false` if there are none left
+    fn fill_buffer(&mut self) -> Result<bool> {
+        let length = try_linux!(syscall(
+            SYS_getdents64,
+            self.fd,
+            self.buffer.as_mut_ptr(),
+            self.buffer.len()
+        ))?;
+
+        self.position = 0;
+        self.length = length as usize;
+        Ok(self.length > 0)
+    }
+
+    /// Parses the entry at the current position, advancing past it
+    ///
+    /// Filesystems which don't report the type of entries have it looked up with [`statx`].
+    fn next_entry(&mut self) -> Result<DirEntry> {
+        let entry = &self.buffer[self.position..self.length];
+
+        let record_length =
+            u16::from_ne_bytes([entry[RECLEN_OFFSET], entry[RECLEN_OFFSET + 1]]) as usize;
+        let d_type = entry[TYPE_OFFSET];
+
+        let name = &entry[NAME_OFFSET..record_length];
+        let name_length = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+
+        let file_type = match d_type {
+            DT_REG => FileType::FILE,
+            DT_DIR => FileType::DIRECTORY,
+            DT_LNK => FileType::SYMLINK,
+            DT_FIFO => FileType::FIFO,
+            DT_SOCK => FileType::SOCKET,
+            DT_CHR => FileType::CHAR_DEVICE,
+            DT_BLK => FileType::BLOCK_DEVICE,
+            _ => {
+                // `name` is null-terminated inside of the record
+                let mut buffer = Statx::default();
+                let result = try_linux!(statx(
+                    self.fd,
+                    name.as_ptr().cast(),
+                    AT_SYMLINK_NOFOLLOW | AT_NO_AUTOMOUNT,
+                    STATX_TYPE as _,
+                    &mut buffer
+                ));
+
+                match result {
+                    Ok(_) => FileType::from_statx(&buffer),
+                    Err(error) => {
+                        self.position += record_length;
+                        return Err(error);
+                    }
+                }
+            }
+        };
+
+        let name = OsStr::from_bytes(&name[..name_length]).to_owned();
+
+        self.position += record_length;
+
+        Ok(DirEntry { name, file_type })
+    }
+}
+
+impl Iterator for ReadDir {
+    type Item = Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.position >= self.length {
+                match self.fill_buffer() {
+                    Ok(true) => {}
+                    Ok(false) => return None,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+
+            match self.next_entry() {
+                Ok(entry) => {
+                    if entry.name != "." && entry.name != ".." {
+                        return Some(Ok(entry));
+                    }
+                }
+                // The entry was removed after the directory was read
+                Err(error) if error == Error::ENOENT => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+impl Drop for ReadDir {
+    fn drop(&mut self) {
+        unsafe { close(self.fd) };
+    }
+}
+
+impl DirEntry {
+    /// Gets the name of this entry
+    pub fn file_name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Gets the type of this entry
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+}
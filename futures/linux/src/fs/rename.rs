@@ -0,0 +1,130 @@
+use crate::{fd::AsFD, fs::Dir, EventRef};
+use executor::{
+    platform::{
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_renameat},
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::{c_int, CString},
+    future::Future,
+    marker::PhantomData,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields when a file or directory has been renamed
+pub struct Rename<'a> {
+    /// The directory `from` and `to` are relative to
+    dir: c_int,
+
+    /// The path of the entry to rename
+    from: Result<CString>,
+
+    /// The new path for the entry
+    to: Result<CString>,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+
+    /// A marker for the lifetime of the directory
+    _dir: PhantomData<&'a Dir>,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the rename.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a rename is completed
+fn rename_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> Rename<'a> {
+    /// Creates a new [`Rename`] [`Future`] to rename `from` to `to`, both relative to `dir`
+    pub(super) fn new_at(dir: &'a Dir, from: &Path, to: &Path) -> Self {
+        let from = CString::new(from.as_os_str().as_encoded_bytes()).map_err(|_| Error::EINVAL);
+        let to = CString::new(to.as_os_str().as_encoded_bytes()).map_err(|_| Error::EINVAL);
+
+        let event_id = EventRef::register(EventHandler::integer(rename_callback));
+
+        Rename {
+            dir: unsafe { dir.fd() },
+            from,
+            to,
+            event_id,
+            sqe_submitted: false,
+            _dir: PhantomData,
+        }
+    }
+}
+
+impl<'a> Future for Rename<'a> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let from = match self.from.as_ref() {
+                    Ok(from) => from.as_ptr(),
+                    Err(error) => return Poll::Ready(Err(*error)),
+                };
+
+                let to = match self.to.as_ref() {
+                    Ok(to) => to.as_ptr(),
+                    Err(error) => return Poll::Ready(Err(*error)),
+                };
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe { io_uring_prep_renameat(sqe.as_ptr(), self.dir, from, self.dir, to, 0) };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(()))
+        })
+    }
+}
+
+impl<'a> Drop for Rename<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{fd::AsFD, fs::File, EventRef};
+use executor::{
+    platform::{
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_ftruncate},
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields when the length of a [`File`] has been changed
+pub struct SetLen<'a> {
+    /// The [`File`] to resize
+    file: &'a File,
+
+    /// The new length of the file in bytes
+    length: u64,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the truncate.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a truncate is completed
+fn set_len_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> SetLen<'a> {
+    /// Creates a new [`SetLen`] [`Future`] which sets the length of `file` to `length`
+    pub(super) fn new(file: &'a File, length: u64) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(set_len_callback));
+
+        SetLen {
+            file,
+            length,
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+}
+
+impl<'a> Future for SetLen<'a> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let length = match i64::try_from(self.length) {
+                    Ok(length) => length,
+                    Err(_) => return Poll::Ready(Err(Error::EFBIG)),
+                };
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe { io_uring_prep_ftruncate(sqe.as_ptr(), self.file.fd(), length as _) };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(()))
+        })
+    }
+}
+
+impl<'a> Drop for SetLen<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{fs::xattr::XattrTarget, EventRef};
+use executor::{
+    platform::{
+        uring::{
+            io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_fsetxattr, io_uring_prep_setxattr,
+        },
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::{c_int, CString, OsStr},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A [`Future`] which yields when an extended attribute has been set
+pub struct SetXattr<'a> {
+    /// The file to set the attribute on
+    target: XattrTarget,
+
+    /// The name of the attribute
+    name: Result<CString>,
+
+    /// The new value of the attribute
+    value: &'a [u8],
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the set.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a set is completed
+fn set_xattr_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> SetXattr<'a> {
+    /// Creates a new [`SetXattr`] [`Future`] which sets the attribute `name` on `target` to
+    /// `value`
+    pub(super) fn new(target: XattrTarget, name: &OsStr, value: &'a [u8]) -> Self {
+        let name = CString::new(name.as_encoded_bytes()).map_err(|_| Error::EINVAL);
+
+        let event_id = EventRef::register(EventHandler::integer(set_xattr_callback));
+
+        SetXattr {
+            target,
+            name,
+            value,
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+}
+
+impl<'a> Future for SetXattr<'a> {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let name = match self.name.as_ref() {
+                    Ok(name) => name.as_ptr(),
+                    Err(error) => return Poll::Ready(Err(*error)),
+                };
+
+                let value = self.value.as_ptr().cast();
+                let length = self.value.len();
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                match &self.target {
+                    XattrTarget::FD(fd) => unsafe {
+                        io_uring_prep_fsetxattr(sqe.as_ptr(), *fd, name, value, 0, length as _)
+                    },
+                    XattrTarget::Path(path) => {
+                        let path = match path {
+                            Ok(path) => path.as_ptr(),
+                            Err(error) => return Poll::Ready(Err(*error)),
+                        };
+
+                        unsafe {
+                            io_uring_prep_setxattr(sqe.as_ptr(), name, value, path, 0, length as _)
+                        }
+                    }
+                }
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(()))
+        })
+    }
+}
+
+impl<'a> Drop for SetXattr<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::fs::{GetXattr, SetXattr};
+use executor::{
+    platform::linux::{
+        sys::xattr::{flistxattr, fremovexattr, listxattr, removexattr},
+        try_linux,
+    },
+    Error, Result,
+};
+use std::{
+    ffi::{c_int, CString, OsStr, OsString},
+    os::unix::ffi::OsStrExt,
+    path::Path,
+    ptr::null_mut,
+};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use std::future::Future;
+
+/// The object an extended attribute operation targets
+pub(super) enum XattrTarget {
+    /// An open file descriptor
+    FD(c_int),
+
+    /// A path on the filesystem
+    Path(Result<CString>),
+}
+
+/// Returns a [`Future`] which yields the value of the extended attribute `name` on the file at
+/// `path`
+pub fn get_xattr<P: AsRef<Path>, N: AsRef<OsStr>>(path: P, name: N) -> GetXattr<'static> {
+    GetXattr::new(XattrTarget::path(path.as_ref()), name.as_ref())
+}
+
+/// Returns a [`Future`] which sets the extended attribute `name` on the file at `path` to `value`
+pub fn set_xattr<'a, P: AsRef<Path>, N: AsRef<OsStr>>(
+    path: P,
+    name: N,
+    value: &'a [u8],
+) -> SetXattr<'a> {
+    SetXattr::new(XattrTarget::path(path.as_ref()), name.as_ref(), value)
+}
+
+/// Gets the names of the extended attributes on the file at `path`
+///
+/// There is no `io_uring` operation for listing extended attributes, so this is performed
+/// synchronously.
+pub fn list_xattr<P: AsRef<Path>>(path: P) -> Result<Vec<OsString>> {
+    XattrTarget::path(path.as_ref()).list()
+}
+
+/// Removes the extended attribute `name` from the file at `path`
+///
+/// There is no `io_uring` operation for removing extended attributes, so this is performed
+/// synchronously.
+pub fn remove_xattr<P: AsRef<Path>, N: AsRef<OsStr>>(path: P, name: N) -> Result<()> {
+    XattrTarget::path(path.as_ref()).remove(name.as_ref())
+}
+
+impl XattrTarget {
+    /// Creates a new [`XattrTarget`] for `path`
+    pub(super) fn path(path: &Path) -> Self {
+        XattrTarget::Path(
+            CString::new(path.as_os_str().as_encoded_bytes()).map_err(|_| Error::EINVAL),
+        )
+    }
+
+    /// Gets the names of the extended attributes on this target
+    pub(super) fn list(&self) -> Result<Vec<OsString>> {
+        loop {
+            let size = self.list_raw(&mut [])?;
+
+            let mut buffer = vec![0; size];
+            let size = match self.list_raw(&mut buffer) {
+                Ok(size) => size,
+                // The list grew between the calls
+                Err(error) if error == Error::ERANGE => continue,
+                Err(error) => return Err(error),
+            };
+            buffer.truncate(size);
+
+            return Ok(buffer
+                .split(|c| *c == 0)
+                .filter(|name| !name.is_empty())
+                .map(|name| OsStr::from_bytes(name).to_owned())
+                .collect());
+        }
+    }
+
+    /// Removes the extended attribute `name` from this target
+    pub(super) fn remove(&self, name: &OsStr) -> Result<()> {
+        let name = CString::new(name.as_encoded_bytes()).map_err(|_| Error::EINVAL)?;
+
+        match self {
+            XattrTarget::FD(fd) => try_linux!(fremovexattr(*fd, name.as_ptr())),
+            XattrTarget::Path(path) => try_linux!(removexattr(
+                path.as_ref().map_err(|error| *error)?.as_ptr(),
+                name.as_ptr()
+            )),
+        }
+        .map(|_| ())
+    }
+
+    /// Lists the names of the extended attributes into `buffer`, returning the size of the list.
+    /// If `buffer` is empty, the required size is returned.
+    fn list_raw(&self, buffer: &mut [u8]) -> Result<usize> {
+        let list = if buffer.is_empty() {
+            null_mut()
+        } else {
+            buffer.as_mut_ptr().cast()
+        };
+
+        match self {
+            XattrTarget::FD(fd) => try_linux!(flistxattr(*fd, list, buffer.len())),
+            XattrTarget::Path(path) => try_linux!(listxattr(
+                path.as_ref().map_err(|error| *error)?.as_ptr(),
+                list,
+                buffer.len()
+            )),
+        }
+        .map(|size| size as usize)
+    }
+}
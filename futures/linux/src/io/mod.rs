@@ -1,7 +1,16 @@
 //! Futures for input and output
 
+mod pipe;
 mod read;
+mod splice;
+mod stdio;
+mod tee;
 mod write;
 
+pub use crate::fd::AsFD;
+pub use pipe::{pipe, Pipe, PipeReader, PipeWriter};
 pub use read::Read;
+pub use splice::{splice, Splice};
+pub use stdio::{stderr, stdin, stdout, Stderr, Stdin, Stdout};
+pub use tee::{tee, Tee};
 pub use write::Write;
@@ -0,0 +1,103 @@
+use crate::{
+    fd::{AsFD, FDRead, FDWrite},
+    io::{Read, Write},
+};
+use executor::{
+    platform::linux::{
+        fcntl::O_CLOEXEC,
+        try_linux,
+        unistd::{close, pipe2},
+    },
+    Result,
+};
+use std::{ffi::c_int, future::Future};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use crate::io::splice;
+
+/// An anonymous pipe, a unidirectional buffer in the kernel
+///
+/// A [`Pipe`] is useful as the intermediate buffer for [`splice`] between two descriptors which
+/// are not pipes.
+pub struct Pipe {
+    /// The end of the pipe which is read from
+    reader: PipeReader,
+
+    /// The end of the pipe which is written to
+    writer: PipeWriter,
+}
+
+/// The reading end of a [`Pipe`]
+pub struct PipeReader(c_int);
+
+/// The writing end of a [`Pipe`]
+pub struct PipeWriter(c_int);
+
+/// Creates a new anonymous pipe, returning the reading and writing ends
+pub fn pipe() -> Result<(PipeReader, PipeWriter)> {
+    let mut fds: [c_int; 2] = [0; 2];
+    try_linux!(pipe2(fds.as_mut_ptr(), O_CLOEXEC))?;
+
+    Ok((PipeReader(fds[0]), PipeWriter(fds[1])))
+}
+
+impl Pipe {
+    /// Creates a new [`Pipe`]
+    pub fn new() -> Result<Self> {
+        let (reader, writer) = pipe()?;
+
+        Ok(Pipe { reader, writer })
+    }
+
+    /// Gets the reading end of this pipe
+    pub fn reader(&self) -> &PipeReader {
+        &self.reader
+    }
+
+    /// Gets the writing end of this pipe
+    pub fn writer(&self) -> &PipeWriter {
+        &self.writer
+    }
+
+    /// Splits this pipe into its reading and writing ends
+    pub fn into_split(self) -> (PipeReader, PipeWriter) {
+        (self.reader, self.writer)
+    }
+}
+
+impl AsFD for PipeReader {
+    unsafe fn fd(&self) -> c_int {
+        self.0
+    }
+}
+
+impl Read for PipeReader {
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDRead::new(self, buf)
+    }
+}
+
+impl Drop for PipeReader {
+    fn drop(&mut self) {
+        unsafe { close(self.0) };
+    }
+}
+
+impl AsFD for PipeWriter {
+    unsafe fn fd(&self) -> c_int {
+        self.0
+    }
+}
+
+impl Write for PipeWriter {
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
+
+impl Drop for PipeWriter {
+    fn drop(&mut self) {
+        unsafe { close(self.0) };
+    }
+}
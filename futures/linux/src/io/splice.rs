@@ -0,0 +1,161 @@
+use crate::{AsFD, EventRef};
+use executor::{
+    platform::{
+        linux::fcntl::SPLICE_F_MOVE,
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_splice},
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use crate::io::Pipe;
+
+/// A [`Future`] which yields after moving bytes between two file descriptors without copying them
+/// through userspace
+pub struct Splice<'a> {
+    /// The descriptor to move bytes from
+    from: c_int,
+
+    /// The offset to read from in `from`, or -1 to use the current position
+    from_offset: i64,
+
+    /// The descriptor to move bytes to
+    to: c_int,
+
+    /// The offset to write to in `to`, or -1 to use the current position
+    to_offset: i64,
+
+    /// The maximum number of bytes to move
+    length: u32,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+
+    /// A marker for the lifetime of the descriptors
+    _lifetime: PhantomData<&'a ()>,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the splice.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a splice is completed
+fn splice_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+/// Moves up to `length` bytes from `from` into `to` without copying them through userspace,
+/// returning the number of bytes moved
+///
+/// At least one of `from` or `to` must be a pipe. To move bytes between two descriptors which are
+/// not pipes, use a [`Pipe`] as an intermediate buffer.
+pub fn splice<'a, F: AsFD, T: AsFD>(from: &'a F, to: &'a T, length: u32) -> Splice<'a> {
+    Splice::new(from, None, to, None, length)
+}
+
+impl<'a> Splice<'a> {
+    /// Creates a new [`Splice`] [`Future`]
+    ///
+    /// If an offset is [`None`], the current position of the descriptor is used. Pipes must not
+    /// have an offset.
+    pub(crate) fn new<F: AsFD, T: AsFD>(
+        from: &'a F,
+        from_offset: Option<u64>,
+        to: &'a T,
+        to_offset: Option<u64>,
+        length: u32,
+    ) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(splice_callback));
+
+        Splice {
+            from: unsafe { from.fd() },
+            from_offset: from_offset.map(|offset| offset as i64).unwrap_or(-1),
+            to: unsafe { to.fd() },
+            to_offset: to_offset.map(|offset| offset as i64).unwrap_or(-1),
+            length,
+            event_id,
+            sqe_submitted: false,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'a> Future for Splice<'a> {
+    type Output = Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_splice(
+                        sqe.as_ptr(),
+                        self.from,
+                        self.from_offset,
+                        self.to,
+                        self.to_offset,
+                        self.length,
+                        SPLICE_F_MOVE as _,
+                    )
+                };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(result as usize))
+        })
+    }
+}
+
+impl<'a> Drop for Splice<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl<'a> !Send for Splice<'a> {}
+impl<'a> !Sync for Splice<'a> {}
@@ -0,0 +1,85 @@
+use crate::{
+    fd::{AsFD, FDRead, FDWrite},
+    io::{Read, Write},
+};
+use executor::{
+    platform::linux::unistd::{STDERR_FILENO, STDIN_FILENO, STDOUT_FILENO},
+    Result,
+};
+use std::{ffi::c_int, future::Future};
+
+/// A handle to the standard input of the process
+///
+/// The underlying descriptor is shared by every handle and is not closed on drop.
+pub struct Stdin {
+    /// Prevents this struct from being constructed elsewhere
+    _priv: (),
+}
+
+/// A handle to the standard output of the process
+///
+/// The underlying descriptor is shared by every handle and is not closed on drop. Writes are not
+/// buffered.
+pub struct Stdout {
+    /// Prevents this struct from being constructed elsewhere
+    _priv: (),
+}
+
+/// A handle to the standard error of the process
+///
+/// The underlying descriptor is shared by every handle and is not closed on drop.
+pub struct Stderr {
+    /// Prevents this struct from being constructed elsewhere
+    _priv: (),
+}
+
+/// Creates a new handle to the standard input of the process
+pub fn stdin() -> Stdin {
+    Stdin { _priv: () }
+}
+
+/// Creates a new handle to the standard output of the process
+pub fn stdout() -> Stdout {
+    Stdout { _priv: () }
+}
+
+/// Creates a new handle to the standard error of the process
+pub fn stderr() -> Stderr {
+    Stderr { _priv: () }
+}
+
+impl AsFD for Stdin {
+    unsafe fn fd(&self) -> c_int {
+        STDIN_FILENO
+    }
+}
+
+impl Read for Stdin {
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDRead::new(self, buf)
+    }
+}
+
+impl AsFD for Stdout {
+    unsafe fn fd(&self) -> c_int {
+        STDOUT_FILENO
+    }
+}
+
+impl Write for Stdout {
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
+
+impl AsFD for Stderr {
+    unsafe fn fd(&self) -> c_int {
+        STDERR_FILENO
+    }
+}
+
+impl Write for Stderr {
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
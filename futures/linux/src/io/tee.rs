@@ -0,0 +1,132 @@
+use crate::{AsFD, EventRef};
+use executor::{
+    platform::{
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_tee},
+        EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use crate::io::Pipe;
+
+/// A [`Future`] which yields after duplicating bytes from one pipe into another without consuming
+/// them
+pub struct Tee<'a> {
+    /// The pipe to duplicate bytes from
+    from: c_int,
+
+    /// The pipe to duplicate bytes into
+    to: c_int,
+
+    /// The maximum number of bytes to duplicate
+    length: u32,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+
+    /// A marker for the lifetime of the descriptors
+    _lifetime: PhantomData<&'a ()>,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the tee.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a tee is completed
+fn tee_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+/// Duplicates up to `length` bytes from `from` into `to` without consuming them from `from`,
+/// returning the number of bytes duplicated
+///
+/// Both `from` and `to` must be pipes, such as the ends of a [`Pipe`].
+pub fn tee<'a, F: AsFD, T: AsFD>(from: &'a F, to: &'a T, length: u32) -> Tee<'a> {
+    Tee::new(from, to, length)
+}
+
+impl<'a> Tee<'a> {
+    /// Creates a new [`Tee`] [`Future`]
+    fn new<F: AsFD, T: AsFD>(from: &'a F, to: &'a T, length: u32) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(tee_callback));
+
+        Tee {
+            from: unsafe { from.fd() },
+            to: unsafe { to.fd() },
+            length,
+            event_id,
+            sqe_submitted: false,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'a> Future for Tee<'a> {
+    type Output = Result<usize>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Err(*error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe { io_uring_prep_tee(sqe.as_ptr(), self.from, self.to, self.length, 0) };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            self.sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(result as usize))
+        })
+    }
+}
+
+impl<'a> Drop for Tee<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl<'a> !Send for Tee<'a> {}
+impl<'a> !Sync for Tee<'a> {}
@@ -13,8 +13,12 @@ pub mod net;
 pub mod sync;
 pub mod time;
 
+mod buffer_ring_ref;
 mod event_ref;
 mod fd;
+mod timer_ref;
 
+use buffer_ring_ref::BufferRingRef;
 use event_ref::EventRef;
 use fd::{AsFD, FDRead};
+use timer_ref::TimerRef;
@@ -0,0 +1,28 @@
+use std::{collections::HashMap, net::IpAddr};
+
+/// Parses the contents of a hosts file (`/etc/hosts`) into a map from lowercase host names to
+/// their addresses
+///
+/// Lines which can't be parsed are skipped, as the C library does.
+pub(super) fn parse_hosts(contents: &str) -> HashMap<String, Vec<IpAddr>> {
+    let mut hosts: HashMap<String, Vec<IpAddr>> = HashMap::new();
+
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap();
+        let mut fields = line.split_whitespace();
+
+        let address = match fields.next().and_then(|address| address.parse().ok()) {
+            Some(address) => address,
+            None => continue,
+        };
+
+        for name in fields {
+            let addresses = hosts.entry(name.to_ascii_lowercase()).or_default();
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+    }
+
+    hosts
+}
@@ -0,0 +1,193 @@
+use executor::{Error, Result};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// The size of a DNS message header
+const HEADER_SIZE: usize = 12;
+
+/// The "recursion desired" flag, asking the server to resolve the name fully
+const FLAG_RECURSION_DESIRED: u16 = 1 << 8;
+
+/// The "truncated" flag, set when the response didn't fit in a UDP datagram
+const FLAG_TRUNCATED: u16 = 1 << 9;
+
+/// The flag set on responses
+const FLAG_RESPONSE: u16 = 1 << 15;
+
+/// The mask for the response code
+const RCODE_MASK: u16 = 0xF;
+
+/// The Internet class
+const CLASS_IN: u16 = 1;
+
+/// The longest a single label may be
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// The longest an encoded name may be
+const MAX_NAME_LENGTH: usize = 255;
+
+/// The type of record being queried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RecordType {
+    /// An IPv4 address ("A")
+    V4 = 1,
+
+    /// An IPv6 address ("AAAA")
+    V6 = 28,
+}
+
+/// The result of a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum ResponseCode {
+    /// The query succeeded
+    NoError,
+
+    /// The name does not exist
+    NameError,
+
+    /// The server failed to answer, another server should be tried
+    Failure,
+}
+
+/// A parsed response to a query
+pub(super) struct Response {
+    /// Did the response not fit in a UDP datagram?
+    pub(super) truncated: bool,
+
+    /// The result of the query
+    pub(super) code: ResponseCode,
+
+    /// The addresses answered for the queried type
+    pub(super) addresses: Vec<IpAddr>,
+}
+
+/// Encodes a recursive query for `record_type` records of `name`
+pub(super) fn encode_query(id: u16, name: &str, record_type: RecordType) -> Result<Vec<u8>> {
+    let mut message = Vec::with_capacity(HEADER_SIZE + name.len() + 6);
+
+    message.extend_from_slice(&id.to_be_bytes());
+    message.extend_from_slice(&FLAG_RECURSION_DESIRED.to_be_bytes());
+    message.extend_from_slice(&1u16.to_be_bytes());
+    message.extend_from_slice(&[0; 6]);
+
+    let name_start = message.len();
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > MAX_LABEL_LENGTH {
+            return Err(Error::EINVAL);
+        }
+
+        message.push(label.len() as u8);
+        message.extend_from_slice(label.as_bytes());
+    }
+    message.push(0);
+
+    if message.len() - name_start > MAX_NAME_LENGTH {
+        return Err(Error::EINVAL);
+    }
+
+    message.extend_from_slice(&(record_type as u16).to_be_bytes());
+    message.extend_from_slice(&CLASS_IN.to_be_bytes());
+
+    Ok(message)
+}
+
+/// Is `message` a response to the query with `id`?
+pub(super) fn is_response(id: u16, message: &[u8]) -> bool {
+    message.len() >= HEADER_SIZE
+        && read_u16(message, 0) == Some(id)
+        && read_u16(message, 2).unwrap() & FLAG_RESPONSE != 0
+}
+
+impl Response {
+    /// Parses the `record_type` addresses out of a response `message`
+    pub(super) fn parse(message: &[u8], record_type: RecordType) -> Result<Self> {
+        let flags = read_u16(message, 2).ok_or(Error::EIO)?;
+        let question_count = read_u16(message, 4).ok_or(Error::EIO)?;
+        let answer_count = read_u16(message, 6).ok_or(Error::EIO)?;
+
+        let truncated = flags & FLAG_TRUNCATED != 0;
+        let code = match flags & RCODE_MASK {
+            0 => ResponseCode::NoError,
+            3 => ResponseCode::NameError,
+            _ => ResponseCode::Failure,
+        };
+
+        let mut addresses = Vec::new();
+        if truncated || code != ResponseCode::NoError {
+            return Ok(Response {
+                truncated,
+                code,
+                addresses,
+            });
+        }
+
+        let mut offset = HEADER_SIZE;
+        for _ in 0..question_count {
+            offset = skip_name(message, offset)? + 4;
+        }
+
+        for _ in 0..answer_count {
+            offset = skip_name(message, offset)?;
+
+            let kind = read_u16(message, offset).ok_or(Error::EIO)?;
+            let class = read_u16(message, offset + 2).ok_or(Error::EIO)?;
+            let length = read_u16(message, offset + 8).ok_or(Error::EIO)? as usize;
+            offset += 10;
+
+            let data = message.get(offset..offset + length).ok_or(Error::EIO)?;
+            offset += length;
+
+            // Other records, such as the CNAMEs leading to the address, are skipped
+            if class != CLASS_IN || kind != record_type as u16 {
+                continue;
+            }
+
+            let address = match (record_type, data.len()) {
+                (RecordType::V4, 4) => {
+                    IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(data).unwrap()))
+                }
+                (RecordType::V6, 16) => {
+                    IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(data).unwrap()))
+                }
+                _ => return Err(Error::EIO),
+            };
+
+            if !addresses.contains(&address) {
+                addresses.push(address);
+            }
+        }
+
+        Ok(Response {
+            truncated,
+            code,
+            addresses,
+        })
+    }
+}
+
+/// Reads a big-endian [`u16`] from `message` at `offset`
+fn read_u16(message: &[u8], offset: usize) -> Option<u16> {
+    message
+        .get(offset..offset + 2)
+        .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+/// Skips over the name at `offset`, returning the offset after it
+fn skip_name(message: &[u8], mut offset: usize) -> Result<usize> {
+    loop {
+        let length = *message.get(offset).ok_or(Error::EIO)? as usize;
+
+        // A compression pointer ends the name
+        if length & 0xC0 == 0xC0 {
+            return Ok(offset + 2);
+        }
+
+        if length & 0xC0 != 0 {
+            return Err(Error::EIO);
+        }
+
+        offset += 1 + length;
+        if length == 0 {
+            return Ok(offset);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod hosts;
+mod message;
+mod query;
+mod resolv_conf;
+mod resolver;
+
+pub use resolver::{lookup_host, Resolver};
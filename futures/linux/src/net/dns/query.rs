@@ -0,0 +1,95 @@
+use super::message::{self, RecordType, Response};
+use crate::{
+    io::{Read, Write},
+    net::{TCPStream, UDPSocket},
+    time::{self, Timeout},
+};
+use executor::{Error, Result};
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+/// The largest response accepted over UDP, as extensions to raise it aren't sent
+const UDP_MESSAGE_SIZE: usize = 512;
+
+/// Sends `query` to `nameserver` over UDP, retrying over TCP if the response is truncated
+pub(super) async fn query(
+    nameserver: SocketAddr,
+    id: u16,
+    query: &[u8],
+    record_type: RecordType,
+    timeout: Duration,
+) -> Result<Response> {
+    let response = query_udp(nameserver, id, query, record_type, timeout).await?;
+    if !response.truncated {
+        return Ok(response);
+    }
+
+    query_tcp(nameserver, id, query, record_type, timeout).await
+}
+
+/// Sends `query` to `nameserver` in a single UDP datagram
+async fn query_udp(
+    nameserver: SocketAddr,
+    id: u16,
+    query: &[u8],
+    record_type: RecordType,
+    timeout: Duration,
+) -> Result<Response> {
+    let local_addr = match nameserver {
+        SocketAddr::V4(_) => SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), 0),
+        SocketAddr::V6(_) => SocketAddr::new(Ipv6Addr::UNSPECIFIED.into(), 0),
+    };
+
+    let mut socket = UDPSocket::bind(local_addr).await?;
+    socket.connect(nameserver).await?;
+    socket.send(query).await?;
+
+    let deadline = time::deadline_after(time::now(), timeout);
+    let mut buffer = [0; UDP_MESSAGE_SIZE];
+    let receive = async {
+        loop {
+            let length = socket.recv(&mut buffer).await?;
+
+            // Stray datagrams, such as late responses to an earlier attempt, are ignored
+            if message::is_response(id, &buffer[..length]) {
+                return Response::parse(&buffer[..length], record_type);
+            }
+        }
+    };
+
+    Timeout::until(receive, deadline).await?
+}
+
+/// Sends `query` to `nameserver` over a TCP connection, prefixed by its length
+async fn query_tcp(
+    nameserver: SocketAddr,
+    id: u16,
+    query: &[u8],
+    record_type: RecordType,
+    timeout: Duration,
+) -> Result<Response> {
+    let exchange = async {
+        let mut stream = TCPStream::connect(nameserver).await?;
+
+        let mut request = Vec::with_capacity(query.len() + 2);
+        request.extend_from_slice(&(query.len() as u16).to_be_bytes());
+        request.extend_from_slice(query);
+        stream.write_all(&request).await?;
+
+        let mut length = [0; 2];
+        stream.read_exact(&mut length).await?;
+
+        let mut response = vec![0; u16::from_be_bytes(length) as usize];
+        stream.read_exact(&mut response).await?;
+
+        if !message::is_response(id, &response) {
+            return Err(Error::EIO);
+        }
+
+        Response::parse(&response, record_type)
+    };
+
+    Timeout::new(exchange, timeout).await?
+}
@@ -0,0 +1,117 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+
+/// The port DNS servers listen on
+pub(super) const DNS_PORT: u16 = 53;
+
+/// The number of dots a name needs before it is tried as an absolute name first
+const DEFAULT_NDOTS: usize = 1;
+
+/// The maximum value for `ndots`, as limited by the C library
+const MAX_NDOTS: usize = 15;
+
+/// The time to wait for a response from a server
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The maximum value for `timeout` in seconds, as limited by the C library
+const MAX_TIMEOUT: u64 = 30;
+
+/// The number of times each server is tried
+const DEFAULT_ATTEMPTS: usize = 2;
+
+/// The maximum value for `attempts`, as limited by the C library
+const MAX_ATTEMPTS: usize = 5;
+
+/// The resolver configuration from `/etc/resolv.conf`
+pub(super) struct ResolvConf {
+    /// The servers to send queries to
+    pub(super) nameservers: Vec<SocketAddr>,
+
+    /// The domains appended to names with fewer than `ndots` dots
+    pub(super) search: Vec<String>,
+
+    /// The number of dots a name needs before it is tried as an absolute name first
+    pub(super) ndots: usize,
+
+    /// The time to wait for a response from a server
+    pub(super) timeout: Duration,
+
+    /// The number of times each server is tried
+    pub(super) attempts: usize,
+}
+
+impl ResolvConf {
+    /// Parses the contents of a resolver configuration file
+    ///
+    /// Unknown or malformed lines are skipped, as the C library does. If no servers are listed, the
+    /// local machine is used.
+    pub(super) fn parse(contents: &str) -> Self {
+        let mut conf = ResolvConf::default();
+        let mut nameservers = Vec::new();
+
+        for line in contents.lines() {
+            let line = line.split(['#', ';']).next().unwrap();
+            let mut fields = line.split_whitespace();
+
+            match fields.next() {
+                Some("nameserver") => {
+                    // Scoped IPv6 addresses ("fe80::1%eth0") are not supported
+                    if let Some(address) = fields.next().and_then(|field| field.parse().ok()) {
+                        nameservers.push(SocketAddr::new(address, DNS_PORT));
+                    }
+                }
+                // The last of "domain" and "search" takes effect
+                Some("domain") | Some("search") => {
+                    conf.search = fields
+                        .map(|domain| domain.trim_end_matches('.').to_ascii_lowercase())
+                        .filter(|domain| !domain.is_empty())
+                        .collect();
+                }
+                Some("options") => {
+                    for option in fields {
+                        conf.parse_option(option);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !nameservers.is_empty() {
+            conf.nameservers = nameservers;
+        }
+
+        conf
+    }
+
+    /// Parses a single option from an `options` line
+    fn parse_option(&mut self, option: &str) {
+        let (name, value) = match option.split_once(':') {
+            Some((name, value)) => match value.parse::<usize>() {
+                Ok(value) => (name, value),
+                Err(_) => return,
+            },
+            None => return,
+        };
+
+        match name {
+            "ndots" => self.ndots = value.min(MAX_NDOTS),
+            "timeout" => self.timeout = Duration::from_secs((value as u64).clamp(1, MAX_TIMEOUT)),
+            "attempts" => self.attempts = value.clamp(1, MAX_ATTEMPTS),
+            _ => {}
+        }
+    }
+}
+
+impl Default for ResolvConf {
+    fn default() -> Self {
+        ResolvConf {
+            nameservers: vec![SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), DNS_PORT)],
+            search: Vec::new(),
+            ndots: DEFAULT_NDOTS,
+            timeout: DEFAULT_TIMEOUT,
+            attempts: DEFAULT_ATTEMPTS,
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use super::{
+    hosts::parse_hosts,
+    message::{self, RecordType, ResponseCode},
+    query::query,
+    resolv_conf::ResolvConf,
+};
+use crate::fs;
+use executor::{Error, Result};
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::{BuildHasher, Hasher},
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+/// The path of the hosts file
+const HOSTS_PATH: &str = "/etc/hosts";
+
+/// The path of the resolver configuration
+const RESOLV_CONF_PATH: &str = "/etc/resolv.conf";
+
+/// Resolves host names into addresses using a hosts table and DNS servers
+pub struct Resolver {
+    /// The addresses of names which don't need to be queried, keyed by lowercase name
+    hosts: HashMap<String, Vec<IpAddr>>,
+
+    /// The resolver configuration
+    conf: ResolvConf,
+}
+
+impl Resolver {
+    /// Creates a new [`Resolver`] which sends queries to `nameservers`, with an empty hosts table
+    /// and no search domains
+    pub fn new(nameservers: Vec<SocketAddr>) -> Self {
+        Resolver {
+            hosts: HashMap::new(),
+            conf: ResolvConf {
+                nameservers,
+                ..ResolvConf::default()
+            },
+        }
+    }
+
+    /// Creates a new [`Resolver`] configured from `/etc/hosts` and `/etc/resolv.conf`
+    ///
+    /// Missing files are treated as empty, as the C library does.
+    pub async fn system() -> Result<Self> {
+        let hosts = read_config(HOSTS_PATH).await?;
+        let conf = read_config(RESOLV_CONF_PATH).await?;
+
+        Ok(Resolver {
+            hosts: parse_hosts(&hosts),
+            conf: ResolvConf::parse(&conf),
+        })
+    }
+
+    /// Adds `address` as an address of `name`, which is then resolved without sending a query
+    pub fn add_host(&mut self, name: &str, address: IpAddr) {
+        let addresses = self.hosts.entry(normalize(name)).or_default();
+        if !addresses.contains(&address) {
+            addresses.push(address);
+        }
+    }
+
+    /// Gets the servers queries are sent to
+    pub fn nameservers(&self) -> &[SocketAddr] {
+        &self.conf.nameservers
+    }
+
+    /// Gets the time to wait for a response from a server
+    pub fn timeout(&self) -> Duration {
+        self.conf.timeout
+    }
+
+    /// Sets the time to wait for a response from a server
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.conf.timeout = timeout;
+    }
+
+    /// Gets the number of times each server is tried
+    pub fn attempts(&self) -> usize {
+        self.conf.attempts
+    }
+
+    /// Sets the number of times each server is tried
+    pub fn set_attempts(&mut self, attempts: usize) {
+        self.conf.attempts = attempts.max(1);
+    }
+
+    /// Resolves `name` into the addresses it refers to, paired with `port`
+    pub async fn lookup(&self, name: &str, port: u16) -> Result<Vec<SocketAddr>> {
+        self.lookup_ip(name).await.map(|addresses| {
+            addresses
+                .into_iter()
+                .map(|address| SocketAddr::new(address, port))
+                .collect()
+        })
+    }
+
+    /// Resolves `name` into the addresses it refers to
+    ///
+    /// Address literals are returned directly, then the hosts table is checked before any queries
+    /// are sent. Fails with `ENOENT` if the name doesn't exist or has no addresses.
+    pub async fn lookup_ip(&self, name: &str) -> Result<Vec<IpAddr>> {
+        if let Ok(address) = name.parse() {
+            return Ok(vec![address]);
+        }
+
+        let absolute = name.ends_with('.');
+        let name = normalize(name);
+        if name.is_empty() {
+            return Err(Error::EINVAL);
+        }
+
+        if let Some(addresses) = self.hosts.get(&name) {
+            return Ok(addresses.clone());
+        }
+
+        let mut last_error = Error::ENOENT;
+        for candidate in self.candidates(&name, absolute) {
+            match self.query_name(&candidate).await {
+                Ok(addresses) => return Ok(addresses),
+                Err(error) => last_error = error,
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Gets the names to query for `name`, in order, by applying the search domains
+    fn candidates(&self, name: &str, absolute: bool) -> Vec<String> {
+        if absolute {
+            return vec![name.to_owned()];
+        }
+
+        let searched = self
+            .conf
+            .search
+            .iter()
+            .map(|domain| format!("{}.{}", name, domain));
+
+        if name.matches('.').count() >= self.conf.ndots {
+            std::iter::once(name.to_owned()).chain(searched).collect()
+        } else {
+            searched.chain(std::iter::once(name.to_owned())).collect()
+        }
+    }
+
+    /// Queries the IPv6 and IPv4 addresses of exactly `name`
+    ///
+    /// Both queries are always sent, so a server which fails `AAAA` queries doesn't prevent the
+    /// IPv4 addresses from being found. This only fails if neither query finds an address.
+    async fn query_name(&self, name: &str) -> Result<Vec<IpAddr>> {
+        let results = [
+            self.query_type(name, RecordType::V6).await,
+            self.query_type(name, RecordType::V4).await,
+        ];
+
+        let mut addresses = Vec::new();
+        let mut last_error = Error::ENOENT;
+        for result in results {
+            match result {
+                Ok(found) => addresses.extend(found),
+                Err(error) => last_error = error,
+            }
+        }
+
+        if addresses.is_empty() {
+            return Err(last_error);
+        }
+
+        Ok(addresses)
+    }
+
+    /// Queries the `record_type` addresses of `name`, trying each server in turn
+    ///
+    /// A name without records of `record_type` yields an empty list, while a name which doesn't
+    /// exist fails with `ENOENT`.
+    async fn query_type(&self, name: &str, record_type: RecordType) -> Result<Vec<IpAddr>> {
+        let id = RandomState::new().build_hasher().finish() as u16;
+        let request = message::encode_query(id, name, record_type)?;
+
+        let mut last_error = Error::ETIMEDOUT;
+        for _ in 0..self.conf.attempts {
+            for nameserver in &self.conf.nameservers {
+                match query(*nameserver, id, &request, record_type, self.conf.timeout).await {
+                    Ok(response) => match response.code {
+                        ResponseCode::NoError => return Ok(response.addresses),
+                        ResponseCode::NameError => return Err(Error::ENOENT),
+                        ResponseCode::Failure => last_error = Error::EIO,
+                    },
+                    Err(error) => last_error = error,
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+}
+
+/// Resolves `name` into the addresses it refers to, paired with `port`, using the system
+/// configuration
+///
+/// See [`Resolver::system`] and [`Resolver::lookup`].
+pub async fn lookup_host(name: &str, port: u16) -> Result<Vec<SocketAddr>> {
+    Resolver::system().await?.lookup(name, port).await
+}
+
+/// Lowercases `name` and removes any trailing dot
+fn normalize(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Reads the configuration file at `path`, or an empty string if it doesn't exist
+async fn read_config(path: &str) -> Result<String> {
+    match fs::read(path).await {
+        Ok(bytes) => Ok(String::from_utf8_lossy(&bytes).into_owned()),
+        Err(error) if error == Error::ENOENT => Ok(String::new()),
+        Err(error) => Err(error),
+    }
+}
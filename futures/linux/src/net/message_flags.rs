@@ -0,0 +1,74 @@
+use executor::platform::linux::sys::socket::{
+    MSG_CTRUNC, MSG_DONTWAIT, MSG_EOR, MSG_MORE, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK, MSG_TRUNC,
+    MSG_WAITALL,
+};
+use std::{
+    ffi::c_int,
+    ops::{BitOr, BitOrAssign},
+};
+
+/// Flags which modify how a message is sent or received, or describe a received message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MessageFlags(c_int);
+
+impl MessageFlags {
+    /// No flags
+    pub const NONE: Self = MessageFlags(0);
+
+    /// Return data without removing it from the receive queue
+    pub const PEEK: Self = MessageFlags(MSG_PEEK);
+
+    /// Wait until the whole buffer is filled, unless an error or disconnect occurs first
+    pub const WAITALL: Self = MessageFlags(MSG_WAITALL);
+
+    /// Fail with `EWOULDBLOCK` instead of waiting if the operation can't complete immediately
+    pub const DONTWAIT: Self = MessageFlags(MSG_DONTWAIT);
+
+    /// Fail with `EPIPE` instead of raising `SIGPIPE` if the peer has closed the connection
+    pub const NOSIGNAL: Self = MessageFlags(MSG_NOSIGNAL);
+
+    /// More data will follow, so hold back partial frames
+    pub const MORE: Self = MessageFlags(MSG_MORE);
+
+    /// Send or receive out-of-band data
+    pub const OOB: Self = MessageFlags(MSG_OOB);
+
+    /// Set on a received message if part of it was discarded because the buffer was too small
+    pub const TRUNC: Self = MessageFlags(MSG_TRUNC);
+
+    /// Set on a received message if part of its ancillary data was discarded because the control
+    /// buffer was too small
+    pub const CTRUNC: Self = MessageFlags(MSG_CTRUNC);
+
+    /// Set on a received message if it ends a record
+    pub const EOR: Self = MessageFlags(MSG_EOR);
+
+    /// Are all of the flags in `other` set in this?
+    pub fn contains(&self, other: MessageFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Creates a [`MessageFlags`] from the raw flags
+    pub(super) fn from_raw(flags: c_int) -> Self {
+        MessageFlags(flags)
+    }
+
+    /// Gets the raw flags
+    pub(super) fn as_raw(&self) -> c_int {
+        self.0
+    }
+}
+
+impl BitOr for MessageFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        MessageFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for MessageFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
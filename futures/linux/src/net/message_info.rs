@@ -0,0 +1,45 @@
+use super::MessageFlags;
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::TCPStream;
+
+/// A description of a message received by [`TCPStream::recv_msg`]
+#[derive(Debug, Clone, Copy)]
+pub struct MessageInfo {
+    /// The number of bytes received into the buffer
+    length: usize,
+
+    /// The number of bytes of ancillary data received into the control buffer
+    control_length: usize,
+
+    /// The flags set on the received message
+    flags: MessageFlags,
+}
+
+impl MessageInfo {
+    /// Creates a new [`MessageInfo`]
+    pub(super) fn new(length: usize, control_length: usize, flags: MessageFlags) -> Self {
+        MessageInfo {
+            length,
+            control_length,
+            flags,
+        }
+    }
+
+    /// Gets the number of bytes received into the buffer
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Gets the number of bytes of ancillary data received into the control buffer
+    pub fn control_length(&self) -> usize {
+        self.control_length
+    }
+
+    /// Gets the flags set on the received message, such as [`MessageFlags::TRUNC`] or
+    /// [`MessageFlags::CTRUNC`]
+    pub fn flags(&self) -> MessageFlags {
+        self.flags
+    }
+}
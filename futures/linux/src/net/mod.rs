@@ -1,13 +1,38 @@
 //! Futures for networking
 
+pub mod unix;
+
+mod dns;
+mod tcp_info;
 mod tcp_listener;
+mod tcp_socket;
 mod tcp_stream;
+mod udp_socket;
 
+mod message_flags;
+mod message_info;
+mod recv_msg;
+mod send_msg;
 mod socket;
 mod socket_address;
+mod socket_recv;
+mod socket_send;
+mod to_socket_addrs;
 
-pub use tcp_listener::TCPListener;
-pub use tcp_stream::TCPStream;
+pub use dns::{lookup_host, Resolver};
+pub use message_flags::MessageFlags;
+pub use message_info::MessageInfo;
+pub use tcp_info::{TCPInfo, TCPState};
+pub use tcp_listener::{Incoming, TCPListener};
+pub use tcp_socket::TCPSocket;
+pub use tcp_stream::{OwnedReadHalf, OwnedWriteHalf, ReadHalf, ReuniteError, TCPStream, WriteHalf};
+pub use to_socket_addrs::ToSocketAddrs;
+pub use udp_socket::{RecvMultishot, UDPSocket};
 
+use recv_msg::RecvMsg;
+use send_msg::SendMsg;
 use socket::Socket;
 use socket_address::SocketAddress;
+use socket_recv::SocketRecv;
+use socket_send::SocketSend;
+use to_socket_addrs::each_addr;
@@ -0,0 +1,236 @@
+use super::{Socket, SocketAddress};
+use crate::EventRef;
+use executor::{
+    platform::{
+        linux::sys::{socket::msghdr, uio::iovec},
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_recvmsg},
+        EventHandler,
+    },
+    Error, EventID, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    ptr::null_mut,
+    task::{Context, Poll},
+};
+
+/// A future which yields a message, with optional ancillary data, received by a [`Socket`]
+pub(super) struct RecvMsg<'a> {
+    /// The socket to receive from
+    socket: &'a Socket,
+
+    /// The buffer to receive the data into
+    buffer: &'a mut [u8],
+
+    /// The space for the address the message was sent from
+    socket_address: SocketAddress,
+
+    /// The buffer to receive ancillary data into
+    control: &'a mut [u8],
+
+    /// The flags to receive with
+    flags: c_int,
+
+    /// The vector describing `buffer` to the kernel
+    iovec: iovec,
+
+    /// The message header describing the receive to the kernel
+    header: msghdr,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// A message received by a [`RecvMsg`]
+pub(super) struct Received {
+    /// The number of bytes received into the buffer
+    pub(super) length: usize,
+
+    /// The address the message was sent from
+    pub(super) socket_address: SocketAddress,
+
+    /// The number of bytes of ancillary data received into the control buffer
+    pub(super) control_length: usize,
+
+    /// The flags set on the received message (`MSG_TRUNC`, `MSG_CTRUNC`, ...)
+    pub(super) flags: c_int,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the receive.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a receive is completed
+fn recv_msg_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> RecvMsg<'a> {
+    /// Creates a new [`RecvMsg`] future
+    pub(super) fn new(
+        socket: &'a Socket,
+        buffer: &'a mut [u8],
+        control: &'a mut [u8],
+        flags: c_int,
+    ) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(recv_msg_callback));
+
+        let socket_address = SocketAddress::default(socket.family());
+
+        RecvMsg {
+            socket,
+            buffer,
+            socket_address,
+            control,
+            flags,
+            iovec: iovec {
+                base: null_mut(),
+                len: 0,
+            },
+            header: msghdr {
+                name: null_mut(),
+                name_len: 0,
+                iov: null_mut(),
+                iov_len: 0,
+                control: null_mut(),
+                control_len: 0,
+                flags: 0,
+            },
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+
+    /// Projects pinned self into `(self.socket, self.buffer, self.socket_address, self.control,
+    /// self.flags, self.iovec, self.header, self.event_id, self.sqe_submitted)`
+    ///
+    /// # SAFTEY
+    /// This is the only way to access the contained [`SocketAddress`], [`iovec`], and [`msghdr`],
+    /// do not access them directly.
+    unsafe fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        &Socket,
+        &mut [u8],
+        Pin<&mut SocketAddress>,
+        &mut [u8],
+        c_int,
+        Pin<&mut iovec>,
+        Pin<&mut msghdr>,
+        Result<EventID>,
+        &mut bool,
+    ) {
+        let this = self.get_unchecked_mut();
+
+        (
+            this.socket,
+            this.buffer,
+            Pin::new(&mut this.socket_address),
+            this.control,
+            this.flags,
+            Pin::new(&mut this.iovec),
+            Pin::new(&mut this.header),
+            this.event_id
+                .as_ref()
+                .map(|event_id| **event_id)
+                .map_err(|error| *error),
+            &mut this.sqe_submitted,
+        )
+    }
+}
+
+impl<'a> Future for RecvMsg<'a> {
+    type Output = Result<Received>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (
+            socket,
+            buffer,
+            mut socket_address,
+            control,
+            flags,
+            mut iovec,
+            mut header,
+            event_id,
+            sqe_submitted,
+        ) = unsafe { self.project() };
+
+        let event_id = match event_id {
+            Ok(event_id) => event_id,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !*sqe_submitted {
+                iovec.base = buffer.as_mut_ptr().cast();
+                iovec.len = buffer.len();
+
+                header.name = socket_address.as_mut_ptr().cast();
+                header.name_len = socket_address.len() as _;
+                header.iov = &mut *iovec;
+                header.iov_len = 1;
+                if control.len() > 0 {
+                    header.control = control.as_mut_ptr().cast();
+                    header.control_len = control.len();
+                }
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_recvmsg(sqe.as_ptr(), socket.fd(), &mut *header, flags as _)
+                };
+
+                sqe.submit().unwrap();
+                *sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            *sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            socket_address.set_len(header.name_len as _);
+
+            Poll::Ready(Ok(Received {
+                length: result as usize,
+                socket_address: socket_address.clone(),
+                control_length: header.control_len as usize,
+                flags: header.flags,
+            }))
+        })
+    }
+}
+
+impl<'a> Drop for RecvMsg<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl<'a> !Send for RecvMsg<'a> {}
+impl<'a> !Sync for RecvMsg<'a> {}
@@ -0,0 +1,213 @@
+use super::{Socket, SocketAddress};
+use crate::EventRef;
+use executor::{
+    platform::{
+        linux::sys::{socket::msghdr, uio::iovec},
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_sendmsg},
+        EventHandler,
+    },
+    Error, EventID, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    ptr::null_mut,
+    task::{Context, Poll},
+};
+
+/// A future which yields after sending a message, with optional ancillary data, from a [`Socket`]
+pub(super) struct SendMsg<'a> {
+    /// The socket to send from
+    socket: &'a Socket,
+
+    /// The data to send
+    buffer: &'a [u8],
+
+    /// The address to send to, or [`None`] to send to the connected address
+    socket_address: Option<SocketAddress>,
+
+    /// The ancillary data to send
+    control: &'a [u8],
+
+    /// The flags to send with
+    flags: c_int,
+
+    /// The vector describing `buffer` to the kernel
+    iovec: iovec,
+
+    /// The message header describing the send to the kernel
+    header: msghdr,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the send.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a send is completed
+fn send_msg_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> SendMsg<'a> {
+    /// Creates a new [`SendMsg`] future
+    pub(super) fn new(
+        socket: &'a Socket,
+        buffer: &'a [u8],
+        socket_address: Option<SocketAddress>,
+        control: &'a [u8],
+        flags: c_int,
+    ) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(send_msg_callback));
+
+        SendMsg {
+            socket,
+            buffer,
+            socket_address,
+            control,
+            flags,
+            iovec: iovec {
+                base: null_mut(),
+                len: 0,
+            },
+            header: msghdr {
+                name: null_mut(),
+                name_len: 0,
+                iov: null_mut(),
+                iov_len: 0,
+                control: null_mut(),
+                control_len: 0,
+                flags: 0,
+            },
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+
+    /// Projects pinned self into `(self.socket, self.buffer, self.socket_address, self.control,
+    /// self.flags, self.iovec, self.header, self.event_id, self.sqe_submitted)`
+    ///
+    /// # SAFTEY
+    /// This is the only way to access the contained [`SocketAddress`], [`iovec`], and [`msghdr`],
+    /// do not access them directly.
+    unsafe fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        &Socket,
+        &[u8],
+        Option<Pin<&mut SocketAddress>>,
+        &[u8],
+        c_int,
+        Pin<&mut iovec>,
+        Pin<&mut msghdr>,
+        Result<EventID>,
+        &mut bool,
+    ) {
+        let this = self.get_unchecked_mut();
+
+        (
+            this.socket,
+            this.buffer,
+            this.socket_address.as_mut().map(Pin::new),
+            this.control,
+            this.flags,
+            Pin::new(&mut this.iovec),
+            Pin::new(&mut this.header),
+            this.event_id
+                .as_ref()
+                .map(|event_id| **event_id)
+                .map_err(|error| *error),
+            &mut this.sqe_submitted,
+        )
+    }
+}
+
+impl<'a> Future for SendMsg<'a> {
+    type Output = Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (
+            socket,
+            buffer,
+            socket_address,
+            control,
+            flags,
+            mut iovec,
+            mut header,
+            event_id,
+            sqe_submitted,
+        ) = unsafe { self.project() };
+
+        let event_id = match event_id {
+            Ok(event_id) => event_id,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !*sqe_submitted {
+                iovec.base = buffer.as_ptr() as _;
+                iovec.len = buffer.len();
+
+                if let Some(mut socket_address) = socket_address {
+                    header.name = socket_address.as_mut_ptr().cast();
+                    header.name_len = socket_address.len() as _;
+                }
+                header.iov = &mut *iovec;
+                header.iov_len = 1;
+                if control.len() > 0 {
+                    header.control = control.as_ptr() as _;
+                    header.control_len = control.len();
+                }
+
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe { io_uring_prep_sendmsg(sqe.as_ptr(), socket.fd(), &*header, flags as _) };
+
+                sqe.submit().unwrap();
+                *sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            *sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            Poll::Ready(Ok(result as usize))
+        })
+    }
+}
+
+impl<'a> Drop for SendMsg<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl<'a> !Send for SendMsg<'a> {}
+impl<'a> !Sync for SendMsg<'a> {}
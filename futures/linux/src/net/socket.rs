@@ -1,17 +1,36 @@
-use super::socket_address::SocketAddress;
+use super::{socket_address::SocketAddress, TCPInfo};
 use executor::{
     platform::linux::{
-        netinet::{r#in::IPPROTO_TCP, tcp::TCP_NODELAY},
+        netinet::{
+            r#in::{
+                in6_addr, in_addr, ip_mreq, ipv6_mreq, IPPROTO_IP, IPPROTO_IPV6, IPPROTO_TCP,
+                IPV6_ADD_MEMBERSHIP, IPV6_DROP_MEMBERSHIP, IPV6_UNICAST_HOPS, IPV6_V6ONLY,
+                IP_ADD_MEMBERSHIP, IP_DROP_MEMBERSHIP, IP_TTL,
+            },
+            tcp::{
+                TCP_CONGESTION, TCP_CORK, TCP_INFO, TCP_KEEPCNT, TCP_KEEPIDLE, TCP_KEEPINTVL,
+                TCP_NODELAY, TCP_QUICKACK, TCP_USER_TIMEOUT,
+            },
+        },
         sys::socket::{
-            bind, getpeername, getsockname, getsockopt, listen, setsockopt, socket, socklen_t,
-            SOCK_STREAM, SOL_SOCKET, SO_REUSEADDR,
+            bind, connect, getpeername, getsockname, getsockopt, listen, setsockopt, socket,
+            socketpair, socklen_t, ucred, AF_INET6, SOL_SOCKET, SO_BINDTODEVICE, SO_BROADCAST,
+            SO_ERROR, SO_KEEPALIVE, SO_LINGER, SO_PEERCRED, SO_RCVBUF, SO_REUSEADDR, SO_REUSEPORT,
+            SO_SNDBUF,
         },
         try_linux,
         unistd::close,
     },
-    Result,
+    Error, Result,
+};
+use std::{
+    ffi::c_int,
+    net::{Ipv4Addr, Ipv6Addr},
+    time::Duration,
 };
-use std::ffi::c_int;
+
+/// The maximum length of a congestion control algorithm name, including the null terminator
+const TCP_CA_NAME_MAX: usize = 16;
 
 /// A Linux socket
 pub(super) struct Socket {
@@ -23,9 +42,17 @@ pub(super) struct Socket {
 }
 
 impl Socket {
-    /// Creates a new unbound [`Socket`]
-    pub(super) fn new(family: c_int) -> Result<Self> {
-        try_linux!(socket(family, SOCK_STREAM, 0)).map(|fd| Socket { fd, family })
+    /// Creates a new unbound [`Socket`] of `kind` (`SOCK_STREAM`, `SOCK_DGRAM`, ...)
+    pub(super) fn new(family: c_int, kind: c_int) -> Result<Self> {
+        try_linux!(socket(family, kind, 0)).map(|fd| Socket { fd, family })
+    }
+
+    /// Creates a pair of connected [`Socket`]s of `kind`
+    pub(super) fn pair(family: c_int, kind: c_int) -> Result<(Self, Self)> {
+        let mut fds = [0; 2];
+        try_linux!(socketpair(family, kind, 0, fds.as_mut_ptr()))?;
+
+        Ok((Socket { fd: fds[0], family }, Socket { fd: fds[1], family }))
     }
 
     /// Creates a [`Socket`] from `fd`
@@ -43,7 +70,9 @@ impl Socket {
         let mut address = SocketAddress::default(self.family);
         let mut len = address.len() as socklen_t;
 
-        try_linux!(getsockname(self.fd, address.as_mut_ptr(), &mut len)).map(|_| address)
+        try_linux!(getsockname(self.fd, address.as_mut_ptr(), &mut len))?;
+        address.set_len(len);
+        Ok(address)
     }
 
     /// Gets the remote address of the peer
@@ -51,20 +80,147 @@ impl Socket {
         let mut address = SocketAddress::default(self.family);
         let mut len = address.len() as socklen_t;
 
-        try_linux!(getpeername(self.fd, address.as_mut_ptr(), &mut len)).map(|_| address)
+        try_linux!(getpeername(self.fd, address.as_mut_ptr(), &mut len))?;
+        address.set_len(len);
+        Ok(address)
     }
 
     /// Gets if Nagle's algorithm is disabled on this socket
     pub(super) fn nodelay(&self) -> Result<bool> {
-        let mut flag: c_int = 0;
-        try_linux!(getsockopt(
-            self.fd,
-            IPPROTO_TCP,
-            TCP_NODELAY,
-            &mut flag as *mut c_int as _,
-            std::mem::size_of::<c_int>() as _
-        ))
-        .map(|_| flag == 1)
+        self.get_option(IPPROTO_TCP, TCP_NODELAY)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets if the address this socket is bound to can be reused
+    pub(super) fn reuse_addr(&self) -> Result<bool> {
+        self.get_option(SOL_SOCKET, SO_REUSEADDR)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets if multiple sockets can be bound to the same address and port
+    pub(super) fn reuse_port(&self) -> Result<bool> {
+        self.get_option(SOL_SOCKET, SO_REUSEPORT)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets if this IPv6 socket is restricted to IPv6 communication only
+    pub(super) fn only_v6(&self) -> Result<bool> {
+        self.get_option(IPPROTO_IPV6, IPV6_V6ONLY)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets the size of the kernel's receive buffer for this socket
+    pub(super) fn recv_buffer_size(&self) -> Result<usize> {
+        self.get_option(SOL_SOCKET, SO_RCVBUF)
+            .map(|size| size as usize)
+    }
+
+    /// Gets the size of the kernel's send buffer for this socket
+    pub(super) fn send_buffer_size(&self) -> Result<usize> {
+        self.get_option(SOL_SOCKET, SO_SNDBUF)
+            .map(|size| size as usize)
+    }
+
+    /// Gets if keepalive probes are sent on this socket
+    pub(super) fn keepalive(&self) -> Result<bool> {
+        self.get_option(SOL_SOCKET, SO_KEEPALIVE)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets how long the connection must be idle before keepalive probes are sent
+    pub(super) fn keepalive_idle(&self) -> Result<Duration> {
+        self.get_option(IPPROTO_TCP, TCP_KEEPIDLE)
+            .map(|seconds| Duration::from_secs(seconds as u64))
+    }
+
+    /// Gets the time between keepalive probes
+    pub(super) fn keepalive_interval(&self) -> Result<Duration> {
+        self.get_option(IPPROTO_TCP, TCP_KEEPINTVL)
+            .map(|seconds| Duration::from_secs(seconds as u64))
+    }
+
+    /// Gets the number of unanswered keepalive probes before the connection is dropped
+    pub(super) fn keepalive_count(&self) -> Result<u32> {
+        self.get_option(IPPROTO_TCP, TCP_KEEPCNT)
+            .map(|count| count as u32)
+    }
+
+    /// Gets how long closing this socket waits for unsent data to be sent, or [`None`] if closing
+    /// returns immediately
+    pub(super) fn linger(&self) -> Result<Option<Duration>> {
+        // struct linger { int l_onoff; int l_linger; }
+        let mut linger: [c_int; 2] = [0; 2];
+        self.get_option_raw(SOL_SOCKET, SO_LINGER, &mut linger)?;
+
+        Ok((linger[0] != 0).then(|| Duration::from_secs(linger[1] as u64)))
+    }
+
+    /// Gets the time-to-live (or hop limit for IPv6) of packets sent from this socket
+    pub(super) fn ttl(&self) -> Result<u32> {
+        if self.family == AF_INET6 {
+            self.get_option(IPPROTO_IPV6, IPV6_UNICAST_HOPS)
+        } else {
+            self.get_option(IPPROTO_IP, IP_TTL)
+        }
+        .map(|ttl| ttl as u32)
+    }
+
+    /// Gets how long sent data may remain unacknowledged before the connection is dropped, with
+    /// zero meaning the system default
+    pub(super) fn user_timeout(&self) -> Result<Duration> {
+        self.get_option(IPPROTO_TCP, TCP_USER_TIMEOUT)
+            .map(|milliseconds| Duration::from_millis(milliseconds as u32 as u64))
+    }
+
+    /// Gets if partial frames are held back until the cork is removed
+    pub(super) fn cork(&self) -> Result<bool> {
+        self.get_option(IPPROTO_TCP, TCP_CORK).map(|flag| flag != 0)
+    }
+
+    /// Gets if acknowledgements are sent immediately rather than delayed
+    pub(super) fn quickack(&self) -> Result<bool> {
+        self.get_option(IPPROTO_TCP, TCP_QUICKACK)
+            .map(|flag| flag != 0)
+    }
+
+    /// Gets the name of the congestion control algorithm used by this socket
+    pub(super) fn congestion(&self) -> Result<String> {
+        let mut name = [0u8; TCP_CA_NAME_MAX];
+        let len = self.get_option_raw(IPPROTO_TCP, TCP_CONGESTION, &mut name)?;
+
+        let name = &name[..len.min(TCP_CA_NAME_MAX)];
+        let end = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        Ok(String::from_utf8_lossy(&name[..end]).into_owned())
+    }
+
+    /// Gets and clears the pending error on this socket
+    pub(super) fn take_error(&self) -> Result<Option<Error>> {
+        self.get_option(SOL_SOCKET, SO_ERROR)
+            .map(|error| (error != 0).then(|| Error::new(error)))
+    }
+
+    /// Gets the kernel's statistics about this TCP connection
+    pub(super) fn tcp_info(&self) -> Result<TCPInfo> {
+        let mut tcp_info = TCPInfo::default();
+        self.get_option_raw(IPPROTO_TCP, TCP_INFO, tcp_info.as_mut_raw())
+            .map(|_| tcp_info)
+    }
+
+    /// Gets the credentials of the process which connected the peer of this socket
+    pub(super) fn peer_cred(&self) -> Result<ucred> {
+        let mut credentials = ucred {
+            pid: 0,
+            uid: 0,
+            gid: 0,
+        };
+        self.get_option_raw(SOL_SOCKET, SO_PEERCRED, &mut credentials)
+            .map(|_| credentials)
+    }
+
+    /// Gets if this socket is allowed to send to broadcast addresses
+    pub(super) fn broadcast(&self) -> Result<bool> {
+        self.get_option(SOL_SOCKET, SO_BROADCAST)
+            .map(|flag| flag != 0)
     }
 
     /// Binds this socket to `addr` (IPv4)
@@ -72,6 +228,14 @@ impl Socket {
         try_linux!(bind(self.fd, address.as_ptr(), address.len() as _)).map(|_| ())
     }
 
+    /// Synchronously connects this socket to `address`
+    ///
+    /// This should only be used for connectionless sockets, where connecting only sets the default
+    /// destination and does not block.
+    pub(super) fn connect(&mut self, address: &SocketAddress) -> Result<()> {
+        try_linux!(connect(self.fd, address.as_ptr(), address.len() as _)).map(|_| ())
+    }
+
     /// Sets this socket into a listen state, allowing this socket to accept incoming connections
     pub(super) fn listen(&mut self, backlog: c_int) -> Result<()> {
         try_linux!(listen(self.fd, backlog)).map(|_| ())
@@ -79,26 +243,208 @@ impl Socket {
 
     /// Sets if this socket will use Nagle's algorithm when sending data
     pub(super) fn set_nodelay(&mut self, nodelay: bool) -> Result<()> {
-        let flag = nodelay as c_int;
-        try_linux!(setsockopt(
-            self.fd,
-            IPPROTO_TCP,
-            TCP_NODELAY,
-            &flag as *const c_int as _,
-            std::mem::size_of::<c_int>() as _
-        ))
-        .map(|_| ())
+        self.set_option(IPPROTO_TCP, TCP_NODELAY, nodelay as c_int)
     }
 
     /// Sets if the address this socket is bound to can be reused
     pub(super) fn set_reuse_addr(&mut self, reuse_addr: bool) -> Result<()> {
-        let flag = reuse_addr as c_int;
+        self.set_option(SOL_SOCKET, SO_REUSEADDR, reuse_addr as c_int)
+    }
+
+    /// Sets if multiple sockets can be bound to the same address and port
+    pub(super) fn set_reuse_port(&mut self, reuse_port: bool) -> Result<()> {
+        self.set_option(SOL_SOCKET, SO_REUSEPORT, reuse_port as c_int)
+    }
+
+    /// Sets if this IPv6 socket is restricted to IPv6 communication only
+    pub(super) fn set_only_v6(&mut self, only_v6: bool) -> Result<()> {
+        self.set_option(IPPROTO_IPV6, IPV6_V6ONLY, only_v6 as c_int)
+    }
+
+    /// Sets the size of the kernel's receive buffer for this socket
+    pub(super) fn set_recv_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.set_option(
+            SOL_SOCKET,
+            SO_RCVBUF,
+            size.min(c_int::MAX as usize) as c_int,
+        )
+    }
+
+    /// Sets the size of the kernel's send buffer for this socket
+    pub(super) fn set_send_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.set_option(
+            SOL_SOCKET,
+            SO_SNDBUF,
+            size.min(c_int::MAX as usize) as c_int,
+        )
+    }
+
+    /// Sets if keepalive probes are sent on this socket
+    pub(super) fn set_keepalive(&mut self, keepalive: bool) -> Result<()> {
+        self.set_option(SOL_SOCKET, SO_KEEPALIVE, keepalive as c_int)
+    }
+
+    /// Sets how long the connection must be idle before keepalive probes are sent
+    pub(super) fn set_keepalive_idle(&mut self, idle: Duration) -> Result<()> {
+        self.set_option(IPPROTO_TCP, TCP_KEEPIDLE, duration_secs(idle))
+    }
+
+    /// Sets the time between keepalive probes
+    pub(super) fn set_keepalive_interval(&mut self, interval: Duration) -> Result<()> {
+        self.set_option(IPPROTO_TCP, TCP_KEEPINTVL, duration_secs(interval))
+    }
+
+    /// Sets the number of unanswered keepalive probes before the connection is dropped
+    pub(super) fn set_keepalive_count(&mut self, count: u32) -> Result<()> {
+        self.set_option(
+            IPPROTO_TCP,
+            TCP_KEEPCNT,
+            count.min(c_int::MAX as u32) as c_int,
+        )
+    }
+
+    /// Sets how long closing this socket waits for unsent data to be sent, or makes closing
+    /// return immediately if `linger` is [`None`]
+    pub(super) fn set_linger(&mut self, linger: Option<Duration>) -> Result<()> {
+        let linger: [c_int; 2] = match linger {
+            Some(linger) => [1, duration_secs(linger)],
+            None => [0, 0],
+        };
+        self.set_option_raw(SOL_SOCKET, SO_LINGER, &linger)
+    }
+
+    /// Sets the time-to-live (or hop limit for IPv6) of packets sent from this socket
+    pub(super) fn set_ttl(&mut self, ttl: u32) -> Result<()> {
+        let ttl = ttl.min(c_int::MAX as u32) as c_int;
+        if self.family == AF_INET6 {
+            self.set_option(IPPROTO_IPV6, IPV6_UNICAST_HOPS, ttl)
+        } else {
+            self.set_option(IPPROTO_IP, IP_TTL, ttl)
+        }
+    }
+
+    /// Sets how long sent data may remain unacknowledged before the connection is dropped, with
+    /// zero meaning the system default
+    pub(super) fn set_user_timeout(&mut self, timeout: Duration) -> Result<()> {
+        let milliseconds = timeout.as_millis().min(c_int::MAX as u128) as c_int;
+        self.set_option(IPPROTO_TCP, TCP_USER_TIMEOUT, milliseconds)
+    }
+
+    /// Sets if partial frames are held back until the cork is removed
+    pub(super) fn set_cork(&mut self, cork: bool) -> Result<()> {
+        self.set_option(IPPROTO_TCP, TCP_CORK, cork as c_int)
+    }
+
+    /// Sets if acknowledgements are sent immediately rather than delayed
+    pub(super) fn set_quickack(&mut self, quickack: bool) -> Result<()> {
+        self.set_option(IPPROTO_TCP, TCP_QUICKACK, quickack as c_int)
+    }
+
+    /// Sets the congestion control algorithm used by this socket
+    pub(super) fn set_congestion(&mut self, name: &str) -> Result<()> {
+        if name.len() >= TCP_CA_NAME_MAX {
+            return Err(Error::EINVAL);
+        }
+
+        self.set_option_raw(IPPROTO_TCP, TCP_CONGESTION, name.as_bytes())
+    }
+
+    /// Sets if this socket is allowed to send to broadcast addresses
+    pub(super) fn set_broadcast(&mut self, broadcast: bool) -> Result<()> {
+        self.set_option(SOL_SOCKET, SO_BROADCAST, broadcast as c_int)
+    }
+
+    /// Binds this socket to the network interface named `interface`, or removes the binding if
+    /// `interface` is [`None`]
+    pub(super) fn bind_device(&mut self, interface: Option<&str>) -> Result<()> {
+        self.set_option_raw(
+            SOL_SOCKET,
+            SO_BINDTODEVICE,
+            interface.unwrap_or("").as_bytes(),
+        )
+    }
+
+    /// Joins or leaves (`join == false`) the IPv4 multicast group `multiaddr` on the interface
+    /// with the address `interface`
+    pub(super) fn set_multicast_v4(
+        &mut self,
+        multiaddr: Ipv4Addr,
+        interface: Ipv4Addr,
+        join: bool,
+    ) -> Result<()> {
+        let request = ip_mreq {
+            multiaddr: in_addr {
+                addr: multiaddr.to_bits().to_be(),
+            },
+            interface: in_addr {
+                addr: interface.to_bits().to_be(),
+            },
+        };
+
+        let name = if join {
+            IP_ADD_MEMBERSHIP
+        } else {
+            IP_DROP_MEMBERSHIP
+        };
+        self.set_option_raw(IPPROTO_IP, name, &request)
+    }
+
+    /// Joins or leaves (`join == false`) the IPv6 multicast group `multiaddr` on the interface
+    /// with the index `interface`
+    pub(super) fn set_multicast_v6(
+        &mut self,
+        multiaddr: &Ipv6Addr,
+        interface: u32,
+        join: bool,
+    ) -> Result<()> {
+        let request = ipv6_mreq {
+            multiaddr: in6_addr {
+                addr: multiaddr.octets(),
+            },
+            interface,
+        };
+
+        let name = if join {
+            IPV6_ADD_MEMBERSHIP
+        } else {
+            IPV6_DROP_MEMBERSHIP
+        };
+        self.set_option_raw(IPPROTO_IPV6, name, &request)
+    }
+
+    /// Gets the value of the integer option `name` at `level`
+    fn get_option(&self, level: c_int, name: c_int) -> Result<c_int> {
+        let mut value: c_int = 0;
+        self.get_option_raw(level, name, &mut value).map(|_| value)
+    }
+
+    /// Sets the integer option `name` at `level` to `value`
+    fn set_option(&mut self, level: c_int, name: c_int, value: c_int) -> Result<()> {
+        self.set_option_raw(level, name, &value)
+    }
+
+    /// Reads the option `name` at `level` into `value`, returning the number of bytes the kernel
+    /// wrote
+    fn get_option_raw<T: ?Sized>(&self, level: c_int, name: c_int, value: &mut T) -> Result<usize> {
+        let mut len = std::mem::size_of_val(value) as socklen_t;
+        try_linux!(getsockopt(
+            self.fd,
+            level,
+            name,
+            value as *mut T as *mut u8 as _,
+            &mut len
+        ))
+        .map(|_| len as usize)
+    }
+
+    /// Sets the option `name` at `level` to the bytes of `value`
+    fn set_option_raw<T: ?Sized>(&mut self, level: c_int, name: c_int, value: &T) -> Result<()> {
         try_linux!(setsockopt(
             self.fd,
-            SOL_SOCKET,
-            SO_REUSEADDR,
-            &flag as *const c_int as _,
-            std::mem::size_of::<c_int>() as _
+            level,
+            name,
+            value as *const T as *const u8 as _,
+            std::mem::size_of_val(value) as _
         ))
         .map(|_| ())
     }
@@ -112,6 +458,11 @@ impl Socket {
     }
 }
 
+/// Converts `duration` into whole seconds for a socket option
+fn duration_secs(duration: Duration) -> c_int {
+    duration.as_secs().min(c_int::MAX as u64) as c_int
+}
+
 impl Drop for Socket {
     fn drop(&mut self) {
         unsafe { close(self.fd) };
@@ -1,10 +1,18 @@
-use executor::platform::linux::{
-    netinet::r#in::{in6_addr, in_addr, sockaddr_in, sockaddr_in6},
-    sys::socket::{sockaddr, AF_INET, AF_INET6},
+use super::unix::UnixSocketAddr;
+use executor::{
+    platform::linux::{
+        netinet::r#in::{in6_addr, in_addr, sockaddr_in, sockaddr_in6},
+        sys::{
+            socket::{sockaddr, socklen_t, AF_INET, AF_INET6, AF_UNIX},
+            un::sockaddr_un,
+        },
+    },
+    Error, Result,
 };
 use std::{
-    ffi::c_int,
+    ffi::{c_char, c_int, OsStr},
     net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    os::unix::ffi::OsStrExt,
 };
 
 /// An address associated with a socket
@@ -15,8 +23,14 @@ pub(super) enum SocketAddress {
 
     /// IPv6
     V6(sockaddr_in6),
+
+    /// Unix domain, with the number of bytes of the address in use
+    Unix(sockaddr_un, socklen_t),
 }
 
+/// The offset of the path in a [`sockaddr_un`]
+const UNIX_PATH_OFFSET: usize = std::mem::offset_of!(sockaddr_un, path);
+
 impl SocketAddress {
     pub(super) fn default(family: c_int) -> Self {
         match family {
@@ -33,6 +47,13 @@ impl SocketAddress {
                 addr: in6_addr { addr: [0; 16] },
                 scope_id: 0,
             }),
+            AF_UNIX => SocketAddress::Unix(
+                sockaddr_un {
+                    family: AF_UNIX as _,
+                    path: [0; 108],
+                },
+                std::mem::size_of::<sockaddr_un>() as _,
+            ),
             _ => panic!("unknown socket family {}", family),
         }
     }
@@ -42,6 +63,7 @@ impl SocketAddress {
         match self {
             SocketAddress::V4(_) => AF_INET,
             SocketAddress::V6(_) => AF_INET6,
+            SocketAddress::Unix(_, _) => AF_UNIX,
         }
     }
 
@@ -49,7 +71,44 @@ impl SocketAddress {
         match self {
             SocketAddress::V4(_) => std::mem::size_of::<sockaddr_in>(),
             SocketAddress::V6(_) => std::mem::size_of::<sockaddr_in6>(),
+            SocketAddress::Unix(_, len) => *len as usize,
+        }
+    }
+
+    /// Sets the number of bytes of the address in use after the kernel has filled it in
+    ///
+    /// This only has an effect on Unix domain addresses, whose length varies.
+    pub(super) fn set_len(&mut self, new_len: socklen_t) {
+        if let SocketAddress::Unix(_, len) = self {
+            *len = new_len.min(std::mem::size_of::<sockaddr_un>() as _);
+        }
+    }
+
+    /// Converts this address into a [`UnixSocketAddr`]
+    ///
+    /// # Panic
+    /// This function will panic if this is not a Unix domain address
+    pub(super) fn into_unix(self) -> UnixSocketAddr {
+        let (addr, len) = match self {
+            SocketAddress::Unix(addr, len) => (addr, len as usize),
+            _ => panic!("attempted to convert an IP socket address into a unix socket address"),
+        };
+
+        if len <= UNIX_PATH_OFFSET {
+            return UnixSocketAddr::Unnamed;
+        }
+
+        let path: Vec<u8> = addr.path[..len - UNIX_PATH_OFFSET]
+            .iter()
+            .map(|c| *c as u8)
+            .collect();
+
+        if path[0] == 0 {
+            return UnixSocketAddr::Abstract(path[1..].to_vec());
         }
+
+        let end = path.iter().position(|c| *c == 0).unwrap_or(path.len());
+        UnixSocketAddr::Pathname(OsStr::from_bytes(&path[..end]).into())
     }
 
     /// Gets the pointer to the underlying socket address
@@ -57,6 +116,7 @@ impl SocketAddress {
         match self {
             SocketAddress::V4(addr) => (addr as *const sockaddr_in).cast(),
             SocketAddress::V6(addr) => (addr as *const sockaddr_in6).cast(),
+            SocketAddress::Unix(addr, _) => (addr as *const sockaddr_un).cast(),
         }
     }
 
@@ -64,6 +124,7 @@ impl SocketAddress {
         match self {
             SocketAddress::V4(addr) => (addr as *mut sockaddr_in).cast(),
             SocketAddress::V6(addr) => (addr as *mut sockaddr_in6).cast(),
+            SocketAddress::Unix(addr, _) => (addr as *mut sockaddr_un).cast(),
         }
     }
 }
@@ -92,6 +153,40 @@ impl From<SocketAddr> for SocketAddress {
     }
 }
 
+impl TryFrom<&UnixSocketAddr> for SocketAddress {
+    type Error = Error;
+
+    fn try_from(addr: &UnixSocketAddr) -> Result<Self> {
+        let mut path = [0; 108];
+
+        // Filesystem paths are null terminated, abstract names start with a null byte
+        let (name, start, len) = match addr {
+            UnixSocketAddr::Unnamed => (&[] as &[u8], 0, 0),
+            UnixSocketAddr::Pathname(pathname) => {
+                let name = pathname.as_os_str().as_bytes();
+                (name, 0, name.len() + 1)
+            }
+            UnixSocketAddr::Abstract(name) => (name.as_slice(), 1, name.len() + 1),
+        };
+
+        if len > path.len() {
+            return Err(Error::ENAMETOOLONG);
+        }
+
+        for (i, c) in name.iter().enumerate() {
+            path[start + i] = *c as c_char;
+        }
+
+        Ok(SocketAddress::Unix(
+            sockaddr_un {
+                family: AF_UNIX as _,
+                path,
+            },
+            (UNIX_PATH_OFFSET + len) as _,
+        ))
+    }
+}
+
 impl Into<SocketAddr> for SocketAddress {
     fn into(self) -> SocketAddr {
         match self {
@@ -105,6 +200,9 @@ impl Into<SocketAddr> for SocketAddress {
                 addr.flow_info.to_be(),
                 addr.scope_id.to_be(),
             )),
+            SocketAddress::Unix(_, _) => {
+                panic!("attempted to convert a unix socket address into an IP socket address")
+            }
         }
     }
 }
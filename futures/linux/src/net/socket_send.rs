@@ -0,0 +1,147 @@
+use super::Socket;
+use crate::EventRef;
+use executor::{
+    platform::{
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_send},
+        EventHandler,
+    },
+    Error, EventID, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A future which yields after sending bytes from a [`Socket`] with flags
+pub(super) struct SocketSend<'a> {
+    /// The socket to send from
+    socket: &'a Socket,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// The buffer to send from
+    buffer: &'a [u8],
+
+    /// The flags to send with
+    flags: c_int,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the send.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a send is completed
+fn send_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl<'a> SocketSend<'a> {
+    /// Creates a new [`SocketSend`] future
+    pub(super) fn new(socket: &'a Socket, buffer: &'a [u8], flags: c_int) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(send_callback));
+
+        SocketSend {
+            socket,
+            event_id,
+            buffer,
+            flags,
+            sqe_submitted: false,
+        }
+    }
+
+    /// Projects pinned self into `(self.socket, self.event_id, self.buffer, self.flags,
+    /// self.sqe_submitted)`
+    ///
+    /// # SAFTEY
+    /// This is the only way to access the contained `buffer`, do not access it directly.
+    unsafe fn project(
+        self: Pin<&mut Self>,
+    ) -> (&Socket, Result<EventID>, Pin<&[u8]>, c_int, &mut bool) {
+        let this = self.get_unchecked_mut();
+
+        (
+            this.socket,
+            this.event_id
+                .as_ref()
+                .map(|event_id| **event_id)
+                .map_err(|error| *error),
+            Pin::new(&this.buffer),
+            this.flags,
+            &mut this.sqe_submitted,
+        )
+    }
+}
+
+impl<'a> Future for SocketSend<'a> {
+    type Output = Result<usize>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (socket, event_id, buffer, flags, sqe_submitted) = unsafe { self.project() };
+
+        let event_id = match event_id {
+            Ok(event_id) => event_id,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !*sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_send(
+                        sqe.as_ptr(),
+                        socket.fd(),
+                        buffer.as_ptr() as _,
+                        buffer.len() as _,
+                        flags,
+                    )
+                };
+
+                sqe.submit().unwrap();
+                *sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            *sqe_submitted = false;
+
+            let bytes_sent = (value & (u32::MAX as usize)) as c_int;
+            if bytes_sent < 0 {
+                return Poll::Ready(Err(Error::new(-bytes_sent)));
+            }
+
+            Poll::Ready(Ok(bytes_sent as usize))
+        })
+    }
+}
+
+impl<'a> Drop for SocketSend<'a> {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl<'a> !Send for SocketSend<'a> {}
+impl<'a> !Sync for SocketSend<'a> {}
@@ -0,0 +1,318 @@
+use std::time::Duration;
+
+/// The state of a TCP connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TCPState {
+    /// The connection is open and data can be transferred
+    Established,
+
+    /// A connection request has been sent and is waiting for a matching request
+    SynSent,
+
+    /// A connection request has been received and sent, and is waiting for acknowledgement
+    SynReceived,
+
+    /// The local side has closed and is waiting for the remote side to acknowledge or close
+    FinWait1,
+
+    /// The local side has closed and the remote side has acknowledged it
+    FinWait2,
+
+    /// Both sides have closed and the socket is waiting for stray packets to expire
+    TimeWait,
+
+    /// The socket is closed
+    Close,
+
+    /// The remote side has closed and is waiting for the local side to close
+    CloseWait,
+
+    /// Both sides have closed and the socket is waiting for the final acknowledgement
+    LastAck,
+
+    /// The socket is listening for connections
+    Listen,
+
+    /// Both sides closed simultaneously and are waiting for acknowledgement
+    Closing,
+
+    /// The connection is waiting for a SYN to be answered by a SYN cookie
+    NewSynReceived,
+
+    /// A state not known when this was written
+    Unknown(u8),
+}
+
+/// Statistics about a TCP connection collected by the kernel (`TCP_INFO`)
+///
+/// Fields which are newer than the running kernel read as zero.
+#[derive(Clone, Default)]
+pub struct TCPInfo {
+    /// The kernel's `struct tcp_info`, up to the fields this crate knows about
+    raw: RawTCPInfo,
+}
+
+/// The layout of the kernel's `struct tcp_info`, with the `tcpi_` prefix removed
+#[repr(C)]
+#[derive(Clone, Default)]
+pub(super) struct RawTCPInfo {
+    state: u8,
+    ca_state: u8,
+    retransmits: u8,
+    probes: u8,
+    backoff: u8,
+    options: u8,
+    wscale: u8,
+    flags: u8,
+
+    rto: u32,
+    ato: u32,
+    snd_mss: u32,
+    rcv_mss: u32,
+
+    unacked: u32,
+    sacked: u32,
+    lost: u32,
+    retrans: u32,
+    fackets: u32,
+
+    last_data_sent: u32,
+    last_ack_sent: u32,
+    last_data_recv: u32,
+    last_ack_recv: u32,
+
+    pmtu: u32,
+    rcv_ssthresh: u32,
+    rtt: u32,
+    rttvar: u32,
+    snd_ssthresh: u32,
+    snd_cwnd: u32,
+    advmss: u32,
+    reordering: u32,
+
+    rcv_rtt: u32,
+    rcv_space: u32,
+
+    total_retrans: u32,
+
+    pacing_rate: u64,
+    max_pacing_rate: u64,
+    bytes_acked: u64,
+    bytes_received: u64,
+    segs_out: u32,
+    segs_in: u32,
+
+    notsent_bytes: u32,
+    min_rtt: u32,
+    data_segs_in: u32,
+    data_segs_out: u32,
+
+    delivery_rate: u64,
+
+    busy_time: u64,
+    rwnd_limited: u64,
+    sndbuf_limited: u64,
+
+    delivered: u32,
+    delivered_ce: u32,
+
+    bytes_sent: u64,
+    bytes_retrans: u64,
+    dsack_dups: u32,
+    reord_seen: u32,
+
+    rcv_ooopack: u32,
+
+    snd_wnd: u32,
+}
+
+impl TCPInfo {
+    /// Gets the underlying structure for the kernel to fill in
+    pub(super) fn as_mut_raw(&mut self) -> &mut RawTCPInfo {
+        &mut self.raw
+    }
+
+    /// Gets the state of the connection
+    pub fn state(&self) -> TCPState {
+        match self.raw.state {
+            1 => TCPState::Established,
+            2 => TCPState::SynSent,
+            3 => TCPState::SynReceived,
+            4 => TCPState::FinWait1,
+            5 => TCPState::FinWait2,
+            6 => TCPState::TimeWait,
+            7 => TCPState::Close,
+            8 => TCPState::CloseWait,
+            9 => TCPState::LastAck,
+            10 => TCPState::Listen,
+            11 => TCPState::Closing,
+            12 => TCPState::NewSynReceived,
+            state => TCPState::Unknown(state),
+        }
+    }
+
+    /// Gets the number of unrecovered retransmission timeouts
+    pub fn retransmits(&self) -> u8 {
+        self.raw.retransmits
+    }
+
+    /// Gets the number of unanswered zero window or keepalive probes
+    pub fn probes(&self) -> u8 {
+        self.raw.probes
+    }
+
+    /// Gets the send window scale advertised by the peer
+    pub fn snd_wscale(&self) -> u8 {
+        self.raw.wscale & 0xF
+    }
+
+    /// Gets the receive window scale advertised to the peer
+    pub fn rcv_wscale(&self) -> u8 {
+        self.raw.wscale >> 4
+    }
+
+    /// Gets the retransmission timeout
+    pub fn rto(&self) -> Duration {
+        Duration::from_micros(self.raw.rto as u64)
+    }
+
+    /// Gets the delayed acknowledgement timeout
+    pub fn ato(&self) -> Duration {
+        Duration::from_micros(self.raw.ato as u64)
+    }
+
+    /// Gets the maximum segment size for sending
+    pub fn snd_mss(&self) -> u32 {
+        self.raw.snd_mss
+    }
+
+    /// Gets the maximum segment size for receiving
+    pub fn rcv_mss(&self) -> u32 {
+        self.raw.rcv_mss
+    }
+
+    /// Gets the number of segments sent but not yet acknowledged
+    pub fn unacked(&self) -> u32 {
+        self.raw.unacked
+    }
+
+    /// Gets the number of segments selectively acknowledged
+    pub fn sacked(&self) -> u32 {
+        self.raw.sacked
+    }
+
+    /// Gets the number of segments believed to be lost
+    pub fn lost(&self) -> u32 {
+        self.raw.lost
+    }
+
+    /// Gets the number of segments currently being retransmitted
+    pub fn retrans(&self) -> u32 {
+        self.raw.retrans
+    }
+
+    /// Gets the time since data was last sent
+    pub fn last_data_sent(&self) -> Duration {
+        Duration::from_millis(self.raw.last_data_sent as u64)
+    }
+
+    /// Gets the time since data was last received
+    pub fn last_data_recv(&self) -> Duration {
+        Duration::from_millis(self.raw.last_data_recv as u64)
+    }
+
+    /// Gets the time since an acknowledgement was last received
+    pub fn last_ack_recv(&self) -> Duration {
+        Duration::from_millis(self.raw.last_ack_recv as u64)
+    }
+
+    /// Gets the path maximum transmission unit
+    pub fn pmtu(&self) -> u32 {
+        self.raw.pmtu
+    }
+
+    /// Gets the smoothed round trip time
+    pub fn rtt(&self) -> Duration {
+        Duration::from_micros(self.raw.rtt as u64)
+    }
+
+    /// Gets the variance of the round trip time
+    pub fn rtt_var(&self) -> Duration {
+        Duration::from_micros(self.raw.rttvar as u64)
+    }
+
+    /// Gets the minimum round trip time observed
+    pub fn min_rtt(&self) -> Duration {
+        Duration::from_micros(self.raw.min_rtt as u64)
+    }
+
+    /// Gets the slow start threshold in segments
+    pub fn snd_ssthresh(&self) -> u32 {
+        self.raw.snd_ssthresh
+    }
+
+    /// Gets the congestion window in segments
+    pub fn snd_cwnd(&self) -> u32 {
+        self.raw.snd_cwnd
+    }
+
+    /// Gets the window advertised by the peer in bytes
+    pub fn snd_wnd(&self) -> u32 {
+        self.raw.snd_wnd
+    }
+
+    /// Gets the space available for receiving in bytes
+    pub fn rcv_space(&self) -> u32 {
+        self.raw.rcv_space
+    }
+
+    /// Gets the total number of segments retransmitted over the connection
+    pub fn total_retrans(&self) -> u32 {
+        self.raw.total_retrans
+    }
+
+    /// Gets the current pacing rate in bytes per second
+    pub fn pacing_rate(&self) -> u64 {
+        self.raw.pacing_rate
+    }
+
+    /// Gets the most recent delivery rate in bytes per second
+    pub fn delivery_rate(&self) -> u64 {
+        self.raw.delivery_rate
+    }
+
+    /// Gets the number of bytes sent, including retransmissions
+    pub fn bytes_sent(&self) -> u64 {
+        self.raw.bytes_sent
+    }
+
+    /// Gets the number of bytes retransmitted
+    pub fn bytes_retrans(&self) -> u64 {
+        self.raw.bytes_retrans
+    }
+
+    /// Gets the number of bytes acknowledged by the peer
+    pub fn bytes_acked(&self) -> u64 {
+        self.raw.bytes_acked
+    }
+
+    /// Gets the number of bytes received from the peer
+    pub fn bytes_received(&self) -> u64 {
+        self.raw.bytes_received
+    }
+
+    /// Gets the number of segments sent
+    pub fn segs_out(&self) -> u32 {
+        self.raw.segs_out
+    }
+
+    /// Gets the number of segments received
+    pub fn segs_in(&self) -> u32 {
+        self.raw.segs_in
+    }
+
+    /// Gets the number of bytes queued but not yet sent
+    pub fn notsent_bytes(&self) -> u32 {
+        self.raw.notsent_bytes
+    }
+}
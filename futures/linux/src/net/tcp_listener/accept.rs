@@ -14,6 +14,7 @@ use std::{
     net::SocketAddr,
     pin::Pin,
     task::{Context, Poll},
+    time::Instant,
 };
 
 /// A future which yields a new connection from a [`TCPListener`]
@@ -30,6 +31,9 @@ pub struct Accept<'a> {
     /// The length of the incoming socket address
     socket_address_len: socklen_t,
 
+    /// The instant the accept is cancelled at, if it has one
+    deadline: Option<Instant>,
+
     /// Has the SQE been submitted?
     sqe_submitted: bool,
 }
@@ -46,6 +50,11 @@ fn accept_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
 impl<'a> Accept<'a> {
     /// Creates a new [`Accept`] future
     pub(super) fn new(listener: &'a TCPListener) -> Self {
+        Accept::with_deadline(listener, None)
+    }
+
+    /// Creates a new [`Accept`] future which fails with `ECANCELED` if `deadline` passes first
+    pub(super) fn with_deadline(listener: &'a TCPListener, deadline: Option<Instant>) -> Self {
         let event_id = EventRef::register(EventHandler::integer(accept_callback));
 
         let socket_address = SocketAddress::default(listener.0.family());
@@ -56,12 +65,13 @@ impl<'a> Accept<'a> {
             event_id,
             socket_address,
             socket_address_len,
+            deadline,
             sqe_submitted: false,
         }
     }
 
     /// Projects pinned self into `(self.listener, self.event_id, self.socket_address,
-    /// self.socket_address_len, self.sqe_submitted)`
+    /// self.socket_address_len, self.deadline, self.sqe_submitted)`
     ///
     /// # SAFTEY
     /// This is the only way to access the contained [`SocketAddress`], do not access it directly.
@@ -72,6 +82,7 @@ impl<'a> Accept<'a> {
         Result<EventID>,
         Pin<&mut SocketAddress>,
         Pin<&mut socklen_t>,
+        Option<Instant>,
         &mut bool,
     ) {
         let this = self.get_unchecked_mut();
@@ -84,6 +95,7 @@ impl<'a> Accept<'a> {
                 .map_err(|error| *error),
             Pin::new(&mut this.socket_address),
             Pin::new(&mut this.socket_address_len),
+            this.deadline,
             &mut this.sqe_submitted,
         )
     }
@@ -93,7 +105,7 @@ impl<'a> Future for Accept<'a> {
     type Output = Result<(TCPStream, SocketAddr)>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (listener, event_id, mut socket_address, socket_address_len, sqe_submitted) =
+        let (listener, event_id, mut socket_address, socket_address_len, deadline, sqe_submitted) =
             unsafe { self.project() };
 
         let event_id = match event_id {
@@ -104,7 +116,10 @@ impl<'a> Future for Accept<'a> {
         EventManager::get_local_mut(|manager| {
             // Submit the SQE if one hasn't been submitted yet
             if !*sqe_submitted {
-                let sqe = manager.get_sqe(event_id).unwrap();
+                let sqe = match manager.get_sqe_with_deadline(event_id, deadline) {
+                    Ok(sqe) => sqe,
+                    Err(error) => return Poll::Ready(Err(error)),
+                };
 
                 unsafe {
                     io_uring_prep_accept(
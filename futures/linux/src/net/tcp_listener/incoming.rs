@@ -0,0 +1,168 @@
+use super::TCPListener;
+use crate::{net::TCPStream, EventRef};
+use executor::{
+    platform::{
+        linux::unistd::close,
+        uring::{io_uring_cqe, io_uring_prep_multishot_accept, IORING_CQE_F_MORE},
+        Completion, EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    ptr::null_mut,
+    task::{Context, Poll},
+};
+
+/// A stream of connections accepted by a [`TCPListener`] using a single multishot accept
+///
+/// Connections which are accepted before [`Incoming::next`] is called are buffered until they are
+/// requested. The accept is transparently re-armed if the kernel stops it.
+pub struct Incoming<'a> {
+    /// The listening socket to accept from
+    listener: &'a TCPListener,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Is there a multishot accept in flight?
+    armed: bool,
+}
+
+/// A future which yields the next connection from an [`Incoming`]
+pub struct IncomingNext<'b, 'a>(&'b mut Incoming<'a>);
+
+/// The callback for when a client is accepted
+fn incoming_callback(cqe: &mut io_uring_cqe, queue: &mut VecDeque<Completion>) {
+    queue.push_back(Completion::new(cqe));
+}
+
+/// The callback for clients accepted after the [`Incoming`] was dropped
+fn close_callback(cqe: &mut io_uring_cqe) {
+    if cqe.res >= 0 {
+        unsafe { close(cqe.res) };
+    }
+}
+
+impl<'a> Incoming<'a> {
+    /// Creates a new [`Incoming`] stream
+    pub(super) fn new(listener: &'a TCPListener) -> Self {
+        let event_id = EventRef::register(EventHandler::queue(incoming_callback));
+
+        Incoming {
+            listener,
+            event_id,
+            armed: false,
+        }
+    }
+
+    /// Returns a future which yields the next connection and the address of the client
+    ///
+    /// The stream never ends, so the future only yields [`None`] for compatibility with iterators.
+    pub fn next(&mut self) -> IncomingNext<'_, 'a> {
+        IncomingNext(self)
+    }
+
+    /// Polls for the next buffered connection, re-arming the accept if required
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<(TCPStream, SocketAddr)>>> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => return Poll::Ready(Some(Err(*error))),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if the kernel isn't currently accepting
+            if !self.armed {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_multishot_accept(
+                        sqe.as_ptr(),
+                        self.listener.0.fd(),
+                        null_mut(),
+                        null_mut(),
+                        0,
+                    )
+                };
+
+                sqe.submit().unwrap();
+                self.armed = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let completion = match event.data_mut().as_queue_mut().pop_front() {
+                Some(completion) => completion,
+                None => {
+                    event.set_waker(Some(cx.waker().clone()));
+                    return Poll::Pending;
+                }
+            };
+
+            if completion.flags() & IORING_CQE_F_MORE == 0 {
+                self.armed = false;
+            }
+
+            let fd = completion.result();
+            if fd < 0 {
+                return Poll::Ready(Some(Err(Error::new(-fd))));
+            }
+
+            // The address can't be captured by the accept as every completion would share it
+            let tcp_stream = unsafe { TCPStream::from_raw(fd, self.listener.0.family()) };
+            Poll::Ready(Some(
+                tcp_stream
+                    .peer_addr()
+                    .map(|socket_address| (tcp_stream, socket_address)),
+            ))
+        })
+    }
+}
+
+impl<'a> Drop for Incoming<'a> {
+    fn drop(&mut self) {
+        let event_id = match std::mem::replace(&mut self.event_id, Err(Error::ECANCELED)) {
+            Ok(event_id) => event_id,
+            Err(_) => return,
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Close any connections which were accepted but never requested
+            let event = manager.get_event_mut(*event_id).unwrap();
+            for completion in event.data_mut().as_queue_mut().drain(..) {
+                if completion.flags() & IORING_CQE_F_MORE == 0 {
+                    self.armed = false;
+                }
+
+                if completion.result() >= 0 {
+                    unsafe { close(completion.result()) };
+                }
+            }
+
+            if self.armed {
+                manager.cancel(*event_id).unwrap();
+            }
+        });
+
+        // Connections can still be accepted until the cancellation completes
+        if self.armed {
+            event_id.orphan(EventHandler::unit(close_callback));
+        }
+    }
+}
+
+impl<'a> !Send for Incoming<'a> {}
+impl<'a> !Sync for Incoming<'a> {}
+
+impl<'b, 'a> Future for IncomingNext<'b, 'a> {
+    type Output = Option<Result<(TCPStream, SocketAddr)>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_next(cx)
+    }
+}
+
+impl<'b, 'a> !Send for IncomingNext<'b, 'a> {}
+impl<'b, 'a> !Sync for IncomingNext<'b, 'a> {}
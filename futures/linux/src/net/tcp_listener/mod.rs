@@ -1,25 +1,34 @@
-use super::{Socket, SocketAddress};
+use super::{each_addr, Socket, TCPSocket, TCPStream, ToSocketAddrs};
+use crate::time::DeadlineError;
 use executor::{platform::linux::sys::socket::SOMAXCONN, Result};
-use std::net::SocketAddr;
+use std::{future::Future, net::SocketAddr, time::Instant};
 
 mod accept;
+mod incoming;
 
 pub use accept::Accept;
+pub use incoming::{Incoming, IncomingNext};
 
 /// A listening socket for TCP connections
-pub struct TCPListener(Socket);
+pub struct TCPListener(pub(super) Socket);
 
 impl TCPListener {
-    /// Creates a new [`TCPListener`] bound to `addr`
-    pub fn bind(addr: SocketAddr) -> Result<Self> {
-        let socket_address: SocketAddress = addr.into();
+    /// Creates a new [`TCPListener`] bound to the first address `addrs` resolves to which can be
+    /// bound
+    ///
+    /// The address is set to be reusable and the listen backlog is the system maximum. Use a
+    /// [`TCPSocket`] for control over these and other options. Returns the error from the last
+    /// address if none can be bound.
+    pub async fn bind<A: ToSocketAddrs>(addrs: A) -> Result<Self> {
+        each_addr(addrs, TCPListener::bind_addr).await
+    }
 
-        let mut socket = Socket::new(socket_address.family())?;
+    /// Creates a new [`TCPListener`] bound to `addr`
+    fn bind_addr(addr: SocketAddr) -> Result<Self> {
+        let mut socket = TCPSocket::new_for_addr(addr)?;
         socket.set_reuse_addr(true)?;
-        socket.bind(&socket_address)?;
-        socket.listen(SOMAXCONN)?;
-
-        Ok(TCPListener(socket))
+        socket.bind(addr)?;
+        socket.listen(SOMAXCONN as u32)
     }
 
     /// Gets the local address of this socket
@@ -31,6 +40,22 @@ impl TCPListener {
     pub fn accept(&self) -> Accept {
         Accept::new(self)
     }
+
+    /// Returns a future which yields when a new client connects to this socket, failing with
+    /// [`DeadlineError::Elapsed`] if none connects before `deadline`
+    pub fn accept_with_deadline(
+        &self,
+        deadline: Instant,
+    ) -> impl Future<Output = std::result::Result<(TCPStream, SocketAddr), DeadlineError>> + '_
+    {
+        let accept = Accept::with_deadline(self, Some(deadline));
+        async move { accept.await.map_err(DeadlineError::from_linked) }
+    }
+
+    /// Returns a stream of connections to this socket, accepted by a single multishot accept
+    pub fn incoming(&self) -> Incoming {
+        Incoming::new(self)
+    }
 }
 
 unsafe impl Send for TCPListener {}
@@ -0,0 +1,131 @@
+use super::{tcp_stream::Connect, Socket, SocketAddress, TCPListener};
+use crate::AsFD;
+use executor::{
+    platform::linux::sys::socket::{AF_INET, AF_INET6, SOCK_STREAM},
+    Result,
+};
+use std::{ffi::c_int, net::SocketAddr};
+
+/// A TCP socket which has not yet been converted into a [`TCPListener`] or a
+/// [`TCPStream`](super::TCPStream), allowing its options to be set beforehand
+pub struct TCPSocket(Socket);
+
+impl TCPSocket {
+    /// Creates a new IPv4 [`TCPSocket`]
+    pub fn new_v4() -> Result<Self> {
+        Socket::new(AF_INET, SOCK_STREAM).map(TCPSocket)
+    }
+
+    /// Creates a new IPv6 [`TCPSocket`]
+    pub fn new_v6() -> Result<Self> {
+        Socket::new(AF_INET6, SOCK_STREAM).map(TCPSocket)
+    }
+
+    /// Creates a new [`TCPSocket`] for the same family as `addr`
+    pub fn new_for_addr(addr: SocketAddr) -> Result<Self> {
+        match addr {
+            SocketAddr::V4(_) => TCPSocket::new_v4(),
+            SocketAddr::V6(_) => TCPSocket::new_v6(),
+        }
+    }
+
+    /// Gets the locally bound address
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.0.local_addr().map(|addr| addr.into())
+    }
+
+    /// Gets if the address this socket is bound to can be reused
+    pub fn reuse_addr(&self) -> Result<bool> {
+        self.0.reuse_addr()
+    }
+
+    /// Gets if multiple sockets can be bound to the same address and port
+    pub fn reuse_port(&self) -> Result<bool> {
+        self.0.reuse_port()
+    }
+
+    /// Gets if this IPv6 socket only accepts IPv6 connections
+    pub fn only_v6(&self) -> Result<bool> {
+        self.0.only_v6()
+    }
+
+    /// Gets the size of the kernel's receive buffer for this socket
+    pub fn recv_buffer_size(&self) -> Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Gets the size of the kernel's send buffer for this socket
+    pub fn send_buffer_size(&self) -> Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets if the address this socket is bound to can be reused
+    pub fn set_reuse_addr(&mut self, reuse_addr: bool) -> Result<()> {
+        self.0.set_reuse_addr(reuse_addr)
+    }
+
+    /// Sets if multiple sockets can be bound to the same address and port
+    ///
+    /// When multiple listeners are bound to the same address, the kernel distributes incoming
+    /// connections between them. This must be set on every socket before it is bound.
+    pub fn set_reuse_port(&mut self, reuse_port: bool) -> Result<()> {
+        self.0.set_reuse_port(reuse_port)
+    }
+
+    /// Sets if this IPv6 socket only accepts IPv6 connections
+    ///
+    /// If this is `false`, binding to the unspecified address (`::`) also accepts IPv4 connections
+    /// as IPv4-mapped IPv6 addresses. This must be set before the socket is bound.
+    pub fn set_only_v6(&mut self, only_v6: bool) -> Result<()> {
+        self.0.set_only_v6(only_v6)
+    }
+
+    /// Sets the size of the kernel's receive buffer for this socket
+    ///
+    /// The kernel doubles the value to allow for bookkeeping overhead.
+    pub fn set_recv_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Sets the size of the kernel's send buffer for this socket
+    ///
+    /// The kernel doubles the value to allow for bookkeeping overhead.
+    pub fn set_send_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Restricts this socket to only send and receive on the network interface named
+    /// `interface`, or removes the restriction if `interface` is [`None`]
+    pub fn bind_device(&mut self, interface: Option<&str>) -> Result<()> {
+        self.0.bind_device(interface)
+    }
+
+    /// Binds this socket to `addr`
+    pub fn bind(&mut self, addr: SocketAddr) -> Result<()> {
+        self.0.bind(&addr.into())
+    }
+
+    /// Converts this socket into a [`TCPListener`] with room for `backlog` pending connections
+    ///
+    /// The kernel silently limits `backlog` to `/proc/sys/net/core/somaxconn`.
+    pub fn listen(mut self, backlog: u32) -> Result<TCPListener> {
+        self.0.listen(backlog.min(c_int::MAX as u32) as c_int)?;
+        Ok(TCPListener(self.0))
+    }
+
+    /// Returns a future which yields a new [`TCPStream`](super::TCPStream) once this socket is
+    /// connected to `addr`
+    pub fn connect(self, addr: SocketAddr) -> Connect {
+        let socket_address: SocketAddress = addr.into();
+        Connect::with_socket(Ok(self.0), socket_address)
+    }
+}
+
+impl AsFD for TCPSocket {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+unsafe impl Send for TCPSocket {}
+unsafe impl Sync for TCPSocket {}
@@ -0,0 +1,171 @@
+use super::TCPStream;
+use crate::{
+    net::{Socket, SocketAddress},
+    EventRef,
+};
+use executor::{
+    platform::{
+        linux::sys::socket::SOCK_STREAM,
+        uring::{io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_connect},
+        EventHandler,
+    },
+    Error, EventID, EventManager, Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A future which yields a new [`TCPStream`] once a connection has been established
+pub struct Connect {
+    /// The socket being connected, taken once the connection completes
+    socket: Option<Result<Socket>>,
+
+    /// The address to connect to
+    socket_address: SocketAddress,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+}
+
+/// The bit used to signal completion of the event in the value. 1 means finished. The bit must be
+/// in a position greater than 32 to fit the result code from the connect.
+const SIGNAL_BIT: usize = 1 << 33;
+
+/// The callback for when a connection is completed
+fn connect_callback(cqe: &mut io_uring_cqe, value: &mut usize) {
+    *value = (cqe.res as usize) | SIGNAL_BIT;
+}
+
+impl Connect {
+    /// Creates a new [`Connect`] future which connects to `addr`
+    pub(super) fn new(addr: SocketAddr) -> Self {
+        let socket_address: SocketAddress = addr.into();
+
+        Connect::with_socket(
+            Socket::new(socket_address.family(), SOCK_STREAM),
+            socket_address,
+        )
+    }
+
+    /// Creates a new [`Connect`] future which connects `socket` to `socket_address`
+    pub(in crate::net) fn with_socket(
+        socket: Result<Socket>,
+        socket_address: SocketAddress,
+    ) -> Self {
+        let event_id = EventRef::register(EventHandler::integer(connect_callback));
+
+        Connect {
+            socket: Some(socket),
+            socket_address,
+            event_id,
+            sqe_submitted: false,
+        }
+    }
+
+    /// Projects pinned self into `(self.socket, self.socket_address, self.event_id,
+    /// self.sqe_submitted)`
+    ///
+    /// # SAFTEY
+    /// This is the only way to access the contained [`SocketAddress`], do not access it directly.
+    unsafe fn project(
+        self: Pin<&mut Self>,
+    ) -> (
+        &mut Option<Result<Socket>>,
+        Pin<&mut SocketAddress>,
+        Result<EventID>,
+        &mut bool,
+    ) {
+        let this = self.get_unchecked_mut();
+
+        (
+            &mut this.socket,
+            Pin::new(&mut this.socket_address),
+            this.event_id
+                .as_ref()
+                .map(|event_id| **event_id)
+                .map_err(|error| *error),
+            &mut this.sqe_submitted,
+        )
+    }
+}
+
+impl Future for Connect {
+    type Output = Result<TCPStream>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (socket, socket_address, event_id, sqe_submitted) = unsafe { self.project() };
+
+        let event_id = match event_id {
+            Ok(event_id) => event_id,
+            Err(error) => return Poll::Ready(Err(error)),
+        };
+
+        let fd = match socket {
+            Some(Ok(socket)) => unsafe { socket.fd() },
+            Some(Err(error)) => return Poll::Ready(Err(*error)),
+            None => panic!("Attempted to poll a completed connect"),
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !*sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_connect(
+                        sqe.as_ptr(),
+                        fd,
+                        socket_address.as_ptr(),
+                        socket_address.len() as _,
+                    )
+                };
+
+                sqe.submit().unwrap();
+                *sqe_submitted = true;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            let value = event.data().as_integer();
+            if value & SIGNAL_BIT == 0 {
+                event.set_waker(Some(cx.waker().clone()));
+                return Poll::Pending;
+            }
+
+            *sqe_submitted = false;
+
+            let result = (value & (u32::MAX as usize)) as c_int;
+            if result < 0 {
+                return Poll::Ready(Err(Error::new(-result)));
+            }
+
+            let socket = socket.take().unwrap().unwrap();
+            Poll::Ready(Ok(TCPStream(socket)))
+        })
+    }
+}
+
+impl Drop for Connect {
+    fn drop(&mut self) {
+        if let Ok(event_id) = &self.event_id {
+            if self.sqe_submitted {
+                EventManager::get_local_mut(|manager| {
+                    let sqe = manager.get_sqe(**event_id).unwrap();
+
+                    unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (**event_id).into_u64(), 0) };
+
+                    sqe.submit().unwrap();
+                })
+            }
+        }
+    }
+}
+
+impl !Send for Connect {}
+impl !Sync for Connect {}
@@ -0,0 +1,109 @@
+use super::{Connect, TCPStream};
+use crate::time::Sleep;
+use executor::{Error, Result};
+use std::{
+    future::{poll_fn, Future},
+    net::SocketAddr,
+    pin::Pin,
+    task::Poll,
+    time::Duration,
+};
+
+/// The time to wait for a connection attempt before starting the next one, as recommended by
+/// RFC 8305
+const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+/// Connects to the first of `addrs` to accept a connection, racing the attempts as described by
+/// RFC 8305 ("Happy Eyeballs")
+pub(super) async fn connect_to_any<I: IntoIterator<Item = SocketAddr>>(
+    addrs: I,
+) -> Result<TCPStream> {
+    let mut addrs = interleave(addrs).into_iter().peekable();
+
+    let mut attempts: Vec<Pin<Box<Connect>>> = Vec::new();
+    let mut delay: Option<Pin<Box<Sleep>>> = None;
+    let mut start_next = true;
+    let mut last_error = Error::EINVAL;
+
+    poll_fn(|cx| loop {
+        // Start the next attempt if the previous one failed or took too long
+        if start_next {
+            start_next = false;
+
+            delay = match addrs.next() {
+                Some(addr) => {
+                    attempts.push(Box::pin(Connect::new(addr)));
+
+                    match Sleep::new(CONNECTION_ATTEMPT_DELAY) {
+                        Ok(sleep) => Some(Box::pin(sleep)),
+                        Err(error) => return Poll::Ready(Err(error)),
+                    }
+                }
+                None => None,
+            };
+        }
+
+        // Check the attempts in progress, yielding the first successful connection
+        let mut failed = false;
+        let mut i = 0;
+        while i < attempts.len() {
+            match attempts[i].as_mut().poll(cx) {
+                Poll::Ready(Ok(stream)) => return Poll::Ready(Ok(stream)),
+                Poll::Ready(Err(error)) => {
+                    last_error = error;
+                    attempts.remove(i);
+                    failed = true;
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+
+        if attempts.is_empty() && addrs.peek().is_none() {
+            return Poll::Ready(Err(last_error));
+        }
+
+        if failed {
+            start_next = true;
+            continue;
+        }
+
+        if let Some(delay) = delay.as_mut() {
+            if delay.as_mut().poll(cx).is_ready() {
+                start_next = true;
+                continue;
+            }
+        }
+
+        return Poll::Pending;
+    })
+    .await
+}
+
+/// Orders `addrs` so the address families alternate, starting with the family of the first address
+fn interleave<I: IntoIterator<Item = SocketAddr>>(addrs: I) -> Vec<SocketAddr> {
+    let mut first_family = Vec::new();
+    let mut second_family = Vec::new();
+
+    let mut first_is_ipv6 = None;
+    for addr in addrs {
+        let first_is_ipv6 = *first_is_ipv6.get_or_insert(addr.is_ipv6());
+
+        if addr.is_ipv6() == first_is_ipv6 {
+            first_family.push(addr);
+        } else {
+            second_family.push(addr);
+        }
+    }
+
+    let mut addrs = Vec::with_capacity(first_family.len() + second_family.len());
+    let mut first_family = first_family.into_iter();
+    let mut second_family = second_family.into_iter();
+    loop {
+        match (first_family.next(), second_family.next()) {
+            (None, None) => break,
+            (first, second) => addrs.extend(first.into_iter().chain(second)),
+        }
+    }
+
+    addrs
+}
@@ -0,0 +1,388 @@
+use super::{
+    MessageFlags, MessageInfo, RecvMsg, SendMsg, Socket, SocketRecv, SocketSend, TCPInfo,
+    ToSocketAddrs,
+};
+use crate::{
+    fd::FDWrite,
+    fs::File,
+    io::{Pipe, Read, Splice, Write},
+    time::{DeadlineError, Timeout},
+    AsFD, FDRead,
+};
+use executor::{Error, Result};
+use std::{
+    ffi::c_int,
+    future::Future,
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+
+mod connect;
+mod connect_to_any;
+mod send_zc;
+mod split;
+
+pub use connect::Connect;
+pub use send_zc::SendZc;
+pub use split::{OwnedReadHalf, OwnedWriteHalf, ReadHalf, ReuniteError, WriteHalf};
+
+/// A TCP stream between a local and a remote socket
+pub struct TCPStream(Socket);
+
+/// The maximum number of bytes moved through the pipe at once by [`TCPStream::send_file`], the
+/// default capacity of a pipe
+const SEND_FILE_CHUNK_SIZE: u64 = 65536;
+
+impl TCPStream {
+    /// Creates a [`TCPStream`] directly from `fd`
+    pub(super) unsafe fn from_raw(fd: c_int, family: c_int) -> Self {
+        let socket = Socket::from_raw(fd, family);
+
+        TCPStream(socket)
+    }
+
+    /// Resolves `addrs`, which may contain host names, and connects to the first address which
+    /// accepts a connection
+    ///
+    /// If `addrs` resolves to more than one address, the attempts are raced as described by
+    /// [`TCPStream::connect_to_any`].
+    pub async fn connect<A: ToSocketAddrs>(addrs: A) -> Result<Self> {
+        let addrs = addrs.to_socket_addrs().await?;
+        match addrs.as_slice() {
+            [addr] => Connect::new(*addr).await,
+            _ => Self::connect_to_any(addrs).await,
+        }
+    }
+
+    /// Connects to `addr`, failing with `ETIMEDOUT` if the connection isn't established within
+    /// `timeout`
+    pub async fn connect_timeout(addr: SocketAddr, timeout: Duration) -> Result<Self> {
+        Timeout::new(Connect::new(addr), timeout).await?
+    }
+
+    /// Connects to the first of `addrs` which accepts a connection
+    ///
+    /// Attempts are raced as described by RFC 8305 ("Happy Eyeballs"). The addresses are ordered so
+    /// IPv6 and IPv4 alternate, and a new attempt is started each time an attempt fails or 250
+    /// milliseconds pass without a connection. Returns the error from the last failed attempt if
+    /// none succeed.
+    pub async fn connect_to_any<I: IntoIterator<Item = SocketAddr>>(addrs: I) -> Result<Self> {
+        connect_to_any::connect_to_any(addrs).await
+    }
+
+    /// Gets the locally bound address
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.0.local_addr().map(|addr| addr.into())
+    }
+
+    /// Gets the remote address of the peer
+    pub fn peer_addr(&self) -> Result<SocketAddr> {
+        self.0.peer_addr().map(|addr| addr.into())
+    }
+
+    /// Gets if Nagle's algorithm is disabled on this socket
+    pub fn nodelay(&self) -> Result<bool> {
+        self.0.nodelay()
+    }
+
+    /// Sets if this socket will use Nagle's algorithm when sending data
+    pub fn set_nodelay(&mut self, nodelay: bool) -> Result<()> {
+        self.0.set_nodelay(nodelay)
+    }
+
+    /// Gets if keepalive probes are sent on this socket
+    pub fn keepalive(&self) -> Result<bool> {
+        self.0.keepalive()
+    }
+
+    /// Sets if keepalive probes are sent on this socket
+    pub fn set_keepalive(&mut self, keepalive: bool) -> Result<()> {
+        self.0.set_keepalive(keepalive)
+    }
+
+    /// Gets how long the connection must be idle before keepalive probes are sent
+    pub fn keepalive_idle(&self) -> Result<Duration> {
+        self.0.keepalive_idle()
+    }
+
+    /// Sets how long the connection must be idle before keepalive probes are sent
+    ///
+    /// The time is rounded down to whole seconds and must be at least one second.
+    pub fn set_keepalive_idle(&mut self, idle: Duration) -> Result<()> {
+        self.0.set_keepalive_idle(idle)
+    }
+
+    /// Gets the time between keepalive probes
+    pub fn keepalive_interval(&self) -> Result<Duration> {
+        self.0.keepalive_interval()
+    }
+
+    /// Sets the time between keepalive probes
+    ///
+    /// The time is rounded down to whole seconds and must be at least one second.
+    pub fn set_keepalive_interval(&mut self, interval: Duration) -> Result<()> {
+        self.0.set_keepalive_interval(interval)
+    }
+
+    /// Gets the number of unanswered keepalive probes before the connection is dropped
+    pub fn keepalive_count(&self) -> Result<u32> {
+        self.0.keepalive_count()
+    }
+
+    /// Sets the number of unanswered keepalive probes before the connection is dropped
+    pub fn set_keepalive_count(&mut self, count: u32) -> Result<()> {
+        self.0.set_keepalive_count(count)
+    }
+
+    /// Gets how long dropping this stream waits for unsent data to be sent, or [`None`] if the
+    /// data is sent in the background
+    pub fn linger(&self) -> Result<Option<Duration>> {
+        self.0.linger()
+    }
+
+    /// Sets how long dropping this stream waits for unsent data to be sent, or sends it in the
+    /// background if `linger` is [`None`]
+    ///
+    /// A duration of zero resets the connection on drop instead of closing it gracefully.
+    pub fn set_linger(&mut self, linger: Option<Duration>) -> Result<()> {
+        self.0.set_linger(linger)
+    }
+
+    /// Gets the time-to-live (or hop limit for IPv6) of packets sent from this socket
+    pub fn ttl(&self) -> Result<u32> {
+        self.0.ttl()
+    }
+
+    /// Sets the time-to-live (or hop limit for IPv6) of packets sent from this socket
+    pub fn set_ttl(&mut self, ttl: u32) -> Result<()> {
+        self.0.set_ttl(ttl)
+    }
+
+    /// Gets the size of the kernel's receive buffer for this socket
+    pub fn recv_buffer_size(&self) -> Result<usize> {
+        self.0.recv_buffer_size()
+    }
+
+    /// Sets the size of the kernel's receive buffer for this socket
+    ///
+    /// The kernel doubles the value to allow for bookkeeping overhead.
+    pub fn set_recv_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.0.set_recv_buffer_size(size)
+    }
+
+    /// Gets the size of the kernel's send buffer for this socket
+    pub fn send_buffer_size(&self) -> Result<usize> {
+        self.0.send_buffer_size()
+    }
+
+    /// Sets the size of the kernel's send buffer for this socket
+    ///
+    /// The kernel doubles the value to allow for bookkeeping overhead.
+    pub fn set_send_buffer_size(&mut self, size: usize) -> Result<()> {
+        self.0.set_send_buffer_size(size)
+    }
+
+    /// Gets how long sent data may remain unacknowledged before the connection is dropped, with
+    /// zero meaning the system default
+    pub fn user_timeout(&self) -> Result<Duration> {
+        self.0.user_timeout()
+    }
+
+    /// Sets how long sent data may remain unacknowledged before the connection is dropped, with
+    /// zero meaning the system default
+    pub fn set_user_timeout(&mut self, timeout: Duration) -> Result<()> {
+        self.0.set_user_timeout(timeout)
+    }
+
+    /// Gets if partial frames are held back until the cork is removed
+    pub fn cork(&self) -> Result<bool> {
+        self.0.cork()
+    }
+
+    /// Sets if partial frames are held back until the cork is removed
+    ///
+    /// Removing the cork sends any frames being held back immediately.
+    pub fn set_cork(&mut self, cork: bool) -> Result<()> {
+        self.0.set_cork(cork)
+    }
+
+    /// Gets if acknowledgements are sent immediately rather than delayed
+    pub fn quickack(&self) -> Result<bool> {
+        self.0.quickack()
+    }
+
+    /// Sets if acknowledgements are sent immediately rather than delayed
+    ///
+    /// This is not permanent, the kernel may switch back to delayed acknowledgements later.
+    pub fn set_quickack(&mut self, quickack: bool) -> Result<()> {
+        self.0.set_quickack(quickack)
+    }
+
+    /// Gets the name of the congestion control algorithm used by this socket
+    pub fn congestion(&self) -> Result<String> {
+        self.0.congestion()
+    }
+
+    /// Sets the congestion control algorithm used by this socket, such as `"cubic"` or `"bbr"`
+    pub fn set_congestion(&mut self, name: &str) -> Result<()> {
+        self.0.set_congestion(name)
+    }
+
+    /// Gets and clears the pending error on this socket (`SO_ERROR`)
+    pub fn take_error(&self) -> Result<Option<Error>> {
+        self.0.take_error()
+    }
+
+    /// Gets the kernel's statistics about this connection
+    pub fn tcp_info(&self) -> Result<TCPInfo> {
+        self.0.tcp_info()
+    }
+
+    /// Reads from this into `buf`, failing with [`DeadlineError::Elapsed`] if nothing is read
+    /// before `deadline`
+    pub fn read_with_deadline<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+        deadline: Instant,
+    ) -> impl Future<Output = std::result::Result<usize, DeadlineError>> + 'a {
+        let read = FDRead::with_deadline(self, buf, Some(deadline));
+        async move { read.await.map_err(DeadlineError::from_linked) }
+    }
+
+    /// Writes `buf` into this, failing with [`DeadlineError::Elapsed`] if nothing is written
+    /// before `deadline`
+    pub fn write_with_deadline<'a>(
+        &'a mut self,
+        buf: &'a [u8],
+        deadline: Instant,
+    ) -> impl Future<Output = std::result::Result<usize, DeadlineError>> + 'a {
+        let write = FDWrite::with_deadline(self, buf, Some(deadline));
+        async move { write.await.map_err(DeadlineError::from_linked) }
+    }
+
+    /// Receives data into `buf` without removing it from the receive queue, returning the number of
+    /// bytes received
+    ///
+    /// A later read will receive the same data again.
+    pub async fn peek(&mut self, buf: &mut [u8]) -> Result<usize> {
+        SocketRecv::new(&self.0, buf, MessageFlags::PEEK.as_raw()).await
+    }
+
+    /// Receives data into `buf` with `flags`, returning the number of bytes received
+    pub async fn recv_with_flags(&mut self, buf: &mut [u8], flags: MessageFlags) -> Result<usize> {
+        SocketRecv::new(&self.0, buf, flags.as_raw()).await
+    }
+
+    /// Sends `buf` with `flags`, returning the number of bytes sent
+    pub async fn send_with_flags(&mut self, buf: &[u8], flags: MessageFlags) -> Result<usize> {
+        SocketSend::new(&self.0, buf, flags.as_raw()).await
+    }
+
+    /// Receives data into `buf` and ancillary data into `control` with `flags`
+    ///
+    /// The returned [`MessageInfo`] describes how much of each buffer was filled and if either was
+    /// truncated.
+    pub async fn recv_msg(
+        &mut self,
+        buf: &mut [u8],
+        control: &mut [u8],
+        flags: MessageFlags,
+    ) -> Result<MessageInfo> {
+        let received = RecvMsg::new(&self.0, buf, control, flags.as_raw()).await?;
+
+        Ok(MessageInfo::new(
+            received.length,
+            received.control_length,
+            MessageFlags::from_raw(received.flags),
+        ))
+    }
+
+    /// Sends `buf` along with the ancillary data in `control` with `flags`, returning the number of
+    /// bytes sent
+    pub async fn send_msg(
+        &mut self,
+        buf: &[u8],
+        control: &[u8],
+        flags: MessageFlags,
+    ) -> Result<usize> {
+        SendMsg::new(&self.0, buf, None, control, flags.as_raw()).await
+    }
+
+    /// Returns a future which sends `buf` without copying it into the kernel, yielding the number
+    /// of bytes sent along with `buf`
+    ///
+    /// The future only yields once the kernel has released `buf`. This is only faster than
+    /// [`Write::write`] for large buffers, as the kernel has to pin the pages and notify when they
+    /// are released. Fewer than `buf.len()` bytes may be sent.
+    pub fn send_zc(&mut self, buf: Vec<u8>) -> SendZc {
+        SendZc::new(self, buf)
+    }
+
+    /// Splits this stream into a reading half and a writing half which can be used at the same
+    /// time
+    pub fn split(&mut self) -> (ReadHalf, WriteHalf) {
+        split::split(self)
+    }
+
+    /// Splits this stream into a reading half and a writing half which can be used at the same
+    /// time, from separate tasks
+    ///
+    /// The halves can be joined back into a [`TCPStream`] with [`OwnedReadHalf::reunite`]. The
+    /// connection is closed once both halves are dropped.
+    pub fn into_split(self) -> (OwnedReadHalf, OwnedWriteHalf) {
+        split::into_split(self)
+    }
+
+    /// Sends `length` bytes of `file` starting at `offset` without copying them through userspace,
+    /// returning the number of bytes sent
+    ///
+    /// Fewer than `length` bytes will be sent if the end of `file` is reached.
+    pub async fn send_file(&mut self, file: &File, mut offset: u64, length: u64) -> Result<u64> {
+        let pipe = Pipe::new()?;
+
+        let mut sent = 0;
+        while sent < length {
+            let chunk = (length - sent).min(SEND_FILE_CHUNK_SIZE) as u32;
+
+            let mut buffered = Splice::new(file, Some(offset), pipe.writer(), None, chunk).await?;
+            if buffered == 0 {
+                break;
+            }
+            offset += buffered as u64;
+
+            while buffered > 0 {
+                let written =
+                    Splice::new(pipe.reader(), None, &*self, None, buffered as u32).await?;
+                if written == 0 {
+                    return Err(Error::ECONNRESET);
+                }
+
+                buffered -= written;
+                sent += written as u64;
+            }
+        }
+
+        Ok(sent)
+    }
+}
+
+impl AsFD for TCPStream {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl Read for TCPStream {
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDRead::new(self, buf)
+    }
+}
+
+impl Write for TCPStream {
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
+
+unsafe impl Send for TCPStream {}
+unsafe impl Sync for TCPStream {}
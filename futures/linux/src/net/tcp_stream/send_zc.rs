@@ -0,0 +1,173 @@
+use super::TCPStream;
+use crate::EventRef;
+use executor::{
+    platform::{
+        uring::{io_uring_cqe, io_uring_prep_send_zc, IORING_CQE_F_MORE, IORING_CQE_F_NOTIF},
+        Completion, EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    collections::VecDeque,
+    ffi::c_int,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Context, Poll},
+};
+
+/// A future which yields after sending bytes from a [`TCPStream`] without copying them into the
+/// kernel
+///
+/// The kernel signals completion twice: once with the number of bytes sent, then again once it no
+/// longer needs the buffer. This only yields after both, handing the buffer back. If the future is
+/// dropped before then, the buffer is kept alive until the kernel releases it.
+pub struct SendZc<'a> {
+    /// The stream to send on
+    stream: &'a TCPStream,
+
+    /// The bytes to send, taken when the future yields
+    buffer: Option<Vec<u8>>,
+
+    /// The event ID this is registered under
+    event_id: Result<EventRef>,
+
+    /// Has the SQE been submitted?
+    sqe_submitted: bool,
+
+    /// The result of the send, once the first completion has arrived
+    result: Option<c_int>,
+
+    /// Has the kernel released the buffer?
+    released: bool,
+}
+
+/// The callback for when a send or its notification is completed
+fn send_zc_callback(cqe: &mut io_uring_cqe, queue: &mut VecDeque<Completion>) {
+    queue.push_back(Completion::new(cqe));
+}
+
+/// The callback for completions after the [`SendZc`] was dropped
+fn release_callback(_: &mut io_uring_cqe) {}
+
+impl<'a> SendZc<'a> {
+    /// Creates a new [`SendZc`] future
+    pub(super) fn new(stream: &'a TCPStream, buffer: Vec<u8>) -> Self {
+        let event_id = EventRef::register(EventHandler::queue(send_zc_callback));
+
+        SendZc {
+            stream,
+            buffer: Some(buffer),
+            event_id,
+            sqe_submitted: false,
+            result: None,
+            released: false,
+        }
+    }
+
+    /// Records the completions which have arrived in `queue`
+    fn take_completions(&mut self, queue: &mut VecDeque<Completion>) {
+        while let Some(completion) = queue.pop_front() {
+            if completion.flags() & IORING_CQE_F_NOTIF != 0 {
+                self.released = true;
+                continue;
+            }
+
+            // Without `IORING_CQE_F_MORE` there will be no notification
+            self.result = Some(completion.result());
+            if completion.flags() & IORING_CQE_F_MORE == 0 {
+                self.released = true;
+            }
+        }
+    }
+}
+
+impl<'a> Future for SendZc<'a> {
+    type Output = (Result<usize>, Vec<u8>);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let event_id = match &self.event_id {
+            Ok(event_id) => **event_id,
+            Err(error) => {
+                let error = *error;
+                return Poll::Ready((Err(error), self.buffer.take().unwrap()));
+            }
+        };
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let sqe = manager.get_sqe(event_id).unwrap();
+
+                let buffer = self.buffer.as_ref().unwrap();
+                unsafe {
+                    io_uring_prep_send_zc(
+                        sqe.as_ptr(),
+                        self.stream.0.fd(),
+                        buffer.as_ptr().cast(),
+                        buffer.len(),
+                        0,
+                        0,
+                    )
+                };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+                self.result = None;
+                self.released = false;
+            }
+
+            let event = manager.get_event_mut(event_id).unwrap();
+            self.take_completions(event.data_mut().as_queue_mut());
+
+            let result = match self.result {
+                Some(result) if self.released => result,
+                _ => {
+                    event.set_waker(Some(cx.waker().clone()));
+                    return Poll::Pending;
+                }
+            };
+
+            self.sqe_submitted = false;
+
+            let buffer = self.buffer.take().unwrap();
+            if result < 0 {
+                return Poll::Ready((Err(Error::new(-result)), buffer));
+            }
+
+            Poll::Ready((Ok(result as usize), buffer))
+        })
+    }
+}
+
+impl<'a> Drop for SendZc<'a> {
+    fn drop(&mut self) {
+        if !self.sqe_submitted {
+            return;
+        }
+
+        let event_id = match std::mem::replace(&mut self.event_id, Err(Error::ECANCELED)) {
+            Ok(event_id) => event_id,
+            Err(_) => return,
+        };
+
+        EventManager::get_local_mut(|manager| {
+            let event = manager.get_event_mut(*event_id).unwrap();
+            self.take_completions(event.data_mut().as_queue_mut());
+
+            if self.result.is_none() {
+                manager.cancel(*event_id).unwrap();
+            }
+        });
+
+        // The kernel may still be reading from the buffer, so it lives with the event until the
+        // last completion
+        if !self.released {
+            let buffer = Rc::new(self.buffer.take().unwrap());
+            event_id.orphan(EventHandler::owned(release_callback, buffer));
+        }
+    }
+}
+
+impl<'a> !Send for SendZc<'a> {}
+impl<'a> !Sync for SendZc<'a> {}
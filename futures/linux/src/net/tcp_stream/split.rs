@@ -0,0 +1,155 @@
+use super::TCPStream;
+use crate::{
+    fd::FDWrite,
+    io::{Read, Write},
+    AsFD, FDRead,
+};
+use executor::Result;
+use std::{ffi::c_int, future::Future, rc::Rc};
+
+/// The reading half of a [`TCPStream`] borrowed by [`TCPStream::split`]
+pub struct ReadHalf<'a>(&'a TCPStream);
+
+/// The writing half of a [`TCPStream`] borrowed by [`TCPStream::split`]
+pub struct WriteHalf<'a>(&'a TCPStream);
+
+/// The reading half of a [`TCPStream`] owned after [`TCPStream::into_split`]
+pub struct OwnedReadHalf(Rc<TCPStream>);
+
+/// The writing half of a [`TCPStream`] owned after [`TCPStream::into_split`]
+pub struct OwnedWriteHalf(Rc<TCPStream>);
+
+/// An error returned by [`OwnedReadHalf::reunite`] when the halves are from different streams
+///
+/// The halves are returned unchanged.
+#[derive(Debug)]
+pub struct ReuniteError(pub OwnedReadHalf, pub OwnedWriteHalf);
+
+/// Splits `stream` into halves which borrow it
+pub(super) fn split(stream: &mut TCPStream) -> (ReadHalf, WriteHalf) {
+    (ReadHalf(stream), WriteHalf(stream))
+}
+
+/// Splits `stream` into halves which share ownership of it
+pub(super) fn into_split(stream: TCPStream) -> (OwnedReadHalf, OwnedWriteHalf) {
+    let stream = Rc::new(stream);
+    (OwnedReadHalf(stream.clone()), OwnedWriteHalf(stream))
+}
+
+impl OwnedReadHalf {
+    /// Joins this with `write_half` back into the original [`TCPStream`]
+    ///
+    /// Fails if `write_half` didn't come from the same stream.
+    pub fn reunite(
+        self,
+        write_half: OwnedWriteHalf,
+    ) -> std::result::Result<TCPStream, ReuniteError> {
+        if !Rc::ptr_eq(&self.0, &write_half.0) {
+            return Err(ReuniteError(self, write_half));
+        }
+
+        drop(write_half);
+        Ok(Rc::try_unwrap(self.0).unwrap_or_else(|_| unreachable!("both halves have been joined")))
+    }
+}
+
+impl OwnedWriteHalf {
+    /// Joins this with `read_half` back into the original [`TCPStream`]
+    ///
+    /// Fails if `read_half` didn't come from the same stream.
+    pub fn reunite(self, read_half: OwnedReadHalf) -> std::result::Result<TCPStream, ReuniteError> {
+        read_half.reunite(self)
+    }
+}
+
+impl<'a> AsRef<TCPStream> for ReadHalf<'a> {
+    fn as_ref(&self) -> &TCPStream {
+        self.0
+    }
+}
+
+impl<'a> AsRef<TCPStream> for WriteHalf<'a> {
+    fn as_ref(&self) -> &TCPStream {
+        self.0
+    }
+}
+
+impl AsRef<TCPStream> for OwnedReadHalf {
+    fn as_ref(&self) -> &TCPStream {
+        &self.0
+    }
+}
+
+impl AsRef<TCPStream> for OwnedWriteHalf {
+    fn as_ref(&self) -> &TCPStream {
+        &self.0
+    }
+}
+
+impl<'a> AsFD for ReadHalf<'a> {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl<'a> AsFD for WriteHalf<'a> {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl AsFD for OwnedReadHalf {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl AsFD for OwnedWriteHalf {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl<'a> Read for ReadHalf<'a> {
+    fn read<'b>(&'b mut self, buf: &'b mut [u8]) -> impl Future<Output = Result<usize>> + 'b {
+        FDRead::new(self, buf)
+    }
+}
+
+impl<'a> Write for WriteHalf<'a> {
+    fn write<'b>(&'b mut self, buf: &'b [u8]) -> impl Future<Output = Result<usize>> + 'b {
+        FDWrite::new(self, buf)
+    }
+}
+
+impl Read for OwnedReadHalf {
+    fn read<'a>(&'a mut self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDRead::new(self, buf)
+    }
+}
+
+impl Write for OwnedWriteHalf {
+    fn write<'a>(&'a mut self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
+
+impl std::fmt::Display for ReuniteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("tried to reunite halves that are not from the same stream")
+    }
+}
+
+impl std::error::Error for ReuniteError {}
+
+impl std::fmt::Debug for OwnedReadHalf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OwnedReadHalf").finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Debug for OwnedWriteHalf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OwnedWriteHalf").finish_non_exhaustive()
+    }
+}
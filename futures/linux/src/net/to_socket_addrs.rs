@@ -0,0 +1,119 @@
+use super::lookup_host;
+use executor::{Error, Result};
+use std::{
+    future::{ready, Future},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+};
+
+/// Asynchronous equivalent of [`std::net::ToSocketAddrs`]
+///
+/// Host names are resolved with [`lookup_host`], so they don't block the executor.
+pub trait ToSocketAddrs {
+    /// Resolves this into the socket addresses it refers to
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a;
+}
+
+impl ToSocketAddrs for SocketAddr {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![*self]))
+    }
+}
+
+impl ToSocketAddrs for SocketAddrV4 {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![SocketAddr::V4(*self)]))
+    }
+}
+
+impl ToSocketAddrs for SocketAddrV6 {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![SocketAddr::V6(*self)]))
+    }
+}
+
+impl ToSocketAddrs for (IpAddr, u16) {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![SocketAddr::new(self.0, self.1)]))
+    }
+}
+
+impl ToSocketAddrs for (Ipv4Addr, u16) {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![SocketAddr::new(self.0.into(), self.1)]))
+    }
+}
+
+impl ToSocketAddrs for (Ipv6Addr, u16) {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(vec![SocketAddr::new(self.0.into(), self.1)]))
+    }
+}
+
+impl ToSocketAddrs for (&str, u16) {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        lookup_host(self.0, self.1)
+    }
+}
+
+impl ToSocketAddrs for (String, u16) {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        lookup_host(&self.0, self.1)
+    }
+}
+
+impl ToSocketAddrs for str {
+    /// Resolves a string of the form `"host:port"`, where `host` is a name or address
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        async move {
+            if let Ok(addr) = self.parse() {
+                return Ok(vec![addr]);
+            }
+
+            let (host, port) = self.rsplit_once(':').ok_or(Error::EINVAL)?;
+            let port = port.parse().map_err(|_| Error::EINVAL)?;
+
+            lookup_host(host, port).await
+        }
+    }
+}
+
+impl ToSocketAddrs for String {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        self.as_str().to_socket_addrs()
+    }
+}
+
+impl ToSocketAddrs for [SocketAddr] {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(self.to_vec()))
+    }
+}
+
+impl ToSocketAddrs for Vec<SocketAddr> {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        ready(Ok(self.clone()))
+    }
+}
+
+impl<T: ToSocketAddrs + ?Sized> ToSocketAddrs for &T {
+    fn to_socket_addrs<'a>(&'a self) -> impl Future<Output = Result<Vec<SocketAddr>>> + 'a {
+        (**self).to_socket_addrs()
+    }
+}
+
+/// Resolves `addrs` and calls `f` with each address until it succeeds, returning the last error if
+/// none succeed
+pub(super) async fn each_addr<A: ToSocketAddrs, T, F: FnMut(SocketAddr) -> Result<T>>(
+    addrs: A,
+    mut f: F,
+) -> Result<T> {
+    let mut last_error = Error::EINVAL;
+    for addr in addrs.to_socket_addrs().await? {
+        match f(addr) {
+            Ok(value) => return Ok(value),
+            Err(error) => last_error = error,
+        }
+    }
+
+    Err(last_error)
+}
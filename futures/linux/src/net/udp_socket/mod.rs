@@ -0,0 +1,148 @@
+use super::{each_addr, RecvMsg, SendMsg, Socket, SocketAddress, ToSocketAddrs};
+use crate::AsFD;
+use executor::{platform::linux::sys::socket::SOCK_DGRAM, Result};
+use std::{
+    ffi::c_int,
+    future::Future,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+};
+
+mod recv_multishot;
+
+pub use recv_multishot::{RecvMultishot, RecvMultishotNext};
+
+/// A UDP socket for sending and receiving datagrams
+pub struct UDPSocket(Socket);
+
+impl UDPSocket {
+    /// Creates a new [`UDPSocket`] bound to the first address `addrs` resolves to which can be
+    /// bound
+    pub async fn bind<A: ToSocketAddrs>(addrs: A) -> Result<Self> {
+        each_addr(addrs, UDPSocket::bind_addr).await
+    }
+
+    /// Creates a new [`UDPSocket`] bound to `addr`
+    fn bind_addr(addr: SocketAddr) -> Result<Self> {
+        let socket_address: SocketAddress = addr.into();
+
+        let mut socket = Socket::new(socket_address.family(), SOCK_DGRAM)?;
+        socket.bind(&socket_address)?;
+
+        Ok(UDPSocket(socket))
+    }
+
+    /// Connects this socket to the first address `addrs` resolves to which can be connected to,
+    /// setting the destination for [`UDPSocket::send`] and filtering datagrams received to only
+    /// those from that address
+    pub async fn connect<A: ToSocketAddrs>(&mut self, addrs: A) -> Result<()> {
+        each_addr(addrs, |addr| self.0.connect(&addr.into())).await
+    }
+
+    /// Gets the locally bound address
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.0.local_addr().map(|addr| addr.into())
+    }
+
+    /// Gets the address this socket is connected to
+    pub fn peer_addr(&self) -> Result<SocketAddr> {
+        self.0.peer_addr().map(|addr| addr.into())
+    }
+
+    /// Returns a future which sends `buf` to the connected address, yielding the number of bytes
+    /// sent
+    pub fn send<'a>(&'a self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        SendMsg::new(&self.0, buf, None, &[], 0)
+    }
+
+    /// Returns a future which sends `buf` to `addr`, yielding the number of bytes sent
+    pub fn send_to<'a>(
+        &'a self,
+        buf: &'a [u8],
+        addr: SocketAddr,
+    ) -> impl Future<Output = Result<usize>> + 'a {
+        SendMsg::new(&self.0, buf, Some(addr.into()), &[], 0)
+    }
+
+    /// Returns a future which receives a datagram into `buf`, yielding the number of bytes
+    /// received
+    ///
+    /// If the datagram is larger than `buf`, the excess bytes are discarded.
+    pub fn recv<'a>(&'a self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        let recv_msg = RecvMsg::new(&self.0, buf, &mut [], 0);
+        async move { recv_msg.await.map(|received| received.length) }
+    }
+
+    /// Returns a future which receives a datagram into `buf`, yielding the number of bytes
+    /// received and the address it was sent from
+    ///
+    /// If the datagram is larger than `buf`, the excess bytes are discarded.
+    pub fn recv_from<'a>(
+        &'a self,
+        buf: &'a mut [u8],
+    ) -> impl Future<Output = Result<(usize, SocketAddr)>> + 'a {
+        let recv_msg = RecvMsg::new(&self.0, buf, &mut [], 0);
+        async move {
+            recv_msg
+                .await
+                .map(|received| (received.length, received.socket_address.into()))
+        }
+    }
+
+    /// Starts receiving datagrams with a single multishot receive, using a ring of `buffers`
+    /// buffers of `buffer_size` bytes each
+    ///
+    /// `buffers` must be a power of two no greater than 32768. Each datagram received is copied
+    /// out of its buffer, so `buffer_size` only needs to fit the largest datagram expected.
+    /// Datagrams larger than that are truncated.
+    pub fn recv_multishot(&self, buffers: u32, buffer_size: usize) -> Result<RecvMultishot> {
+        RecvMultishot::new(self, buffers, buffer_size)
+    }
+
+    /// Gets if this socket is allowed to send to broadcast addresses
+    pub fn broadcast(&self) -> Result<bool> {
+        self.0.broadcast()
+    }
+
+    /// Sets if this socket is allowed to send to broadcast addresses
+    pub fn set_broadcast(&mut self, broadcast: bool) -> Result<()> {
+        self.0.set_broadcast(broadcast)
+    }
+
+    /// Joins the IPv4 multicast group `multiaddr` on the interface with the address `interface`
+    ///
+    /// If `interface` is [`Ipv4Addr::UNSPECIFIED`], the system chooses the interface.
+    pub fn join_multicast_v4(&mut self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        self.0.set_multicast_v4(multiaddr, interface, true)
+    }
+
+    /// Leaves the IPv4 multicast group `multiaddr` on the interface with the address `interface`
+    pub fn leave_multicast_v4(&mut self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> Result<()> {
+        self.0.set_multicast_v4(multiaddr, interface, false)
+    }
+
+    /// Joins the IPv6 multicast group `multiaddr` on the interface with the index `interface`
+    ///
+    /// If `interface` is 0, the system chooses the interface.
+    pub fn join_multicast_v6(&mut self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        self.0.set_multicast_v6(multiaddr, interface, true)
+    }
+
+    /// Leaves the IPv6 multicast group `multiaddr` on the interface with the index `interface`
+    pub fn leave_multicast_v6(&mut self, multiaddr: &Ipv6Addr, interface: u32) -> Result<()> {
+        self.0.set_multicast_v6(multiaddr, interface, false)
+    }
+
+    /// Gets the family of the underlying socket
+    pub(super) fn family(&self) -> c_int {
+        self.0.family()
+    }
+}
+
+impl AsFD for UDPSocket {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+unsafe impl Send for UDPSocket {}
+unsafe impl Sync for UDPSocket {}
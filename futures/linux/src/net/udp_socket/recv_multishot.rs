@@ -0,0 +1,200 @@
+use super::{SocketAddress, UDPSocket};
+use crate::{BufferRingRef, EventRef};
+use executor::{
+    platform::{
+        linux::sys::socket::msghdr,
+        uring::{
+            io_uring_cqe, io_uring_prep_cancel64, io_uring_prep_recvmsg_multishot,
+            io_uring_recvmsg_name, io_uring_recvmsg_payload, io_uring_recvmsg_payload_length,
+            io_uring_recvmsg_validate, io_uring_sqe_set_buf_group, io_uring_sqe_set_flags,
+            IORING_CQE_BUFFER_SHIFT, IORING_CQE_F_MORE, IOSQE_BUFFER_SELECT,
+        },
+        Completion, EventHandler,
+    },
+    Error, EventManager, Result,
+};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    ptr::null_mut,
+    task::{Context, Poll},
+};
+
+/// A stream of datagrams received by a [`UDPSocket`] using a single multishot receive
+///
+/// Datagrams which arrive before [`RecvMultishot::next`] is called are buffered until they are
+/// requested. The receive is transparently re-armed if the kernel stops it.
+pub struct RecvMultishot<'a> {
+    /// The socket to receive from
+    socket: &'a UDPSocket,
+
+    /// The message header describing the layout of the received buffers to the kernel
+    header: Box<msghdr>,
+
+    /// The ring of buffers the kernel receives datagrams into
+    buffer_ring: BufferRingRef,
+
+    /// The event ID this is registered under
+    event_id: EventRef,
+
+    /// Is there a multishot receive in flight?
+    armed: bool,
+}
+
+/// A future which yields the next datagram from a [`RecvMultishot`]
+pub struct RecvMultishotNext<'b, 'a>(&'b mut RecvMultishot<'a>);
+
+/// The callback for when a datagram is received
+fn recv_multishot_callback(cqe: &mut io_uring_cqe, queue: &mut VecDeque<Completion>) {
+    queue.push_back(Completion::new(cqe));
+}
+
+impl<'a> RecvMultishot<'a> {
+    /// Creates a new [`RecvMultishot`] with `buffers` buffers of `buffer_size` bytes
+    pub(super) fn new(socket: &'a UDPSocket, buffers: u32, buffer_size: usize) -> Result<Self> {
+        let buffer_ring = BufferRingRef::register(buffers, buffer_size)?;
+        let event_id = EventRef::register(EventHandler::queue(recv_multishot_callback))?;
+
+        let header = Box::new(msghdr {
+            name: null_mut(),
+            name_len: SocketAddress::default(socket.family()).len() as _,
+            iov: null_mut(),
+            iov_len: 0,
+            control: null_mut(),
+            control_len: 0,
+            flags: 0,
+        });
+
+        Ok(RecvMultishot {
+            socket,
+            header,
+            buffer_ring,
+            event_id,
+            armed: false,
+        })
+    }
+
+    /// Returns a future which yields the next datagram received and the address it was sent from
+    ///
+    /// The stream never ends, so the future only yields [`None`] for compatibility with iterators.
+    pub fn next(&mut self) -> RecvMultishotNext<'_, 'a> {
+        RecvMultishotNext(self)
+    }
+
+    /// Polls for the next buffered datagram, re-arming the receive if required
+    fn poll_next(&mut self, cx: &mut Context) -> Poll<Option<Result<(Vec<u8>, SocketAddr)>>> {
+        EventManager::get_local_mut(|manager| loop {
+            // Submit the SQE if the kernel isn't currently receiving
+            if !self.armed {
+                let sqe = manager.get_sqe(*self.event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_recvmsg_multishot(
+                        sqe.as_ptr(),
+                        self.socket.0.fd(),
+                        &mut *self.header,
+                        0,
+                    );
+                    io_uring_sqe_set_flags(sqe.as_ptr(), IOSQE_BUFFER_SELECT);
+                    io_uring_sqe_set_buf_group(sqe.as_ptr(), *self.buffer_ring as _);
+                };
+
+                sqe.submit().unwrap();
+                self.armed = true;
+            }
+
+            let event = manager.get_event_mut(*self.event_id).unwrap();
+            let completion = match event.data_mut().as_queue_mut().pop_front() {
+                Some(completion) => completion,
+                None => {
+                    event.set_waker(Some(cx.waker().clone()));
+                    return Poll::Pending;
+                }
+            };
+
+            if completion.flags() & IORING_CQE_F_MORE == 0 {
+                self.armed = false;
+            }
+
+            if completion.result() < 0 {
+                let error = Error::new(-completion.result());
+
+                // Every buffer was in use, they are returned as the queued datagrams are consumed
+                if error == Error::ENOBUFS {
+                    continue;
+                }
+
+                return Poll::Ready(Some(Err(error)));
+            }
+
+            let id = (completion.flags() >> IORING_CQE_BUFFER_SHIFT) as u16;
+            let buffer_ring = manager.get_buffer_ring_mut(*self.buffer_ring).unwrap();
+
+            let datagram = self.parse(buffer_ring.get(id), completion.result() as usize);
+            buffer_ring.recycle(id);
+
+            return Poll::Ready(Some(datagram));
+        })
+    }
+
+    /// Copies the datagram and source address out of a received `buffer` of `length` bytes
+    fn parse(&mut self, buffer: &[u8], length: usize) -> Result<(Vec<u8>, SocketAddr)> {
+        let out = unsafe {
+            io_uring_recvmsg_validate(buffer.as_ptr() as _, length as _, &mut *self.header)
+        };
+        if out == null_mut() {
+            return Err(Error::EINVAL);
+        }
+
+        let payload = unsafe {
+            let payload = io_uring_recvmsg_payload(out, &mut *self.header);
+            let payload_length =
+                io_uring_recvmsg_payload_length(out, length as _, &mut *self.header);
+
+            std::slice::from_raw_parts(payload as *const u8, payload_length as usize).to_vec()
+        };
+
+        let mut socket_address = SocketAddress::default(self.socket.family());
+        unsafe {
+            let name_length = ((*out).namelen as usize).min(socket_address.len());
+
+            std::ptr::copy_nonoverlapping(
+                io_uring_recvmsg_name(out) as *const u8,
+                socket_address.as_mut_ptr() as *mut u8,
+                name_length,
+            );
+        }
+
+        Ok((payload, socket_address.into()))
+    }
+}
+
+impl<'a> Drop for RecvMultishot<'a> {
+    fn drop(&mut self) {
+        if self.armed {
+            EventManager::get_local_mut(|manager| {
+                let sqe = manager.get_sqe(*self.event_id).unwrap();
+
+                unsafe { io_uring_prep_cancel64(sqe.as_ptr(), (*self.event_id).into_u64(), 0) };
+
+                sqe.submit().unwrap();
+            })
+        }
+    }
+}
+
+impl<'a> !Send for RecvMultishot<'a> {}
+impl<'a> !Sync for RecvMultishot<'a> {}
+
+impl<'b, 'a> Future for RecvMultishotNext<'b, 'a> {
+    type Output = Option<Result<(Vec<u8>, SocketAddr)>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.0.poll_next(cx)
+    }
+}
+
+impl<'b, 'a> !Send for RecvMultishotNext<'b, 'a> {}
+impl<'b, 'a> !Sync for RecvMultishotNext<'b, 'a> {}
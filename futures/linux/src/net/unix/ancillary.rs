@@ -0,0 +1,78 @@
+//! Encoding and decoding of `SCM_RIGHTS` ancillary data
+
+use executor::platform::linux::sys::socket::{SCM_RIGHTS, SOL_SOCKET};
+use std::{
+    ffi::c_int,
+    os::fd::{FromRawFd, OwnedFd, RawFd},
+};
+
+/// The size of a `cmsghdr`, the header before each piece of ancillary data
+const HEADER_SIZE: usize = std::mem::size_of::<usize>() + 2 * std::mem::size_of::<c_int>();
+
+/// Rounds `length` up to the alignment of ancillary data (`CMSG_ALIGN`)
+const fn align(length: usize) -> usize {
+    (length + std::mem::size_of::<usize>() - 1) & !(std::mem::size_of::<usize>() - 1)
+}
+
+/// Gets the size of the control buffer needed to hold `count` file descriptors (`CMSG_SPACE`)
+pub(super) const fn rights_space(count: usize) -> usize {
+    align(HEADER_SIZE) + align(count * std::mem::size_of::<c_int>())
+}
+
+/// Encodes `fds` as a control buffer with a single `SCM_RIGHTS` message
+pub(super) fn encode_rights(fds: &[RawFd]) -> Vec<u8> {
+    if fds.len() == 0 {
+        return Vec::new();
+    }
+
+    let length = HEADER_SIZE + fds.len() * std::mem::size_of::<c_int>();
+
+    let mut control = Vec::with_capacity(rights_space(fds.len()));
+    control.extend_from_slice(&length.to_ne_bytes());
+    control.extend_from_slice(&SOL_SOCKET.to_ne_bytes());
+    control.extend_from_slice(&SCM_RIGHTS.to_ne_bytes());
+    for fd in fds {
+        control.extend_from_slice(&fd.to_ne_bytes());
+    }
+    control.resize(rights_space(fds.len()), 0);
+
+    control
+}
+
+/// Takes ownership of the file descriptors in every `SCM_RIGHTS` message of `control`
+pub(super) fn decode_rights(control: &[u8]) -> Vec<OwnedFd> {
+    const INT_SIZE: usize = std::mem::size_of::<c_int>();
+    const USIZE_SIZE: usize = std::mem::size_of::<usize>();
+
+    let mut fds = Vec::new();
+
+    let mut offset = 0;
+    while offset + HEADER_SIZE <= control.len() {
+        let length = usize::from_ne_bytes(control[offset..][..USIZE_SIZE].try_into().unwrap());
+        let level = c_int::from_ne_bytes(
+            control[offset + USIZE_SIZE..][..INT_SIZE]
+                .try_into()
+                .unwrap(),
+        );
+        let kind = c_int::from_ne_bytes(
+            control[offset + USIZE_SIZE + INT_SIZE..][..INT_SIZE]
+                .try_into()
+                .unwrap(),
+        );
+
+        if length < HEADER_SIZE || offset + length > control.len() {
+            break;
+        }
+
+        if level == SOL_SOCKET && kind == SCM_RIGHTS {
+            for fd in control[offset + HEADER_SIZE..offset + length].chunks_exact(INT_SIZE) {
+                let fd = c_int::from_ne_bytes(fd.try_into().unwrap());
+                fds.push(unsafe { OwnedFd::from_raw_fd(fd) });
+            }
+        }
+
+        offset += align(length);
+    }
+
+    fds
+}
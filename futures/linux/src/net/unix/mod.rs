@@ -0,0 +1,16 @@
+//! Unix domain sockets
+
+use super::{RecvMsg, SendMsg, Socket, SocketAddress};
+
+mod ancillary;
+mod peer_credentials;
+mod unix_datagram;
+mod unix_listener;
+mod unix_socket_addr;
+mod unix_stream;
+
+pub use peer_credentials::PeerCredentials;
+pub use unix_datagram::UnixDatagram;
+pub use unix_listener::UnixListener;
+pub use unix_socket_addr::UnixSocketAddr;
+pub use unix_stream::UnixStream;
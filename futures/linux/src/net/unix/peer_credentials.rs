@@ -0,0 +1,42 @@
+use executor::platform::linux::sys::socket::ucred;
+
+/// The credentials of the process on the other end of a Unix domain socket, captured when the
+/// connection was established
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerCredentials {
+    /// The process ID
+    pid: i32,
+
+    /// The user ID
+    uid: u32,
+
+    /// The group ID
+    gid: u32,
+}
+
+impl PeerCredentials {
+    /// Gets the process ID of the peer
+    pub fn pid(&self) -> i32 {
+        self.pid
+    }
+
+    /// Gets the effective user ID of the peer
+    pub fn uid(&self) -> u32 {
+        self.uid
+    }
+
+    /// Gets the effective group ID of the peer
+    pub fn gid(&self) -> u32 {
+        self.gid
+    }
+}
+
+impl From<ucred> for PeerCredentials {
+    fn from(credentials: ucred) -> Self {
+        PeerCredentials {
+            pid: credentials.pid as _,
+            uid: credentials.uid as _,
+            gid: credentials.gid as _,
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use super::{ancillary, RecvMsg, SendMsg, Socket, SocketAddress, UnixSocketAddr};
+use crate::AsFD;
+use executor::{
+    platform::linux::sys::socket::{AF_UNIX, MSG_CMSG_CLOEXEC, SOCK_DGRAM},
+    Result,
+};
+use std::{
+    ffi::c_int,
+    future::Future,
+    os::fd::{OwnedFd, RawFd},
+};
+
+/// A Unix domain socket for sending and receiving datagrams
+pub struct UnixDatagram(Socket);
+
+impl UnixDatagram {
+    /// Creates a new [`UnixDatagram`] bound to `addr`
+    pub fn bind<A: Into<UnixSocketAddr>>(addr: A) -> Result<Self> {
+        let socket_address = SocketAddress::try_from(&addr.into())?;
+
+        let mut socket = Socket::new(AF_UNIX, SOCK_DGRAM)?;
+        socket.bind(&socket_address)?;
+
+        Ok(UnixDatagram(socket))
+    }
+
+    /// Creates a new [`UnixDatagram`] which is not bound to any address
+    pub fn unbound() -> Result<Self> {
+        Socket::new(AF_UNIX, SOCK_DGRAM).map(UnixDatagram)
+    }
+
+    /// Creates a pair of [`UnixDatagram`]s connected to each other
+    pub fn pair() -> Result<(Self, Self)> {
+        Socket::pair(AF_UNIX, SOCK_DGRAM).map(|(a, b)| (UnixDatagram(a), UnixDatagram(b)))
+    }
+
+    /// Connects this socket to `addr`, setting the destination for [`UnixDatagram::send`] and
+    /// filtering datagrams received to only those from `addr`
+    pub fn connect<A: Into<UnixSocketAddr>>(&mut self, addr: A) -> Result<()> {
+        self.0.connect(&SocketAddress::try_from(&addr.into())?)
+    }
+
+    /// Gets the locally bound address
+    pub fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.0.local_addr().map(|addr| addr.into_unix())
+    }
+
+    /// Gets the address this socket is connected to
+    pub fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.0.peer_addr().map(|addr| addr.into_unix())
+    }
+
+    /// Returns a future which sends `buf` to the connected address, yielding the number of bytes
+    /// sent
+    pub fn send<'a>(&'a self, buf: &'a [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        SendMsg::new(&self.0, buf, None, &[], 0)
+    }
+
+    /// Sends `buf` to `addr`, returning the number of bytes sent
+    pub async fn send_to<A: Into<UnixSocketAddr>>(&self, buf: &[u8], addr: A) -> Result<usize> {
+        let socket_address = SocketAddress::try_from(&addr.into())?;
+        SendMsg::new(&self.0, buf, Some(socket_address), &[], 0).await
+    }
+
+    /// Returns a future which receives a datagram into `buf`, yielding the number of bytes
+    /// received
+    ///
+    /// If the datagram is larger than `buf`, the excess bytes are discarded.
+    pub fn recv<'a>(&'a self, buf: &'a mut [u8]) -> impl Future<Output = Result<usize>> + 'a {
+        let recv_msg = RecvMsg::new(&self.0, buf, &mut [], 0);
+        async move { recv_msg.await.map(|received| received.length) }
+    }
+
+    /// Returns a future which receives a datagram into `buf`, yielding the number of bytes
+    /// received and the address it was sent from
+    ///
+    /// If the datagram is larger than `buf`, the excess bytes are discarded.
+    pub fn recv_from<'a>(
+        &'a self,
+        buf: &'a mut [u8],
+    ) -> impl Future<Output = Result<(usize, UnixSocketAddr)>> + 'a {
+        let recv_msg = RecvMsg::new(&self.0, buf, &mut [], 0);
+        async move {
+            recv_msg
+                .await
+                .map(|received| (received.length, received.socket_address.into_unix()))
+        }
+    }
+
+    /// Sends `buf` along with duplicates of `fds` to the connected address, returning the number
+    /// of bytes of `buf` sent
+    ///
+    /// The descriptors in `fds` must remain open until this completes.
+    pub async fn send_with_fds(&self, buf: &[u8], fds: &[RawFd]) -> Result<usize> {
+        let control = ancillary::encode_rights(fds);
+        SendMsg::new(&self.0, buf, None, &control, 0).await
+    }
+
+    /// Receives a datagram into `buf` along with at most `max_fds` file descriptors, returning
+    /// the number of bytes received and the descriptors
+    ///
+    /// The received descriptors are marked close-on-exec. Any descriptors beyond `max_fds` are
+    /// discarded by the kernel.
+    pub async fn recv_with_fds(
+        &self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> Result<(usize, Vec<OwnedFd>)> {
+        let mut control = vec![0; ancillary::rights_space(max_fds)];
+        let received = RecvMsg::new(&self.0, buf, &mut control, MSG_CMSG_CLOEXEC).await?;
+
+        let fds = ancillary::decode_rights(&control[..received.control_length]);
+        Ok((received.length, fds))
+    }
+}
+
+impl AsFD for UnixDatagram {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+unsafe impl Send for UnixDatagram {}
+unsafe impl Sync for UnixDatagram {}
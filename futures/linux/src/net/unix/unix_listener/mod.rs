@@ -0,0 +1,41 @@
+use super::{Socket, SocketAddress, UnixSocketAddr};
+use executor::{
+    platform::linux::sys::socket::{AF_UNIX, SOCK_STREAM, SOMAXCONN},
+    Result,
+};
+
+mod accept;
+
+pub use accept::Accept;
+
+/// A listening Unix domain socket for stream connections
+pub struct UnixListener(Socket);
+
+impl UnixListener {
+    /// Creates a new [`UnixListener`] bound to `addr`
+    ///
+    /// Binding to a filesystem path creates the socket file, which is not removed when the
+    /// listener is dropped.
+    pub fn bind<A: Into<UnixSocketAddr>>(addr: A) -> Result<Self> {
+        let socket_address = SocketAddress::try_from(&addr.into())?;
+
+        let mut socket = Socket::new(AF_UNIX, SOCK_STREAM)?;
+        socket.bind(&socket_address)?;
+        socket.listen(SOMAXCONN)?;
+
+        Ok(UnixListener(socket))
+    }
+
+    /// Gets the local address of this socket
+    pub fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.0.local_addr().map(|address| address.into_unix())
+    }
+
+    /// Returns a future which yields when a new client connects to this socket
+    pub fn accept(&self) -> Accept {
+        Accept::new(self)
+    }
+}
+
+unsafe impl Send for UnixListener {}
+unsafe impl Sync for UnixListener {}
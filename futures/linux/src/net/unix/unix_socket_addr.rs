@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+/// An address associated with a Unix domain socket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnixSocketAddr {
+    /// The socket is not bound to an address
+    Unnamed,
+
+    /// The socket is bound to a path on the filesystem
+    Pathname(PathBuf),
+
+    /// The socket is bound to a name in the abstract namespace, which does not appear on the
+    /// filesystem
+    Abstract(Vec<u8>),
+}
+
+impl UnixSocketAddr {
+    /// Creates a new [`UnixSocketAddr`] for `name` in the abstract namespace
+    pub fn from_abstract_name<N: AsRef<[u8]>>(name: N) -> Self {
+        UnixSocketAddr::Abstract(name.as_ref().to_vec())
+    }
+
+    /// Gets the path on the filesystem if this is a [`UnixSocketAddr::Pathname`]
+    pub fn as_pathname(&self) -> Option<&Path> {
+        match self {
+            UnixSocketAddr::Pathname(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Gets the name in the abstract namespace if this is a [`UnixSocketAddr::Abstract`]
+    pub fn as_abstract_name(&self) -> Option<&[u8]> {
+        match self {
+            UnixSocketAddr::Abstract(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Is this address unnamed?
+    pub fn is_unnamed(&self) -> bool {
+        *self == UnixSocketAddr::Unnamed
+    }
+}
+
+impl From<PathBuf> for UnixSocketAddr {
+    fn from(path: PathBuf) -> Self {
+        UnixSocketAddr::Pathname(path)
+    }
+}
+
+impl From<&Path> for UnixSocketAddr {
+    fn from(path: &Path) -> Self {
+        UnixSocketAddr::Pathname(path.to_owned())
+    }
+}
+
+impl From<&str> for UnixSocketAddr {
+    fn from(path: &str) -> Self {
+        UnixSocketAddr::Pathname(path.into())
+    }
+}
@@ -0,0 +1,106 @@
+use super::{ancillary, PeerCredentials, RecvMsg, SendMsg, Socket, UnixSocketAddr};
+use crate::{
+    fd::FDWrite,
+    io::{Read, Write},
+    AsFD, FDRead,
+};
+use executor::{
+    platform::linux::sys::socket::{AF_UNIX, MSG_CMSG_CLOEXEC, SOCK_STREAM},
+    Result,
+};
+use std::{
+    ffi::c_int,
+    os::fd::{OwnedFd, RawFd},
+};
+
+mod connect;
+
+pub use connect::Connect;
+
+/// A stream between a local and a remote Unix domain socket
+pub struct UnixStream(Socket);
+
+impl UnixStream {
+    /// Creates a [`UnixStream`] directly from `fd`
+    pub(super) unsafe fn from_raw(fd: c_int) -> Self {
+        UnixStream(Socket::from_raw(fd, AF_UNIX))
+    }
+
+    /// Returns a future which yields a new [`UnixStream`] connected to `addr`
+    pub fn connect<A: Into<UnixSocketAddr>>(addr: A) -> Connect {
+        Connect::new(&addr.into())
+    }
+
+    /// Creates a pair of [`UnixStream`]s connected to each other
+    pub fn pair() -> Result<(Self, Self)> {
+        Socket::pair(AF_UNIX, SOCK_STREAM).map(|(a, b)| (UnixStream(a), UnixStream(b)))
+    }
+
+    /// Gets the locally bound address
+    pub fn local_addr(&self) -> Result<UnixSocketAddr> {
+        self.0.local_addr().map(|addr| addr.into_unix())
+    }
+
+    /// Gets the remote address of the peer
+    pub fn peer_addr(&self) -> Result<UnixSocketAddr> {
+        self.0.peer_addr().map(|addr| addr.into_unix())
+    }
+
+    /// Gets the credentials of the process which connected the peer of this socket
+    pub fn peer_cred(&self) -> Result<PeerCredentials> {
+        self.0.peer_cred().map(|credentials| credentials.into())
+    }
+
+    /// Sends `buf` along with duplicates of `fds` to the peer, returning the number of bytes of
+    /// `buf` sent
+    ///
+    /// The descriptors in `fds` must remain open until this completes.
+    pub async fn send_with_fds(&mut self, buf: &[u8], fds: &[RawFd]) -> Result<usize> {
+        let control = ancillary::encode_rights(fds);
+        SendMsg::new(&self.0, buf, None, &control, 0).await
+    }
+
+    /// Receives data into `buf` along with at most `max_fds` file descriptors sent by the peer,
+    /// returning the number of bytes received and the descriptors
+    ///
+    /// The received descriptors are marked close-on-exec. Any descriptors beyond `max_fds` are
+    /// discarded by the kernel.
+    pub async fn recv_with_fds(
+        &mut self,
+        buf: &mut [u8],
+        max_fds: usize,
+    ) -> Result<(usize, Vec<OwnedFd>)> {
+        let mut control = vec![0; ancillary::rights_space(max_fds)];
+        let received = RecvMsg::new(&self.0, buf, &mut control, MSG_CMSG_CLOEXEC).await?;
+
+        let fds = ancillary::decode_rights(&control[..received.control_length]);
+        Ok((received.length, fds))
+    }
+}
+
+impl AsFD for UnixStream {
+    unsafe fn fd(&self) -> c_int {
+        self.0.fd()
+    }
+}
+
+impl Read for UnixStream {
+    fn read<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+    ) -> impl std::future::Future<Output = Result<usize>> + 'a {
+        FDRead::new(self, buf)
+    }
+}
+
+impl Write for UnixStream {
+    fn write<'a>(
+        &'a mut self,
+        buf: &'a [u8],
+    ) -> impl std::future::Future<Output = Result<usize>> + 'a {
+        FDWrite::new(self, buf)
+    }
+}
+
+unsafe impl Send for UnixStream {}
+unsafe impl Sync for UnixStream {}
@@ -0,0 +1,43 @@
+// rustdoc imports
+#[allow(unused_imports)]
+use super::LocalSemaphore;
+
+/// The error from acquiring permits from a [`LocalSemaphore`] which has been closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcquireError(());
+
+/// The error from attempting to acquire permits from a [`LocalSemaphore`] without waiting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TryAcquireError {
+    /// The semaphore has been closed
+    Closed,
+
+    /// There weren't enough permits available, or other tasks are waiting for them
+    NoPermits,
+}
+
+impl AcquireError {
+    /// Creates a new [`AcquireError`]
+    pub(super) fn new() -> Self {
+        AcquireError(())
+    }
+}
+
+impl std::fmt::Display for AcquireError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("semaphore closed")
+    }
+}
+
+impl std::error::Error for AcquireError {}
+
+impl std::fmt::Display for TryAcquireError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TryAcquireError::Closed => "semaphore closed",
+            TryAcquireError::NoPermits => "no permits available",
+        })
+    }
+}
+
+impl std::error::Error for TryAcquireError {}
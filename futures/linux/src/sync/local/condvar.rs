@@ -0,0 +1,180 @@
+use super::{LocalMutex, LocalMutexGuard};
+use crate::time::TimeoutExt;
+use executor::platform::WaitQueue;
+use std::{
+    cell::RefCell,
+    future::{poll_fn, Future},
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+/// A condition variable, letting tasks holding a [`LocalMutex`] wait until another task signals
+/// them
+///
+/// Each waiting task is queued separately, so a notification is only lost if no task is waiting
+/// when it is sent. Like other condition variables, a task may wake without being notified and
+/// should check its condition again, which [`LocalCondvar::wait_while`] does. This can only be
+/// used on one thread.
+pub struct LocalCondvar {
+    /// The tasks waiting to be notified
+    waiters: RefCell<WaitQueue>,
+}
+
+/// Whether a [`LocalCondvar::wait_timeout`] returned because the timeout passed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WaitTimeoutResult(bool);
+
+/// A [`Future`] which yields once a waiting task is notified
+struct Waiter<'a> {
+    /// The condition variable being waited on
+    condvar: &'a LocalCondvar,
+
+    /// The key this is waiting under
+    key: u64,
+
+    /// Has this yielded?
+    finished: bool,
+}
+
+impl LocalCondvar {
+    /// Creates a new [`LocalCondvar`] with no waiting tasks
+    pub const fn new() -> Self {
+        LocalCondvar {
+            waiters: RefCell::new(WaitQueue::new()),
+        }
+    }
+
+    /// Unlocks the mutex held by `guard` and waits to be notified, locking it again before
+    /// returning
+    ///
+    /// The task is queued before the mutex is unlocked, so a notification sent by a task after it
+    /// takes the lock can't be missed.
+    pub async fn wait<'a, T>(&self, guard: LocalMutexGuard<'a, T>) -> LocalMutexGuard<'a, T> {
+        let mutex = guard.mutex();
+
+        let waiter = self.waiter().await;
+        drop(guard);
+        waiter.await;
+
+        mutex.lock().await
+    }
+
+    /// Waits to be notified until `condition` returns `false`, holding the lock whenever it is
+    /// checked
+    pub async fn wait_while<'a, T, F: FnMut(&mut T) -> bool>(
+        &self,
+        mut guard: LocalMutexGuard<'a, T>,
+        mut condition: F,
+    ) -> LocalMutexGuard<'a, T> {
+        while condition(&mut *guard) {
+            guard = self.wait(guard).await;
+        }
+
+        guard
+    }
+
+    /// Unlocks the mutex held by `guard` and waits to be notified or for `timeout` to pass,
+    /// locking it again before returning
+    pub async fn wait_timeout<'a, T>(
+        &self,
+        guard: LocalMutexGuard<'a, T>,
+        timeout: Duration,
+    ) -> (LocalMutexGuard<'a, T>, WaitTimeoutResult) {
+        let mutex = guard.mutex();
+
+        let waiter = self.waiter().await;
+        drop(guard);
+        let timed_out = waiter.timeout(timeout).await.is_err();
+
+        (mutex.lock().await, WaitTimeoutResult(timed_out))
+    }
+
+    /// Wakes the longest waiting task
+    pub fn notify_one(&self) {
+        if let Some(task) = self.waiters.borrow_mut().pop() {
+            task.wake();
+        }
+    }
+
+    /// Wakes every waiting task
+    pub fn notify_all(&self) {
+        let mut waiters = self.waiters.borrow_mut();
+
+        while let Some(task) = waiters.pop() {
+            task.wake();
+        }
+    }
+
+    /// Queues the current task, returning a [`Waiter`] which yields once it is notified
+    async fn waiter(&self) -> Waiter {
+        let waker = poll_fn(|cx| Poll::Ready(cx.waker().clone())).await;
+
+        Waiter::new(self, waker)
+    }
+}
+
+impl Default for LocalCondvar {
+    fn default() -> Self {
+        LocalCondvar::new()
+    }
+}
+
+impl !Send for LocalCondvar {}
+impl !Sync for LocalCondvar {}
+
+impl WaitTimeoutResult {
+    /// Did the wait return because the timeout passed?
+    pub fn timed_out(&self) -> bool {
+        self.0
+    }
+}
+
+impl<'a> Waiter<'a> {
+    /// Creates a new [`Waiter`], queueing `waker` on `condvar`
+    fn new(condvar: &'a LocalCondvar, waker: Waker) -> Self {
+        let key = condvar.waiters.borrow_mut().push(waker);
+
+        Waiter {
+            condvar,
+            key,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Future for Waiter<'a> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        let mut waiters = this.condvar.waiters.borrow_mut();
+        if waiters.contains(this.key) {
+            waiters.set_waker(this.key, cx.waker());
+            return Poll::Pending;
+        }
+
+        this.finished = true;
+        Poll::Ready(())
+    }
+}
+
+impl<'a> Drop for Waiter<'a> {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+
+        // A notification for a task which stopped waiting is passed on to the next task
+        let mut waiters = self.condvar.waiters.borrow_mut();
+        if !waiters.remove(self.key) {
+            if let Some(task) = waiters.pop() {
+                task.wake();
+            }
+        }
+    }
+}
+
+impl<'a> !Send for Waiter<'a> {}
+impl<'a> !Sync for Waiter<'a> {}
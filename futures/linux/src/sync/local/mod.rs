@@ -1,3 +1,19 @@
+mod acquire_error;
+mod condvar;
+mod mutex;
 mod notify;
+mod permits;
+mod rw_lock;
+mod semaphore;
 
+pub use acquire_error::{AcquireError, TryAcquireError};
+pub use condvar::{LocalCondvar, WaitTimeoutResult};
+pub use mutex::{LocalMutex, LocalMutexGuard, OwnedLocalMutexGuard};
 pub use notify::{LocalNotified, LocalNotify};
+pub use rw_lock::{
+    LocalRwLock, LocalRwLockReadGuard, LocalRwLockWriteGuard, OwnedLocalRwLockReadGuard,
+    OwnedLocalRwLockWriteGuard,
+};
+pub use semaphore::{LocalSemaphore, LocalSemaphorePermit, OwnedLocalSemaphorePermit};
+
+use permits::{Acquire, Permits};
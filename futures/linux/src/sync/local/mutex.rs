@@ -0,0 +1,170 @@
+use super::{Acquire, Permits};
+use std::{
+    cell::{RefCell, UnsafeCell},
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+/// A lock giving tasks exclusive access to a value, which can be held across `.await`s
+///
+/// Tasks acquire the lock in the order they asked for it. This can only be used on one thread.
+pub struct LocalMutex<T> {
+    /// The single permit for the lock
+    permits: RefCell<Permits>,
+
+    /// The protected value
+    value: UnsafeCell<T>,
+}
+
+/// A guard giving exclusive access to the value in a [`LocalMutex`], unlocking it when dropped
+pub struct LocalMutexGuard<'a, T> {
+    /// The locked mutex
+    mutex: &'a LocalMutex<T>,
+}
+
+/// A guard giving exclusive access to the value in a shared [`LocalMutex`], unlocking it when
+/// dropped
+pub struct OwnedLocalMutexGuard<T> {
+    /// The locked mutex
+    mutex: Rc<LocalMutex<T>>,
+}
+
+impl<T> LocalMutex<T> {
+    /// Creates a new unlocked [`LocalMutex`] containing `value`
+    pub const fn new(value: T) -> Self {
+        LocalMutex {
+            permits: RefCell::new(Permits::new(1)),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Waits for the lock, returning a guard which unlocks it when dropped
+    pub async fn lock(&self) -> LocalMutexGuard<T> {
+        Acquire::new(&self.permits, 1)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        LocalMutexGuard { mutex: self }
+    }
+
+    /// Waits for the lock on a shared mutex, returning a guard which keeps the mutex alive and
+    /// unlocks it when dropped
+    pub async fn lock_owned(self: Rc<Self>) -> OwnedLocalMutexGuard<T> {
+        Acquire::new(&self.permits, 1)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        OwnedLocalMutexGuard { mutex: self }
+    }
+
+    /// Attempts to take the lock without waiting
+    ///
+    /// Fails if the lock is held or other tasks are waiting for it.
+    pub fn try_lock(&self) -> Option<LocalMutexGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(1) {
+            return None;
+        }
+
+        Some(LocalMutexGuard { mutex: self })
+    }
+
+    /// Attempts to take the lock on a shared mutex without waiting
+    ///
+    /// Fails if the lock is held or other tasks are waiting for it.
+    pub fn try_lock_owned(self: Rc<Self>) -> Option<OwnedLocalMutexGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(1) {
+            return None;
+        }
+
+        Some(OwnedLocalMutexGuard { mutex: self })
+    }
+
+    /// Is the lock currently held?
+    pub fn is_locked(&self) -> bool {
+        self.permits.borrow().available() == 0
+    }
+
+    /// Gets the value without locking, as holding `&mut self` guarantees no guards exist
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Consumes this, returning the value
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Releases the lock
+    fn unlock(&self) {
+        self.permits.borrow_mut().release(1);
+    }
+}
+
+impl<T: Default> Default for LocalMutex<T> {
+    fn default() -> Self {
+        LocalMutex::new(T::default())
+    }
+}
+
+impl<T> !Send for LocalMutex<T> {}
+impl<T> !Sync for LocalMutex<T> {}
+
+impl<'a, T> LocalMutexGuard<'a, T> {
+    /// Gets the mutex this guard locks
+    pub(super) fn mutex(&self) -> &'a LocalMutex<T> {
+        self.mutex
+    }
+}
+
+impl<'a, T> Deref for LocalMutexGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for LocalMutexGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<'a, T> Drop for LocalMutexGuard<'a, T> {
+    fn drop(&mut self) {
+        self.mutex.unlock();
+    }
+}
+
+impl<'a, T> !Send for LocalMutexGuard<'a, T> {}
+impl<'a, T> !Sync for LocalMutexGuard<'a, T> {}
+
+impl<T> OwnedLocalMutexGuard<T> {
+    /// Gets the mutex this guard locks
+    pub fn mutex(&self) -> &Rc<LocalMutex<T>> {
+        &self.mutex
+    }
+}
+
+impl<T> Deref for OwnedLocalMutexGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T> DerefMut for OwnedLocalMutexGuard<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T> Drop for OwnedLocalMutexGuard<T> {
+    fn drop(&mut self) {
+        self.mutex.unlock();
+    }
+}
+
+impl<T> !Send for OwnedLocalMutexGuard<T> {}
+impl<T> !Sync for OwnedLocalMutexGuard<T> {}
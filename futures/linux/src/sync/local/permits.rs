@@ -0,0 +1,171 @@
+use super::AcquireError;
+use executor::platform::WaitQueue;
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A count of permits which tasks acquire in the order they ask for them
+///
+/// A task can only take permits when nothing is waiting ahead of it, so a task waiting for many
+/// permits isn't starved by tasks taking a few at a time.
+pub(super) struct Permits {
+    /// The number of permits which can be acquired
+    available: usize,
+
+    /// The tasks waiting to acquire permits
+    waiters: WaitQueue,
+
+    /// Have the permits been closed, failing any further acquisitions?
+    closed: bool,
+}
+
+/// A [`Future`] which yields once permits have been acquired from [`Permits`], or fails if they
+/// are closed
+///
+/// Dropping this before it yields gives up its place in the queue.
+pub(super) struct Acquire<'a> {
+    /// The permits to acquire from
+    permits: &'a RefCell<Permits>,
+
+    /// The number of permits to acquire
+    count: usize,
+
+    /// The key this is waiting under, if it is waiting
+    key: Option<u64>,
+}
+
+impl Permits {
+    /// Creates a new [`Permits`] with `available` permits
+    pub(super) const fn new(available: usize) -> Self {
+        Permits {
+            available,
+            waiters: WaitQueue::new(),
+            closed: false,
+        }
+    }
+
+    /// Gets the number of permits which can be acquired
+    pub(super) fn available(&self) -> usize {
+        self.available
+    }
+
+    /// Have the permits been closed?
+    pub(super) fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Attempts to acquire `count` permits without waiting, returning `true` if they were
+    pub(super) fn try_acquire(&mut self, count: usize) -> bool {
+        if self.closed || self.waiters.len() > 0 || self.available < count {
+            return false;
+        }
+
+        self.available -= count;
+        true
+    }
+
+    /// Returns `count` permits, letting the next waiting task attempt to acquire them
+    pub(super) fn release(&mut self, count: usize) {
+        self.available += count;
+        self.waiters.wake_front();
+    }
+
+    /// Fails every waiting and future acquisition
+    pub(super) fn close(&mut self) {
+        self.closed = true;
+
+        while let Some(task) = self.waiters.pop() {
+            task.wake();
+        }
+    }
+
+    /// Attempts to acquire `count` permits for the task waiting under `key`, adding it to the queue
+    /// if it isn't waiting yet
+    fn poll_acquire(
+        &mut self,
+        count: usize,
+        key: &mut Option<u64>,
+        cx: &mut Context,
+    ) -> Poll<Result<(), AcquireError>> {
+        if self.closed {
+            *key = None;
+            return Poll::Ready(Err(AcquireError::new()));
+        }
+
+        match *key {
+            None => {
+                if self.try_acquire(count) {
+                    return Poll::Ready(Ok(()));
+                }
+
+                *key = Some(self.waiters.push(cx.waker().clone()));
+                Poll::Pending
+            }
+            Some(waiting) => {
+                if self.waiters.front() != Some(waiting) || self.available < count {
+                    self.waiters.set_waker(waiting, cx.waker());
+                    return Poll::Pending;
+                }
+
+                self.waiters.remove(waiting);
+                *key = None;
+                self.available -= count;
+
+                // The next task may also be able to acquire from what is left
+                self.waiters.wake_front();
+                Poll::Ready(Ok(()))
+            }
+        }
+    }
+
+    /// Removes the task waiting under `key` from the queue
+    fn cancel(&mut self, key: u64) {
+        let was_front = self.waiters.front() == Some(key);
+        self.waiters.remove(key);
+
+        // The cancelled task may have been woken to acquire, so the next task needs to try instead
+        if was_front {
+            self.waiters.wake_front();
+        }
+    }
+}
+
+impl !Send for Permits {}
+impl !Sync for Permits {}
+
+impl<'a> Acquire<'a> {
+    /// Creates a new [`Acquire`] future for `count` of `permits`
+    pub(super) fn new(permits: &'a RefCell<Permits>, count: usize) -> Self {
+        Acquire {
+            permits,
+            count,
+            key: None,
+        }
+    }
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = Result<(), AcquireError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        this.permits
+            .borrow_mut()
+            .poll_acquire(this.count, &mut this.key, cx)
+    }
+}
+
+impl<'a> Drop for Acquire<'a> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.permits.borrow_mut().cancel(key);
+        }
+    }
+}
+
+impl<'a> !Send for Acquire<'a> {}
+impl<'a> !Sync for Acquire<'a> {}
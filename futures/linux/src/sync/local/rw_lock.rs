@@ -0,0 +1,258 @@
+use super::{Acquire, Permits};
+use std::{
+    cell::{RefCell, UnsafeCell},
+    ops::{Deref, DerefMut},
+    rc::Rc,
+};
+
+/// A lock giving tasks either shared read access or exclusive write access to a value, which can
+/// be held across `.await`s
+///
+/// Tasks acquire the lock in the order they asked for it, so a waiting writer blocks readers which
+/// arrive after it. This can only be used on one thread.
+pub struct LocalRwLock<T> {
+    /// A permit for each reader, all of which are taken by a writer
+    permits: RefCell<Permits>,
+
+    /// The protected value
+    value: UnsafeCell<T>,
+}
+
+/// A guard giving shared access to the value in a [`LocalRwLock`], releasing it when dropped
+pub struct LocalRwLockReadGuard<'a, T> {
+    /// The locked lock
+    lock: &'a LocalRwLock<T>,
+}
+
+/// A guard giving exclusive access to the value in a [`LocalRwLock`], releasing it when dropped
+pub struct LocalRwLockWriteGuard<'a, T> {
+    /// The locked lock
+    lock: &'a LocalRwLock<T>,
+}
+
+/// A guard giving shared access to the value in a shared [`LocalRwLock`], releasing it when
+/// dropped
+pub struct OwnedLocalRwLockReadGuard<T> {
+    /// The locked lock
+    lock: Rc<LocalRwLock<T>>,
+}
+
+/// A guard giving exclusive access to the value in a shared [`LocalRwLock`], releasing it when
+/// dropped
+pub struct OwnedLocalRwLockWriteGuard<T> {
+    /// The locked lock
+    lock: Rc<LocalRwLock<T>>,
+}
+
+/// The maximum number of readers which can hold the lock at once
+const MAX_READERS: usize = usize::MAX >> 3;
+
+impl<T> LocalRwLock<T> {
+    /// Creates a new unlocked [`LocalRwLock`] containing `value`
+    pub const fn new(value: T) -> Self {
+        LocalRwLock {
+            permits: RefCell::new(Permits::new(MAX_READERS)),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Waits for shared read access, returning a guard which releases it when dropped
+    pub async fn read(&self) -> LocalRwLockReadGuard<T> {
+        Acquire::new(&self.permits, 1)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        LocalRwLockReadGuard { lock: self }
+    }
+
+    /// Waits for exclusive write access, returning a guard which releases it when dropped
+    pub async fn write(&self) -> LocalRwLockWriteGuard<T> {
+        Acquire::new(&self.permits, MAX_READERS)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        LocalRwLockWriteGuard { lock: self }
+    }
+
+    /// Waits for shared read access to a shared lock, returning a guard which keeps the lock alive
+    /// and releases it when dropped
+    pub async fn read_owned(self: Rc<Self>) -> OwnedLocalRwLockReadGuard<T> {
+        Acquire::new(&self.permits, 1)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        OwnedLocalRwLockReadGuard { lock: self }
+    }
+
+    /// Waits for exclusive write access to a shared lock, returning a guard which keeps the lock
+    /// alive and releases it when dropped
+    pub async fn write_owned(self: Rc<Self>) -> OwnedLocalRwLockWriteGuard<T> {
+        Acquire::new(&self.permits, MAX_READERS)
+            .await
+            .unwrap_or_else(|_| unreachable!("locks are never closed"));
+
+        OwnedLocalRwLockWriteGuard { lock: self }
+    }
+
+    /// Attempts to take shared read access without waiting
+    ///
+    /// Fails if a writer holds the lock or other tasks are waiting for it.
+    pub fn try_read(&self) -> Option<LocalRwLockReadGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(1) {
+            return None;
+        }
+
+        Some(LocalRwLockReadGuard { lock: self })
+    }
+
+    /// Attempts to take exclusive write access without waiting
+    ///
+    /// Fails if the lock is held or other tasks are waiting for it.
+    pub fn try_write(&self) -> Option<LocalRwLockWriteGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(MAX_READERS) {
+            return None;
+        }
+
+        Some(LocalRwLockWriteGuard { lock: self })
+    }
+
+    /// Attempts to take shared read access to a shared lock without waiting
+    ///
+    /// Fails if a writer holds the lock or other tasks are waiting for it.
+    pub fn try_read_owned(self: Rc<Self>) -> Option<OwnedLocalRwLockReadGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(1) {
+            return None;
+        }
+
+        Some(OwnedLocalRwLockReadGuard { lock: self })
+    }
+
+    /// Attempts to take exclusive write access to a shared lock without waiting
+    ///
+    /// Fails if the lock is held or other tasks are waiting for it.
+    pub fn try_write_owned(self: Rc<Self>) -> Option<OwnedLocalRwLockWriteGuard<T>> {
+        if !self.permits.borrow_mut().try_acquire(MAX_READERS) {
+            return None;
+        }
+
+        Some(OwnedLocalRwLockWriteGuard { lock: self })
+    }
+
+    /// Gets the value without locking, as holding `&mut self` guarantees no guards exist
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// Consumes this, returning the value
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+
+    /// Releases `count` permits
+    fn release(&self, count: usize) {
+        self.permits.borrow_mut().release(count);
+    }
+}
+
+impl<T: Default> Default for LocalRwLock<T> {
+    fn default() -> Self {
+        LocalRwLock::new(T::default())
+    }
+}
+
+impl<T> !Send for LocalRwLock<T> {}
+impl<T> !Sync for LocalRwLock<T> {}
+
+impl<'a, T> Deref for LocalRwLockReadGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<'a, T> Drop for LocalRwLockReadGuard<'a, T> {
+    fn drop(&mut self) {
+        self.lock.release(1);
+    }
+}
+
+impl<'a, T> !Send for LocalRwLockReadGuard<'a, T> {}
+impl<'a, T> !Sync for LocalRwLockReadGuard<'a, T> {}
+
+impl<'a, T> Deref for LocalRwLockWriteGuard<'a, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<'a, T> DerefMut for LocalRwLockWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<'a, T> Drop for LocalRwLockWriteGuard<'a, T> {
+    fn drop(&mut self) {
+        self.lock.release(MAX_READERS);
+    }
+}
+
+impl<'a, T> !Send for LocalRwLockWriteGuard<'a, T> {}
+impl<'a, T> !Sync for LocalRwLockWriteGuard<'a, T> {}
+
+impl<T> OwnedLocalRwLockReadGuard<T> {
+    /// Gets the lock this guard holds
+    pub fn lock(&self) -> &Rc<LocalRwLock<T>> {
+        &self.lock
+    }
+}
+
+impl<T> Deref for OwnedLocalRwLockReadGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for OwnedLocalRwLockReadGuard<T> {
+    fn drop(&mut self) {
+        self.lock.release(1);
+    }
+}
+
+impl<T> !Send for OwnedLocalRwLockReadGuard<T> {}
+impl<T> !Sync for OwnedLocalRwLockReadGuard<T> {}
+
+impl<T> OwnedLocalRwLockWriteGuard<T> {
+    /// Gets the lock this guard holds
+    pub fn lock(&self) -> &Rc<LocalRwLock<T>> {
+        &self.lock
+    }
+}
+
+impl<T> Deref for OwnedLocalRwLockWriteGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> DerefMut for OwnedLocalRwLockWriteGuard<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for OwnedLocalRwLockWriteGuard<T> {
+    fn drop(&mut self) {
+        self.lock.release(MAX_READERS);
+    }
+}
+
+impl<T> !Send for OwnedLocalRwLockWriteGuard<T> {}
+impl<T> !Sync for OwnedLocalRwLockWriteGuard<T> {}
@@ -0,0 +1,177 @@
+use super::{Acquire, AcquireError, Permits, TryAcquireError};
+use std::{cell::RefCell, rc::Rc};
+
+/// A count of permits which tasks acquire and return, bounding how many can do something at once
+///
+/// Tasks acquire permits in the order they asked for them, so a task waiting for many permits
+/// isn't starved by tasks taking a few at a time. This can only be used on one thread.
+pub struct LocalSemaphore {
+    /// The permits
+    permits: RefCell<Permits>,
+}
+
+/// Permits acquired from a [`LocalSemaphore`], which are returned to it when dropped
+pub struct LocalSemaphorePermit<'a> {
+    /// The semaphore the permits came from
+    semaphore: &'a LocalSemaphore,
+
+    /// The number of permits held
+    count: usize,
+}
+
+/// Permits acquired from a shared [`LocalSemaphore`], which are returned to it when dropped
+pub struct OwnedLocalSemaphorePermit {
+    /// The semaphore the permits came from
+    semaphore: Rc<LocalSemaphore>,
+
+    /// The number of permits held
+    count: usize,
+}
+
+impl LocalSemaphore {
+    /// Creates a new [`LocalSemaphore`] with `permits` permits
+    pub const fn new(permits: usize) -> Self {
+        LocalSemaphore {
+            permits: RefCell::new(Permits::new(permits)),
+        }
+    }
+
+    /// Gets the number of permits which can currently be acquired
+    pub fn available_permits(&self) -> usize {
+        self.permits.borrow().available()
+    }
+
+    /// Waits for `count` permits, failing if the semaphore is closed first
+    pub async fn acquire(&self, count: usize) -> Result<LocalSemaphorePermit, AcquireError> {
+        Acquire::new(&self.permits, count).await?;
+
+        Ok(LocalSemaphorePermit {
+            semaphore: self,
+            count,
+        })
+    }
+
+    /// Waits for `count` permits from a shared semaphore, failing if it is closed first
+    pub async fn acquire_owned(
+        self: Rc<Self>,
+        count: usize,
+    ) -> Result<OwnedLocalSemaphorePermit, AcquireError> {
+        Acquire::new(&self.permits, count).await?;
+
+        Ok(OwnedLocalSemaphorePermit {
+            semaphore: self,
+            count,
+        })
+    }
+
+    /// Attempts to acquire `count` permits without waiting
+    ///
+    /// Fails if the semaphore is closed, there aren't enough permits, or other tasks are waiting.
+    pub fn try_acquire(&self, count: usize) -> Result<LocalSemaphorePermit, TryAcquireError> {
+        self.try_acquire_raw(count)?;
+
+        Ok(LocalSemaphorePermit {
+            semaphore: self,
+            count,
+        })
+    }
+
+    /// Attempts to acquire `count` permits from a shared semaphore without waiting
+    ///
+    /// Fails if the semaphore is closed, there aren't enough permits, or other tasks are waiting.
+    pub fn try_acquire_owned(
+        self: Rc<Self>,
+        count: usize,
+    ) -> Result<OwnedLocalSemaphorePermit, TryAcquireError> {
+        self.try_acquire_raw(count)?;
+
+        Ok(OwnedLocalSemaphorePermit {
+            semaphore: self,
+            count,
+        })
+    }
+
+    /// Adds `count` new permits to the semaphore
+    pub fn add_permits(&self, count: usize) {
+        self.permits.borrow_mut().release(count);
+    }
+
+    /// Closes the semaphore, failing every waiting and future acquisition
+    ///
+    /// Permits which have already been acquired are unaffected.
+    pub fn close(&self) {
+        self.permits.borrow_mut().close();
+    }
+
+    /// Has the semaphore been closed?
+    pub fn is_closed(&self) -> bool {
+        self.permits.borrow().is_closed()
+    }
+
+    /// Attempts to take `count` permits without waiting
+    fn try_acquire_raw(&self, count: usize) -> Result<(), TryAcquireError> {
+        let mut permits = self.permits.borrow_mut();
+
+        if permits.is_closed() {
+            Err(TryAcquireError::Closed)
+        } else if permits.try_acquire(count) {
+            Ok(())
+        } else {
+            Err(TryAcquireError::NoPermits)
+        }
+    }
+}
+
+impl !Send for LocalSemaphore {}
+impl !Sync for LocalSemaphore {}
+
+impl<'a> LocalSemaphorePermit<'a> {
+    /// Gets the number of permits held
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Drops the permits without returning them to the semaphore
+    pub fn forget(mut self) {
+        self.count = 0;
+    }
+}
+
+impl<'a> Drop for LocalSemaphorePermit<'a> {
+    fn drop(&mut self) {
+        if self.count > 0 {
+            self.semaphore.add_permits(self.count);
+        }
+    }
+}
+
+impl<'a> !Send for LocalSemaphorePermit<'a> {}
+impl<'a> !Sync for LocalSemaphorePermit<'a> {}
+
+impl OwnedLocalSemaphorePermit {
+    /// Gets the number of permits held
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Gets the semaphore the permits came from
+    pub fn semaphore(&self) -> &Rc<LocalSemaphore> {
+        &self.semaphore
+    }
+
+    /// Drops the permits without returning them to the semaphore
+    pub fn forget(mut self) {
+        self.count = 0;
+    }
+}
+
+impl Drop for OwnedLocalSemaphorePermit {
+    fn drop(&mut self) {
+        if self.count > 0 {
+            self.semaphore.add_permits(self.count);
+        }
+    }
+}
+
+impl !Send for OwnedLocalSemaphorePermit {}
+impl !Sync for OwnedLocalSemaphorePermit {}
@@ -3,5 +3,10 @@
 mod local;
 mod notify;
 
-pub use local::{LocalNotified, LocalNotify};
+pub use local::{
+    AcquireError, LocalCondvar, LocalMutex, LocalMutexGuard, LocalNotified, LocalNotify,
+    LocalRwLock, LocalRwLockReadGuard, LocalRwLockWriteGuard, LocalSemaphore, LocalSemaphorePermit,
+    OwnedLocalMutexGuard, OwnedLocalRwLockReadGuard, OwnedLocalRwLockWriteGuard,
+    OwnedLocalSemaphorePermit, TryAcquireError, WaitTimeoutResult,
+};
 pub use notify::Notify;
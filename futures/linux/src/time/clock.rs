@@ -0,0 +1,74 @@
+use executor::platform::{
+    linux::time::{
+        __kernel_timespec, clock_gettime, clockid_t, timespec, CLOCK_BOOTTIME, CLOCK_MONOTONIC,
+        CLOCK_REALTIME,
+    },
+    uring::{IORING_TIMEOUT_ABS, IORING_TIMEOUT_BOOTTIME, IORING_TIMEOUT_REALTIME},
+};
+use std::{
+    ffi::c_uint,
+    time::{Duration, Instant},
+};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::Sleep;
+
+/// The clock a [`Sleep`] measures its deadline against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Clock {
+    /// A clock which is not affected by changes to the system time and stops while the system is
+    /// suspended
+    #[default]
+    Monotonic,
+
+    /// A clock like [`Clock::Monotonic`] which keeps counting while the system is suspended
+    Boottime,
+
+    /// The system time, which follows changes made to it
+    Realtime,
+}
+
+impl Clock {
+    /// Gets the flags for an absolute timeout against this clock
+    pub(super) fn timeout_flags(&self) -> c_uint {
+        IORING_TIMEOUT_ABS
+            | match self {
+                Clock::Monotonic => 0,
+                Clock::Boottime => IORING_TIMEOUT_BOOTTIME,
+                Clock::Realtime => IORING_TIMEOUT_REALTIME,
+            }
+    }
+
+    /// Converts `deadline` into an absolute time on this clock
+    ///
+    /// Deadlines which have already passed are converted into the current time.
+    pub(super) fn timespec(&self, deadline: Instant) -> __kernel_timespec {
+        let remaining = deadline.saturating_duration_since(super::now());
+        let time = self.now() + remaining;
+
+        __kernel_timespec {
+            sec: time.as_secs() as _,
+            nsec: time.subsec_nanos() as _,
+        }
+    }
+
+    /// Gets the current time on this clock
+    fn now(&self) -> Duration {
+        let mut now = timespec { sec: 0, nsec: 0 };
+
+        // This can only fail for an invalid clock or pointer
+        unsafe { clock_gettime(self.id(), &mut now) };
+
+        Duration::new(now.sec as u64, now.nsec as u32)
+    }
+
+    /// Gets the ID of this clock
+    fn id(&self) -> clockid_t {
+        match self {
+            Clock::Monotonic => CLOCK_MONOTONIC,
+            Clock::Boottime => CLOCK_BOOTTIME,
+            Clock::Realtime => CLOCK_REALTIME,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use executor::Error;
+
+/// The error from an operation with a deadline linked to it in the kernel
+///
+/// The kernel cancels the operation when the deadline passes. The deadline is measured against
+/// [`now`](super::now), but the kernel counts down to it in real time, so a paused clock doesn't
+/// hold it back or advance it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineError {
+    /// The deadline passed before the operation completed, so the kernel cancelled it
+    Elapsed,
+
+    /// The operation failed before the deadline
+    Io(Error),
+}
+
+impl DeadlineError {
+    /// Creates a [`DeadlineError`] from the error of an operation submitted with a linked deadline
+    ///
+    /// The operation is only cancelled by the deadline while it is being polled, so `ECANCELED`
+    /// means the deadline passed.
+    pub(crate) fn from_linked(error: Error) -> Self {
+        if error == Error::ECANCELED {
+            DeadlineError::Elapsed
+        } else {
+            DeadlineError::Io(error)
+        }
+    }
+}
+
+impl std::fmt::Display for DeadlineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeadlineError::Elapsed => f.write_str("deadline has elapsed"),
+            DeadlineError::Io(error) => write!(f, "{:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for DeadlineError {}
+
+impl From<Error> for DeadlineError {
+    fn from(error: Error) -> Self {
+        DeadlineError::Io(error)
+    }
+}
+
+impl From<DeadlineError> for Error {
+    fn from(error: DeadlineError) -> Self {
+        match error {
+            DeadlineError::Elapsed => Error::ETIMEDOUT,
+            DeadlineError::Io(error) => error,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use executor::Error;
+use std::time::Instant;
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::Timeout;
+
+/// The error from a [`Timeout`] whose deadline passed before its future yielded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed(Instant);
+
+impl Elapsed {
+    /// Creates a new [`Elapsed`] for `deadline`
+    pub(super) fn new(deadline: Instant) -> Self {
+        Elapsed(deadline)
+    }
+
+    /// Gets the deadline which passed
+    pub fn deadline(&self) -> Instant {
+        self.0
+    }
+}
+
+impl std::fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for Error {
+    fn from(_: Elapsed) -> Self {
+        Error::ETIMEDOUT
+    }
+}
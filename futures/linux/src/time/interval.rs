@@ -1,142 +1,127 @@
-use crate::EventRef;
-use executor::{
-    platform::{
-        linux::time::__kernel_timespec,
-        uring::{
-            io_uring_cqe, io_uring_prep_timeout, io_uring_prep_timeout_remove,
-            IORING_TIMEOUT_MULTISHOT,
-        },
-        EventHandler,
-    },
-    EventManager, Result,
-};
+use super::{MissedTickBehavior, Sleep};
+use executor::Result;
 use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 /// A future which yields after a fixed period
 pub struct Interval {
-    /// The timespec for the SQE
-    timespec: __kernel_timespec,
+    /// The sleep until the next tick
+    sleep: Sleep,
 
-    /// The event id this is registered under
-    event_id: EventRef,
+    /// The time between ticks
+    period: Duration,
 
-    /// Has the SQE been submitted yet?
-    sqe_submitted: bool,
+    /// How ticks are scheduled after falling behind
+    missed_tick_behavior: MissedTickBehavior,
 }
 
-/// A future which yields after one tick from an [`Interval`]
+/// A future which yields the instant one tick from an [`Interval`] was scheduled for
 pub struct Tick<'a> {
-    timespec: __kernel_timespec,
+    /// The interval being ticked
     interval: &'a mut Interval,
 }
 
-/// Creates an [`Interval`] future which yields immediately then yields every `period`
+/// How late a tick must be before it is considered missed
+const MISSED_TICK_THRESHOLD: Duration = Duration::from_millis(5);
+
+/// Creates an [`Interval`] future which first yields after `period` and then yields every
+/// `period`
 pub fn interval(period: Duration) -> Result<Interval> {
     Interval::new(period)
 }
 
-/// The callback called when the interval timer fires
-fn interval_callback(_: &mut io_uring_cqe, value: &mut usize) {
-    *value += 1;
+/// Creates an [`Interval`] future which first yields at `start` and then yields every `period`
+///
+/// Passing [`now`](super::now) as `start` makes the first tick yield immediately.
+pub fn interval_at(start: Instant, period: Duration) -> Result<Interval> {
+    Interval::new_at(start, period)
 }
 
 impl Interval {
-    /// Creates a new [`Interval`] that first yields after `delay` and then yields every `period`
+    /// Creates a new [`Interval`] that first yields after `period` and then yields every `period`
     pub fn new(period: Duration) -> Result<Self> {
-        let event_id = EventRef::register(EventHandler::integer(interval_callback))?;
+        Interval::new_at(super::deadline_after(super::now(), period), period)
+    }
 
-        let timespec = __kernel_timespec {
-            sec: period.as_secs() as _,
-            nsec: period.subsec_nanos() as _,
-        };
+    /// Creates a new [`Interval`] that first yields at `start` and then yields every `period`
+    ///
+    /// # Panic
+    /// This function will panic if `period` is zero
+    pub fn new_at(start: Instant, period: Duration) -> Result<Self> {
+        assert!(
+            !period.is_zero(),
+            "Attempted to create an interval with a zero period"
+        );
 
         Ok(Interval {
-            timespec,
-            event_id,
-            sqe_submitted: false,
+            sleep: Sleep::until(start)?,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
         })
     }
 
-    /// Returns a future which will yield after the next timer tick
-    pub fn tick(&mut self) -> Tick {
-        Tick {
-            timespec: self.timespec,
-            interval: self,
-        }
+    /// Gets the time between ticks
+    pub fn period(&self) -> Duration {
+        self.period
     }
-}
 
-impl Drop for Interval {
-    fn drop(&mut self) {
-        if self.sqe_submitted {
-            EventManager::get_local_mut(|manager| {
-                let sqe = manager.get_sqe(*self.event_id).unwrap();
+    /// Gets how ticks are scheduled after falling behind
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
 
-                unsafe {
-                    io_uring_prep_timeout_remove(sqe.as_ptr(), (*self.event_id).into_u64(), 0)
-                };
+    /// Sets how ticks are scheduled after falling behind
+    pub fn set_missed_tick_behavior(&mut self, missed_tick_behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = missed_tick_behavior;
+    }
 
-                sqe.submit().unwrap();
-            })
-        }
+    /// Restarts the schedule so the next tick yields one period from now
+    pub fn reset(&mut self) -> Result<()> {
+        self.reset_at(super::deadline_after(super::now(), self.period))
     }
-}
 
-impl !Send for Interval {}
-impl !Sync for Interval {}
+    /// Restarts the schedule so the next tick yields at `deadline`
+    pub fn reset_at(&mut self, deadline: Instant) -> Result<()> {
+        Pin::new(&mut self.sleep).reset(deadline)
+    }
 
-impl<'a> Tick<'a> {
-    /// Projects this into `(self.timespec, self.interval)`
-    ///
-    /// # SAFTEY
-    /// This is the only way to access the contained [`__kernel_timespec`], do not acces it
-    /// directly.
-    unsafe fn project(self: Pin<&mut Self>) -> (Pin<&mut __kernel_timespec>, &mut Interval) {
-        let this = self.get_unchecked_mut();
-        (Pin::new(&mut this.timespec), this.interval)
+    /// Returns a future which will yield after the next timer tick
+    pub fn tick(&mut self) -> Tick {
+        Tick { interval: self }
     }
 }
 
+impl !Send for Interval {}
+impl !Sync for Interval {}
+
 impl<'a> Future for Tick<'a> {
-    type Output = ();
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (timespec, interval) = unsafe { self.project() };
-
-        EventManager::get_local_mut(|manager| {
-            // Submit the SQE if one hasn't been submitted yet
-            if !interval.sqe_submitted {
-                let sqe = manager.get_sqe(*interval.event_id).unwrap();
-
-                unsafe {
-                    io_uring_prep_timeout(
-                        sqe.as_ptr(),
-                        timespec.get_mut(),
-                        0,
-                        IORING_TIMEOUT_MULTISHOT,
-                    )
-                };
-
-                sqe.submit().unwrap();
-                interval.sqe_submitted = true;
-            }
-
-            // Check if the event is ready
-            let event = manager.get_event_mut(*interval.event_id).unwrap();
-            let value = event.data().as_integer();
-            if value > 0 {
-                event.data_mut().set_integer(value - 1);
-                return Poll::Ready(());
-            }
-
-            event.set_waker(Some(cx.waker().clone()));
-            Poll::Pending
-        })
+    type Output = Instant;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let interval = &mut *self.interval;
+
+        if Pin::new(&mut interval.sleep).poll(cx).is_pending() {
+            return Poll::Pending;
+        }
+
+        let scheduled = interval.sleep.deadline();
+        let now = super::now();
+        let next = if now > scheduled + MISSED_TICK_THRESHOLD {
+            interval
+                .missed_tick_behavior
+                .next_deadline(scheduled, now, interval.period)
+        } else {
+            super::deadline_after(scheduled, interval.period)
+        };
+
+        // The sleep has fired, so resetting it reuses its event and can't fail
+        Pin::new(&mut interval.sleep).reset(next).unwrap();
+
+        Poll::Ready(scheduled)
     }
 }
 
@@ -0,0 +1,160 @@
+use super::Clock;
+use crate::EventRef;
+use executor::{
+    platform::{
+        linux::time::__kernel_timespec,
+        uring::{io_uring_cqe, io_uring_prep_timeout, io_uring_prep_timeout_remove},
+        EventHandler,
+    },
+    EventManager, Result,
+};
+use std::{
+    task::{Context, Poll},
+    time::Instant,
+};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::Sleep;
+
+/// An absolute kernel timeout, used by a [`Sleep`] on a clock other than [`Clock::Monotonic`]
+pub(super) struct KernelTimeout {
+    /// The timespec for the SQE
+    timespec: __kernel_timespec,
+
+    /// The event id this is registered under
+    event_id: EventRef,
+
+    /// Has the SQE been submitted yet?
+    sqe_submitted: bool,
+
+    /// Has the timeout completed?
+    completed: bool,
+}
+
+/// The callback called when the timeout fires
+fn timeout_callback(_: &mut io_uring_cqe, value: &mut usize) {
+    *value += 1;
+}
+
+impl KernelTimeout {
+    /// Creates a new [`KernelTimeout`]
+    pub(super) fn new() -> Result<Self> {
+        let event_id = EventRef::register(EventHandler::integer(timeout_callback))?;
+
+        Ok(KernelTimeout {
+            timespec: __kernel_timespec { sec: 0, nsec: 0 },
+            event_id,
+            sqe_submitted: false,
+            completed: false,
+        })
+    }
+
+    /// Has the submitted timeout fired?
+    pub(super) fn fired(&self) -> bool {
+        if self.completed {
+            return true;
+        }
+
+        self.sqe_submitted
+            && EventManager::get_local_mut(|manager| {
+                manager
+                    .get_event_mut(*self.event_id)
+                    .unwrap()
+                    .data()
+                    .as_integer()
+                    > 0
+            })
+    }
+
+    /// Prepares this to be submitted again for a new deadline
+    pub(super) fn reset(&mut self) -> Result<()> {
+        if self.sqe_submitted && !self.fired() {
+            // The current timeout may fire before the removal is processed, so it must not be able
+            // to complete the new one
+            let event_id = EventRef::register(EventHandler::integer(timeout_callback))?;
+            self.cancel();
+            self.event_id = event_id;
+        } else {
+            EventManager::get_local_mut(|manager| {
+                manager
+                    .get_event_mut(*self.event_id)
+                    .unwrap()
+                    .data_mut()
+                    .set_integer(0)
+            });
+        }
+
+        self.sqe_submitted = false;
+        self.completed = false;
+
+        Ok(())
+    }
+
+    /// Submits the timeout for `deadline` on `clock` if it hasn't been yet, then checks if it has
+    /// fired
+    pub(super) fn poll(
+        &mut self,
+        deadline: Instant,
+        clock: Clock,
+        cx: &mut Context<'_>,
+    ) -> Poll<()> {
+        if self.completed {
+            return Poll::Ready(());
+        }
+
+        EventManager::get_local_mut(|manager| {
+            // Submit the SQE if one hasn't been submitted yet
+            if !self.sqe_submitted {
+                let sqe = manager.get_sqe(*self.event_id).unwrap();
+
+                self.timespec = clock.timespec(deadline);
+                unsafe {
+                    io_uring_prep_timeout(
+                        sqe.as_ptr(),
+                        &mut self.timespec,
+                        0,
+                        clock.timeout_flags(),
+                    )
+                };
+
+                sqe.submit().unwrap();
+                self.sqe_submitted = true;
+            }
+
+            // Check if the event is ready
+            let event = manager.get_event_mut(*self.event_id).unwrap();
+            if event.data().as_integer() > 0 {
+                self.completed = true;
+                return Poll::Ready(());
+            }
+
+            event.set_waker(Some(cx.waker().clone()));
+            Poll::Pending
+        })
+    }
+
+    /// Removes the submitted timeout if it hasn't fired yet
+    fn cancel(&mut self) {
+        if self.sqe_submitted && !self.completed {
+            EventManager::get_local_mut(|manager| {
+                let sqe = manager.get_sqe(*self.event_id).unwrap();
+
+                unsafe {
+                    io_uring_prep_timeout_remove(sqe.as_ptr(), (*self.event_id).into_u64(), 0)
+                };
+
+                sqe.submit().unwrap();
+            })
+        }
+    }
+}
+
+impl Drop for KernelTimeout {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+impl !Send for KernelTimeout {}
+impl !Sync for KernelTimeout {}
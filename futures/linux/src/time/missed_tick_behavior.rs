@@ -0,0 +1,39 @@
+use std::time::{Duration, Instant};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::Interval;
+
+/// How an [`Interval`] schedules its ticks after falling behind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissedTickBehavior {
+    /// Yield the missed ticks as quickly as possible until caught up, keeping the original
+    /// schedule
+    #[default]
+    Burst,
+
+    /// Yield the next tick one period after the late tick, shifting the schedule
+    Delay,
+
+    /// Drop the missed ticks and yield the next tick on the original schedule
+    Skip,
+}
+
+impl MissedTickBehavior {
+    /// Gets the deadline of the tick after one `scheduled` for but yielded at `now`
+    pub(super) fn next_deadline(
+        &self,
+        scheduled: Instant,
+        now: Instant,
+        period: Duration,
+    ) -> Instant {
+        match self {
+            MissedTickBehavior::Burst => super::deadline_after(scheduled, period),
+            MissedTickBehavior::Delay => super::deadline_after(now, period),
+            MissedTickBehavior::Skip => {
+                let behind = (now - scheduled).as_nanos() % period.as_nanos();
+                super::deadline_after(now, period - Duration::from_nanos(behind as u64))
+            }
+        }
+    }
+}
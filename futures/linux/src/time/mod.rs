@@ -1,11 +1,26 @@
 //! Futures for time keeping
 
+mod clock;
+mod deadline_error;
+mod elapsed;
 mod interval;
+mod kernel_timeout;
+mod missed_tick_behavior;
+mod paused;
 mod sleep;
 mod timeout;
+mod timeout_ext;
 mod timer;
 
-pub use interval::{interval, Interval};
-pub use sleep::{sleep, Sleep};
-pub use timeout::{timeout, Timeout};
-pub use timer::{Timer, TimerInterval, TimerSleep, TimerTimeout};
+pub use clock::Clock;
+pub use deadline_error::DeadlineError;
+pub use elapsed::Elapsed;
+pub use interval::{interval, interval_at, Interval};
+pub use missed_tick_behavior::MissedTickBehavior;
+pub use paused::{advance, is_paused, now, pause, resume};
+pub use sleep::{sleep, sleep_until, Sleep};
+pub use timeout::{timeout, timeout_at, Timeout};
+pub use timeout_ext::TimeoutExt;
+pub use timer::{Timer, TimerInterval, TimerSleep, TimerTick, TimerTimeout};
+
+pub(crate) use paused::deadline_after;
@@ -0,0 +1,46 @@
+use executor::{platform::TimerWheel, EventManager};
+use std::time::{Duration, Instant};
+
+// rustdoc imports
+#[allow(unused_imports)]
+use super::{Clock, Interval, Sleep, Timeout};
+
+/// Gets the current instant on the executor's clock
+///
+/// [`Sleep`], [`Interval`], and [`Timeout`] measure their deadlines against this clock. It is the
+/// same as [`Instant::now`] unless it has been paused or advanced.
+pub fn now() -> Instant {
+    EventManager::get_local(|manager| manager.now())
+}
+
+/// Stops the executor's clock, so time only passes when [`advance`] is called
+///
+/// While the clock is paused and every task is waiting, it automatically advances to the next
+/// timer, so timer heavy code runs without waiting in real time. Only sleeps using
+/// [`Clock::Monotonic`] follow the paused clock.
+pub fn pause() {
+    EventManager::get_local_mut(|manager| manager.pause())
+}
+
+/// Restarts the executor's clock from the instant it was paused at
+pub fn resume() {
+    EventManager::get_local_mut(|manager| manager.resume())
+}
+
+/// Is the executor's clock paused?
+pub fn is_paused() -> bool {
+    EventManager::get_local(|manager| manager.is_paused())
+}
+
+/// Moves the executor's clock forward by `duration`, firing any timers it passes
+pub fn advance(duration: Duration) {
+    EventManager::get_local_mut(|manager| manager.advance(duration))
+}
+
+/// Gets the instant `duration` after `now`
+///
+/// Durations longer than [`TimerWheel::MAX_DURATION`], up to [`Duration::MAX`], are clamped to it
+/// so the deadline can't overflow.
+pub(crate) fn deadline_after(now: Instant, duration: Duration) -> Instant {
+    now + duration.min(TimerWheel::MAX_DURATION)
+}
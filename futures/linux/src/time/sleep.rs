@@ -1,32 +1,32 @@
-use crate::EventRef;
-use executor::{
-    platform::{
-        linux::time::__kernel_timespec,
-        uring::{io_uring_cqe, io_uring_prep_timeout, io_uring_prep_timeout_remove},
-        EventHandler,
-    },
-    EventID, EventManager, Result,
-};
+use super::{kernel_timeout::KernelTimeout, Clock};
+use crate::TimerRef;
+use executor::{EventManager, Result};
 use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-/// A future which yields after a certain duration
+/// A future which yields once a deadline has passed
 pub struct Sleep {
-    /// The timespec for the SQE
-    timespec: __kernel_timespec,
+    /// The instant this sleep yields at
+    deadline: Instant,
+
+    /// The clock `deadline` is measured against
+    clock: Clock,
 
-    /// The event id this is registered under
-    event_id: EventRef,
+    /// What is waiting for the deadline
+    inner: SleepInner,
+}
 
-    /// Has the SQE been submitted yet?
-    sqe_submitted: bool,
+/// What a [`Sleep`] waits on
+enum SleepInner {
+    /// A timer in the executor's timer wheel, used for [`Clock::Monotonic`]
+    Wheel(TimerRef),
 
-    /// Has the sleep completed?
-    completed: bool,
+    /// A kernel timeout, used for the other clocks
+    Kernel(KernelTimeout),
 }
 
 /// Sleep until `duration` has passed
@@ -34,90 +34,104 @@ pub fn sleep(duration: Duration) -> Result<Sleep> {
     Sleep::new(duration)
 }
 
-/// The callback called when the sleep timer fires
-fn sleep_callback(_: &mut io_uring_cqe, value: &mut usize) {
-    *value += 1;
+/// Sleep until `deadline`
+pub fn sleep_until(deadline: Instant) -> Result<Sleep> {
+    Sleep::until(deadline)
 }
 
 impl Sleep {
     /// Creates a new [`Sleep`] which yields after `duration` has passed
+    ///
+    /// Durations of over two years, such as [`Duration::MAX`], are shortened to a little over two
+    /// years.
     pub fn new(duration: Duration) -> Result<Self> {
-        let event_id = EventRef::register(EventHandler::integer(sleep_callback))?;
+        Sleep::until(super::deadline_after(super::now(), duration))
+    }
+
+    /// Creates a new [`Sleep`] which yields at `deadline`
+    pub fn until(deadline: Instant) -> Result<Self> {
+        Sleep::with_clock(deadline, Clock::Monotonic)
+    }
 
-        let timespec = __kernel_timespec {
-            sec: duration.as_secs() as _,
-            nsec: duration.subsec_nanos() as _,
+    /// Creates a new [`Sleep`] which yields at `deadline` as measured by `clock`
+    ///
+    /// With [`Clock::Monotonic`] the sleep is a timer in the executor's timer wheel, which doesn't
+    /// use an event or a submission and has millisecond resolution. The other clocks submit their
+    /// own kernel timeout, converting the deadline to a time on `clock` when first polled, so with
+    /// [`Clock::Boottime`] time spent suspended counts towards it and with [`Clock::Realtime`] it
+    /// follows changes made to the system time.
+    pub fn with_clock(deadline: Instant, clock: Clock) -> Result<Self> {
+        let inner = match clock {
+            Clock::Monotonic => SleepInner::Wheel(TimerRef::insert(deadline)),
+            _ => SleepInner::Kernel(KernelTimeout::new()?),
         };
 
         Ok(Sleep {
-            timespec,
-            event_id,
-            sqe_submitted: false,
-            completed: false,
+            deadline,
+            clock,
+            inner,
         })
     }
 
-    /// Projects this object into
-    /// `(self.timespec, self.sqe_submitted, self.completed, self.event_id)`
-    ///
-    /// # SAFTEY
-    /// This is the only way to access the contained [`__kernel_timespec`], do not acces it
-    /// directly.
-    unsafe fn project(
-        self: Pin<&mut Self>,
-    ) -> (Pin<&mut __kernel_timespec>, &mut bool, &mut bool, EventID) {
-        let this = self.get_unchecked_mut();
-        (
-            Pin::new(&mut this.timespec),
-            &mut this.sqe_submitted,
-            &mut this.completed,
-            *this.event_id,
-        )
+    /// Gets the instant this sleep yields at
+    pub fn deadline(&self) -> Instant {
+        self.deadline
     }
-}
 
-impl Future for Sleep {
-    type Output = ();
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let (timespec, sqe_submitted, completed, event_id) = unsafe { self.project() };
-
-        EventManager::get_local_mut(|manager| {
-            // Submit the SQE if one hasn't been submitted yet
-            if !*sqe_submitted {
-                let sqe = manager.get_sqe(event_id).unwrap();
-
-                unsafe { io_uring_prep_timeout(sqe.as_ptr(), timespec.get_mut(), 0, 0) };
+    /// Gets the clock the deadline is measured against
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
 
-                sqe.submit().unwrap();
-                *sqe_submitted = true;
+    /// Has the deadline passed?
+    pub fn is_elapsed(&self) -> bool {
+        match &self.inner {
+            SleepInner::Wheel(timer) => {
+                EventManager::get_local(|manager| manager.timers().is_fired(**timer))
             }
+            SleepInner::Kernel(timeout) => timeout.fired(),
+        }
+    }
 
-            // Check if the event is ready
-            let event = manager.get_event_mut(event_id).unwrap();
-            if event.data().as_integer() > 0 {
-                *completed = true;
-                return Poll::Ready(());
+    /// Changes the instant this sleep yields at to `deadline`, even if it has already yielded
+    ///
+    /// Re-arming against a fixed schedule with this avoids the drift from repeatedly sleeping for
+    /// a duration. This can only fail if the sleep uses a kernel timeout which is still waiting and
+    /// a new event can't be registered to replace its current one.
+    pub fn reset(self: Pin<&mut Self>, deadline: Instant) -> Result<()> {
+        let this = self.get_mut();
+
+        match &mut this.inner {
+            SleepInner::Wheel(timer) => {
+                EventManager::get_local_mut(|manager| manager.timers_mut().reset(**timer, deadline))
             }
+            SleepInner::Kernel(timeout) => timeout.reset()?,
+        }
 
-            event.set_waker(Some(cx.waker().clone()));
-            Poll::Pending
-        })
+        this.deadline = deadline;
+        Ok(())
     }
 }
 
-impl Drop for Sleep {
-    fn drop(&mut self) {
-        if self.sqe_submitted && !self.completed {
-            EventManager::get_local_mut(|manager| {
-                let sqe = manager.get_sqe(*self.event_id).unwrap();
-
-                unsafe {
-                    io_uring_prep_timeout_remove(sqe.as_ptr(), (*self.event_id).into_u64(), 0)
-                };
+impl Future for Sleep {
+    type Output = ();
 
-                sqe.submit().unwrap();
-            })
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        match &mut this.inner {
+            SleepInner::Wheel(timer) => {
+                let fired = EventManager::get_local_mut(|manager| {
+                    manager.timers_mut().poll(**timer, cx.waker())
+                });
+
+                if fired {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            }
+            SleepInner::Kernel(timeout) => timeout.poll(this.deadline, this.clock, cx),
         }
     }
 }
@@ -1,52 +1,105 @@
-use super::Sleep;
-use executor::Result;
-use futures_common::{Select, SelectResult};
+use super::Elapsed;
+use crate::TimerRef;
+use executor::EventManager;
 use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-/// A [`Future`] which yields when either the contained [`Future`] yields or a timeout passes
-pub struct Timeout<F: Future>(Select<F, Sleep>);
+/// A [`Future`] which yields when either the contained [`Future`] yields or a deadline passes
+///
+/// The deadline is a timer in the executor's timer wheel, which is only inserted when this is
+/// first polled, so creating a [`Timeout`] can't fail.
+pub struct Timeout<F: Future> {
+    /// The future being limited
+    future: F,
+
+    /// The instant the future is abandoned at
+    deadline: Instant,
+
+    /// The timer for `deadline`, once this has been polled
+    timer: Option<TimerRef>,
+}
 
 /// Creates a [`Timeout`] future which yields when either `future` yields or `timeout` passes
-pub fn timeout<F: Future>(future: F, timeout: Duration) -> Result<Timeout<F>> {
+pub fn timeout<F: Future>(future: F, timeout: Duration) -> Timeout<F> {
     Timeout::new(future, timeout)
 }
 
+/// Creates a [`Timeout`] future which yields when either `future` yields or `deadline` passes
+pub fn timeout_at<F: Future>(future: F, deadline: Instant) -> Timeout<F> {
+    Timeout::until(future, deadline)
+}
+
 impl<F: Future> Timeout<F> {
     /// Creates a [`Timeout`] future which yields when either `future` yields or `timeout` passes
-    pub fn new(future: F, timeout: Duration) -> Result<Self> {
-        let sleep = Sleep::new(timeout)?;
-        let inner = Select::new(future, sleep);
+    ///
+    /// Timeouts of over two years, such as [`Duration::MAX`], are shortened to a little over two
+    /// years.
+    pub fn new(future: F, timeout: Duration) -> Self {
+        Timeout::until(future, super::deadline_after(super::now(), timeout))
+    }
 
-        Ok(Timeout(inner))
+    /// Creates a [`Timeout`] future which yields when either `future` yields or `deadline` passes
+    pub fn until(future: F, deadline: Instant) -> Self {
+        Timeout {
+            future,
+            deadline,
+            timer: None,
+        }
     }
 
-    /// Gets the contained [`Select`]
+    /// Gets the instant the contained [`Future`] is abandoned at
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Gets a reference to the contained [`Future`]
+    pub fn get_ref(&self) -> &F {
+        &self.future
+    }
+
+    /// Consumes this, returning the contained [`Future`]
+    pub fn into_inner(self) -> F {
+        self.future
+    }
+
+    /// Projects pinned self into `(self.future, self.deadline, self.timer)`
     ///
     /// # SAFTEY
-    /// This is the only way to access the contained [`Select`], do not acces it directly.
-    unsafe fn project(self: Pin<&mut Self>) -> Pin<&mut Select<F, Sleep>> {
-        self.map_unchecked_mut(|timeout| &mut timeout.0)
+    /// This is the only way to access the contained `future`, do not access it directly.
+    unsafe fn project(self: Pin<&mut Self>) -> (Pin<&mut F>, Instant, &mut Option<TimerRef>) {
+        let this = self.get_unchecked_mut();
+
+        (
+            Pin::new_unchecked(&mut this.future),
+            this.deadline,
+            &mut this.timer,
+        )
     }
 }
 
 impl<F: Future> Future for Timeout<F> {
-    type Output = Option<F::Output>;
+    type Output = Result<F::Output, Elapsed>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if let Poll::Ready(result) = unsafe { self.project() }.poll(cx) {
-            if let SelectResult::A(value) = result {
-                return Poll::Ready(Some(value));
-            }
+        let (future, deadline, timer) = unsafe { self.project() };
 
-            return Poll::Ready(None);
+        if let Poll::Ready(value) = future.poll(cx) {
+            return Poll::Ready(Ok(value));
         }
 
-        Poll::Pending
+        let timer = timer.get_or_insert_with(|| TimerRef::insert(deadline));
+        let fired =
+            EventManager::get_local_mut(|manager| manager.timers_mut().poll(**timer, cx.waker()));
+
+        if fired {
+            Poll::Ready(Err(Elapsed::new(deadline)))
+        } else {
+            Poll::Pending
+        }
     }
 }
 
@@ -0,0 +1,22 @@
+use super::Timeout;
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+/// An extension trait for limiting how long a [`Future`] can take
+pub trait TimeoutExt: Future + Sized {
+    /// Limits this to yielding within `timeout`, failing with [`Elapsed`](super::Elapsed) if it
+    /// doesn't
+    fn timeout(self, timeout: Duration) -> Timeout<Self> {
+        Timeout::new(self, timeout)
+    }
+
+    /// Limits this to yielding before `deadline`, failing with [`Elapsed`](super::Elapsed) if it
+    /// doesn't
+    fn deadline(self, deadline: Instant) -> Timeout<Self> {
+        Timeout::until(self, deadline)
+    }
+}
+
+impl<F: Future> TimeoutExt for F {}
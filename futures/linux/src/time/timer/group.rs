@@ -0,0 +1,305 @@
+use crate::{time, TimerRef};
+use executor::{Error, EventManager, Result};
+use std::{
+    task::{Poll, Waker},
+    time::{Duration, Instant},
+};
+
+/// The timers belonging to a [`Timer`](super::Timer) and the clock they are measured against
+pub(super) struct TimerGroup {
+    /// The instant on the executor's clock the group's clock was created at
+    real_origin: Instant,
+
+    /// The instant on the clock when it was created, advanced by [`TimerGroup::advance`]
+    virtual_origin: Instant,
+
+    /// The total time the clock has spent paused
+    paused_total: Duration,
+
+    /// The instant on the executor's clock the group's clock was paused at, if it is paused
+    paused_at: Option<Instant>,
+
+    /// The timers in the group
+    members: Vec<Option<Member>>,
+
+    /// The indices of free slots in `members`
+    free: Vec<usize>,
+}
+
+/// A timer in a [`TimerGroup`]
+struct Member {
+    /// The instant on the group's clock this fires at
+    deadline: Instant,
+
+    /// The state of the timer
+    state: MemberState,
+
+    /// The [`Waker`] for the task waiting on the timer, to wake when the group is resumed
+    waker: Option<Waker>,
+}
+
+/// The state of a [`Member`]
+enum MemberState {
+    /// The timer hasn't fired, it has no timer in the wheel while the group is paused
+    Waiting(Option<TimerRef>),
+
+    /// The timer's deadline has passed
+    Fired,
+
+    /// The timer was cancelled and won't fire until it is reset
+    Cancelled,
+}
+
+impl TimerGroup {
+    /// Creates a new empty [`TimerGroup`] whose clock currently reads `start`
+    pub(super) fn new(start: Instant) -> Self {
+        TimerGroup {
+            real_origin: time::now(),
+            virtual_origin: start,
+            paused_total: Duration::ZERO,
+            paused_at: None,
+            members: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Gets the current instant on the group's clock
+    pub(super) fn now(&self) -> Instant {
+        let real = self.paused_at.unwrap_or_else(time::now);
+        self.virtual_origin + (real - self.real_origin).saturating_sub(self.paused_total)
+    }
+
+    /// Gets the number of timers which haven't fired or been cancelled
+    pub(super) fn pending(&self) -> usize {
+        (0..self.members.len())
+            .filter(|&index| match &self.members[index] {
+                Some(member) => {
+                    matches!(member.state, MemberState::Waiting(_)) && !self.is_fired(index)
+                }
+                None => false,
+            })
+            .count()
+    }
+
+    /// Is the group's clock paused?
+    pub(super) fn is_paused(&self) -> bool {
+        self.paused_at.is_some()
+    }
+
+    /// Inserts a new timer which fires at `deadline` on the group's clock, returning its index
+    pub(super) fn insert(&mut self, deadline: Instant) -> usize {
+        let member = Member {
+            deadline,
+            state: MemberState::Waiting(self.wheel_timer(deadline)),
+            waker: None,
+        };
+
+        match self.free.pop() {
+            Some(index) => {
+                self.members[index] = Some(member);
+                index
+            }
+            None => {
+                self.members.push(Some(member));
+                self.members.len() - 1
+            }
+        }
+    }
+
+    /// Moves the timer at `index` to fire at `deadline`, even if it has fired or been cancelled
+    pub(super) fn reset(&mut self, index: usize, deadline: Instant) {
+        let real_deadline = self.real_deadline(deadline);
+        let paused = self.is_paused();
+
+        let member = self.member_mut(index);
+        member.deadline = deadline;
+
+        match &member.state {
+            MemberState::Waiting(Some(timer)) => EventManager::get_local_mut(|manager| {
+                manager.timers_mut().reset(**timer, real_deadline)
+            }),
+            MemberState::Waiting(None) => {}
+            _ => {
+                member.state =
+                    MemberState::Waiting((!paused).then(|| TimerRef::insert(real_deadline)))
+            }
+        }
+    }
+
+    /// Removes the timer at `index`
+    pub(super) fn remove(&mut self, index: usize) {
+        self.members[index] = None;
+        self.free.push(index);
+    }
+
+    /// Gets the instant on the group's clock the timer at `index` fires at
+    pub(super) fn deadline(&self, index: usize) -> Instant {
+        self.member(index).deadline
+    }
+
+    /// Has the timer at `index` fired?
+    pub(super) fn is_fired(&self, index: usize) -> bool {
+        match &self.member(index).state {
+            MemberState::Waiting(Some(timer)) => {
+                EventManager::get_local(|manager| manager.timers().is_fired(**timer))
+            }
+            MemberState::Waiting(None) | MemberState::Cancelled => false,
+            MemberState::Fired => true,
+        }
+    }
+
+    /// Was the timer at `index` cancelled?
+    pub(super) fn is_cancelled(&self, index: usize) -> bool {
+        matches!(self.member(index).state, MemberState::Cancelled)
+    }
+
+    /// Checks if the timer at `index` has fired, setting `waker` to be woken when it does if not
+    ///
+    /// Yields `ECANCELED` if the timer was cancelled.
+    pub(super) fn poll(&mut self, index: usize, waker: &Waker) -> Poll<Result<()>> {
+        let member = self.member_mut(index);
+
+        let fired = match &member.state {
+            MemberState::Waiting(Some(timer)) => {
+                EventManager::get_local_mut(|manager| manager.timers_mut().poll(**timer, waker))
+            }
+            MemberState::Waiting(None) => false,
+            MemberState::Fired => return Poll::Ready(Ok(())),
+            MemberState::Cancelled => return Poll::Ready(Err(Error::ECANCELED)),
+        };
+
+        if fired {
+            member.state = MemberState::Fired;
+            member.waker = None;
+            return Poll::Ready(Ok(()));
+        }
+
+        match &member.waker {
+            Some(current) if current.will_wake(waker) => {}
+            _ => member.waker = Some(waker.clone()),
+        }
+
+        Poll::Pending
+    }
+
+    /// Cancels every timer which hasn't fired yet, waking the tasks waiting on them
+    pub(super) fn cancel_all(&mut self) {
+        for index in 0..self.members.len() {
+            let waiting = match &self.members[index] {
+                Some(member) => matches!(member.state, MemberState::Waiting(_)),
+                None => false,
+            };
+
+            // Timers which have fired in the wheel but haven't been polled aren't cancelled
+            if !waiting || self.is_fired(index) {
+                continue;
+            }
+
+            let member = self.member_mut(index);
+            member.state = MemberState::Cancelled;
+
+            if let Some(waker) = member.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Stops the group's clock, removing the timers from the wheel until it is resumed
+    pub(super) fn pause(&mut self) {
+        if self.is_paused() {
+            return;
+        }
+
+        self.paused_at = Some(time::now());
+
+        for member in self.members.iter_mut().flatten() {
+            if let MemberState::Waiting(timer) = &mut member.state {
+                *timer = None;
+            }
+        }
+    }
+
+    /// Restarts the group's clock from where it was paused, waking the tasks waiting on timers
+    pub(super) fn resume(&mut self) {
+        let paused_at = match self.paused_at.take() {
+            Some(paused_at) => paused_at,
+            None => return,
+        };
+
+        self.paused_total += time::now() - paused_at;
+
+        for index in 0..self.members.len() {
+            let deadline = match &self.members[index] {
+                Some(member) => member.deadline,
+                None => continue,
+            };
+            let real_deadline = self.real_deadline(deadline);
+
+            let member = self.members[index].as_mut().unwrap();
+            if let MemberState::Waiting(timer) = &mut member.state {
+                *timer = Some(TimerRef::insert(real_deadline));
+
+                if let Some(waker) = member.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+    }
+
+    /// Moves the group's clock forward by `duration`, firing any timers it passes even while the
+    /// clock is paused
+    pub(super) fn advance(&mut self, duration: Duration) {
+        self.virtual_origin += duration;
+
+        let now = self.now();
+        for index in 0..self.members.len() {
+            let deadline = match &self.members[index] {
+                Some(member) => member.deadline,
+                None => continue,
+            };
+            let real_deadline = self.real_deadline(deadline);
+
+            let member = self.members[index].as_mut().unwrap();
+            let timer = match &member.state {
+                MemberState::Waiting(timer) => timer,
+                _ => continue,
+            };
+
+            if deadline <= now {
+                member.state = MemberState::Fired;
+
+                if let Some(waker) = member.waker.take() {
+                    waker.wake();
+                }
+            } else if let Some(timer) = timer {
+                EventManager::get_local_mut(|manager| {
+                    manager.timers_mut().reset(**timer, real_deadline)
+                });
+            }
+        }
+    }
+
+    /// Gets the timer wheel timer for `deadline`, if the clock is running
+    fn wheel_timer(&self, deadline: Instant) -> Option<TimerRef> {
+        (!self.is_paused()).then(|| TimerRef::insert(self.real_deadline(deadline)))
+    }
+
+    /// Converts `deadline` on the group's clock into an instant on the executor's clock
+    ///
+    /// Deadlines before the clock was created are converted into the instant it was created.
+    fn real_deadline(&self, deadline: Instant) -> Instant {
+        self.real_origin
+            + self.paused_total
+            + deadline.saturating_duration_since(self.virtual_origin)
+    }
+
+    /// Gets the timer at `index`
+    fn member(&self, index: usize) -> &Member {
+        self.members[index].as_ref().unwrap()
+    }
+
+    /// Mutably gets the timer at `index`
+    fn member_mut(&mut self, index: usize) -> &mut Member {
+        self.members[index].as_mut().unwrap()
+    }
+}
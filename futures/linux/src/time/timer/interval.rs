@@ -1,56 +1,112 @@
-use crate::time::{interval::Tick, Interval};
+use super::TimerSleep;
+use crate::time::MissedTickBehavior;
 use executor::Result;
 use std::{
     future::Future,
-    marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
-/// A future which yields after a fixed period
-pub struct TimerInterval<'a> {
-    /// The actual interval future
-    inner: Interval,
+/// A future which yields after a fixed period on a [`Timer`](super::Timer)'s clock
+pub struct TimerInterval {
+    /// The sleep until the next tick
+    sleep: TimerSleep,
 
-    /// A marker for the lifetime
-    _lifetime: PhantomData<&'a mut ()>,
-}
+    /// The time between ticks
+    period: Duration,
 
-/// A future which yields after one tick from [`TimerInterval`]
-pub struct TimerTick<'a, 'b: 'a> {
-    /// The actual tick future
-    inner: Tick<'a>,
+    /// How ticks are scheduled after falling behind
+    missed_tick_behavior: MissedTickBehavior,
+}
 
-    /// A marker for the lifetime
-    _lifetime: PhantomData<&'b mut ()>,
+/// A future which yields the instant one tick from a [`TimerInterval`] was scheduled for
+///
+/// If the interval is cancelled by [`Timer::cancel_all`](super::Timer::cancel_all), this yields
+/// `ECANCELED` instead until the interval is reset.
+pub struct TimerTick<'a> {
+    /// The interval being ticked
+    interval: &'a mut TimerInterval,
 }
 
-impl<'a> TimerInterval<'a> {
-    /// Creates a new [`TimerInterval`] future
-    pub(super) fn new(period: Duration) -> Result<Self> {
-        Ok(TimerInterval {
-            inner: Interval::new(period)?,
-            _lifetime: PhantomData,
-        })
+/// How late a tick must be before it is considered missed
+const MISSED_TICK_THRESHOLD: Duration = Duration::from_millis(5);
+
+impl TimerInterval {
+    /// Creates a new [`TimerInterval`] future which first yields after `period`
+    ///
+    /// # Panic
+    /// This function will panic if `period` is zero
+    pub(super) fn new(sleep: TimerSleep, period: Duration) -> Self {
+        assert!(
+            !period.is_zero(),
+            "Attempted to create an interval with a zero period"
+        );
+
+        TimerInterval {
+            sleep,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
+        }
+    }
+
+    /// Gets the time between ticks
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Gets how ticks are scheduled after falling behind
+    pub fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Sets how ticks are scheduled after falling behind
+    pub fn set_missed_tick_behavior(&mut self, missed_tick_behavior: MissedTickBehavior) {
+        self.missed_tick_behavior = missed_tick_behavior;
+    }
+
+    /// Restarts the schedule so the next tick yields one period from now on the timer's clock
+    pub fn reset(&mut self) {
+        let deadline = crate::time::deadline_after(self.sleep.now(), self.period);
+        self.sleep.reset(deadline);
     }
 
     /// Returns a future which will yield after the next timer tick
-    pub fn tick<'b>(&'b mut self) -> TimerTick<'b, 'a> {
-        TimerTick {
-            inner: self.inner.tick(),
-            _lifetime: PhantomData,
-        }
+    pub fn tick(&mut self) -> TimerTick {
+        TimerTick { interval: self }
     }
 }
 
-impl<'a, 'b: 'a> Future for TimerTick<'a, 'b> {
-    type Output = ();
+impl !Send for TimerInterval {}
+impl !Sync for TimerInterval {}
+
+impl<'a> Future for TimerTick<'a> {
+    type Output = Result<Instant>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        Pin::new(&mut self.inner).poll(cx)
+        let interval = &mut *self.interval;
+
+        match Pin::new(&mut interval.sleep).poll(cx) {
+            Poll::Ready(Ok(())) => {}
+            Poll::Ready(Err(error)) => return Poll::Ready(Err(error)),
+            Poll::Pending => return Poll::Pending,
+        }
+
+        let scheduled = interval.sleep.deadline();
+        let now = interval.sleep.now();
+        let next = if now > scheduled + MISSED_TICK_THRESHOLD {
+            interval
+                .missed_tick_behavior
+                .next_deadline(scheduled, now, interval.period)
+        } else {
+            crate::time::deadline_after(scheduled, interval.period)
+        };
+
+        interval.sleep.reset(next);
+
+        Poll::Ready(Ok(scheduled))
     }
 }
 
-impl<'a> !Send for TimerInterval<'a> {}
-impl<'a> !Sync for TimerInterval<'a> {}
+impl<'a> !Send for TimerTick<'a> {}
+impl<'a> !Sync for TimerTick<'a> {}
@@ -1,40 +1,116 @@
-use executor::Result;
-use std::{future::Future, time::Duration};
+use group::TimerGroup;
+use std::{
+    cell::RefCell,
+    future::Future,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
+mod group;
 mod interval;
 mod sleep;
 mod timeout;
 
-pub use interval::TimerInterval;
+pub use interval::{TimerInterval, TimerTick};
 pub use sleep::TimerSleep;
 pub use timeout::TimerTimeout;
 
-/// A timer which can be used to make repeated time-based calls
+/// A group of timers sharing a clock, which can be cancelled, paused, and resumed together
+///
+/// The timers are driven by the executor's timer wheel, so they don't use events or submissions.
+/// A connection can create its timers through a [`Timer`] and cancel every one of them with
+/// [`Timer::cancel_all`] when it closes.
+///
+/// The clock starts out reading the same as [`now`](super::now), but can be offset with
+/// [`Timer::new_at`], stopped with [`Timer::pause`], and moved forward with [`Timer::advance`].
+/// Deadlines passed to a [`Timer`] are measured against its clock.
 pub struct Timer {
-    /// Prevents this struct from being constructed elsewhere
-    _priv: (),
+    /// The timers in this group and their clock
+    group: Rc<RefCell<TimerGroup>>,
 }
 
 impl Timer {
-    /// Creates a new [`Timer`]
-    pub fn new() -> Result<Self> {
-        Ok(Timer { _priv: () })
+    /// Creates a new [`Timer`] whose clock follows [`now`](super::now)
+    pub fn new() -> Self {
+        Timer::new_at(super::now())
+    }
+
+    /// Creates a new [`Timer`] whose clock currently reads `start` and runs from there
+    pub fn new_at(start: Instant) -> Self {
+        Timer {
+            group: Rc::new(RefCell::new(TimerGroup::new(start))),
+        }
+    }
+
+    /// Gets the current instant on this timer's clock
+    pub fn now(&self) -> Instant {
+        self.group.borrow().now()
     }
 
     /// Creates a [`TimerSleep`] future which yields after `duration`
-    pub fn sleep(&mut self, duration: Duration) -> Result<TimerSleep> {
-        TimerSleep::new(duration)
+    pub fn sleep(&self, duration: Duration) -> TimerSleep {
+        self.sleep_until(crate::time::deadline_after(self.now(), duration))
+    }
+
+    /// Creates a [`TimerSleep`] future which yields at `deadline` on this timer's clock
+    pub fn sleep_until(&self, deadline: Instant) -> TimerSleep {
+        TimerSleep::new(&self.group, deadline)
     }
 
-    /// Creates an [`TimerInterval`] future which yields immediately then yields every `period`
-    pub fn interval(&mut self, period: Duration) -> Result<TimerInterval> {
-        TimerInterval::new(period)
+    /// Creates a [`TimerInterval`] future which first yields after `period` and then yields every
+    /// `period`
+    ///
+    /// # Panic
+    /// This function will panic if `period` is zero
+    pub fn interval(&self, period: Duration) -> TimerInterval {
+        TimerInterval::new(self.sleep(period), period)
     }
 
     /// Creates a [`TimerTimeout`] future which yields when either `future` yields or `timeout`
     /// passes
-    pub fn timeout<F: Future>(&mut self, future: F, timeout: Duration) -> Result<TimerTimeout<F>> {
-        TimerTimeout::new(self, future, timeout)
+    pub fn timeout<F: Future>(&self, future: F, timeout: Duration) -> TimerTimeout<F> {
+        TimerTimeout::new(future, self.sleep(timeout))
+    }
+
+    /// Gets the number of timers created by this which haven't fired or been cancelled
+    pub fn pending(&self) -> usize {
+        self.group.borrow().pending()
+    }
+
+    /// Cancels every timer created by this which hasn't fired yet
+    ///
+    /// Tasks waiting on the timers are woken. Cancelled sleeps and interval ticks yield
+    /// `ECANCELED`, while cancelled timeouts wait for their future. A cancelled timer can be
+    /// restarted by resetting it.
+    pub fn cancel_all(&self) {
+        self.group.borrow_mut().cancel_all()
+    }
+
+    /// Stops this timer's clock, so none of its timers fire until it is resumed
+    pub fn pause(&self) {
+        self.group.borrow_mut().pause()
+    }
+
+    /// Restarts this timer's clock from the instant it was paused at
+    pub fn resume(&self) {
+        self.group.borrow_mut().resume()
+    }
+
+    /// Is this timer's clock paused?
+    pub fn is_paused(&self) -> bool {
+        self.group.borrow().is_paused()
+    }
+
+    /// Moves this timer's clock forward by `duration`, firing any timers it passes even while
+    /// paused
+    pub fn advance(&self, duration: Duration) {
+        self.group.borrow_mut().advance(duration)
+    }
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Timer::new()
     }
 }
 
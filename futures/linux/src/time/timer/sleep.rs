@@ -1,39 +1,79 @@
-use crate::time::Sleep;
+use super::group::TimerGroup;
 use executor::Result;
 use std::{
+    cell::RefCell,
     future::Future,
-    marker::PhantomData,
     pin::Pin,
+    rc::Rc,
     task::{Context, Poll},
-    time::Duration,
+    time::Instant,
 };
 
-/// A [`Future`] which yields after a period of time
-pub struct TimerSleep<'a> {
-    /// The actual sleep future
-    inner: Sleep,
+/// A [`Future`] which yields once a deadline on a [`Timer`](super::Timer)'s clock has passed
+///
+/// If the sleep is cancelled by [`Timer::cancel_all`](super::Timer::cancel_all), it yields
+/// `ECANCELED` instead.
+pub struct TimerSleep {
+    /// The group this belongs to
+    group: Rc<RefCell<TimerGroup>>,
 
-    /// A marker for the lifetime
-    _lifetime: PhantomData<&'a mut ()>,
+    /// The index of this in the group
+    index: usize,
 }
 
-impl<'a> TimerSleep<'a> {
-    /// Creates a new [`TimerSleep`] future
-    pub(super) fn new(duration: Duration) -> Result<Self> {
-        Ok(TimerSleep {
-            inner: Sleep::new(duration)?,
-            _lifetime: PhantomData,
-        })
+impl TimerSleep {
+    /// Creates a new [`TimerSleep`] future in `group`
+    pub(super) fn new(group: &Rc<RefCell<TimerGroup>>, deadline: Instant) -> Self {
+        let index = group.borrow_mut().insert(deadline);
+
+        TimerSleep {
+            group: group.clone(),
+            index,
+        }
+    }
+
+    /// Gets the instant on the timer's clock this sleep yields at
+    pub fn deadline(&self) -> Instant {
+        self.group.borrow().deadline(self.index)
+    }
+
+    /// Has the deadline passed?
+    pub fn is_elapsed(&self) -> bool {
+        self.group.borrow().is_fired(self.index)
+    }
+
+    /// Was this sleep cancelled by [`Timer::cancel_all`](super::Timer::cancel_all)?
+    ///
+    /// A cancelled sleep yields `ECANCELED` until it is reset.
+    pub fn is_cancelled(&self) -> bool {
+        self.group.borrow().is_cancelled(self.index)
+    }
+
+    /// Changes the instant on the timer's clock this sleep yields at to `deadline`, even if it has
+    /// already yielded or been cancelled
+    pub fn reset(&mut self, deadline: Instant) {
+        self.group.borrow_mut().reset(self.index, deadline)
+    }
+
+    /// Gets the current instant on the timer's clock
+    pub(super) fn now(&self) -> Instant {
+        self.group.borrow().now()
     }
 }
 
-impl<'a> Future for TimerSleep<'a> {
-    type Output = ();
+impl Future for TimerSleep {
+    type Output = Result<()>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.group.borrow_mut().poll(self.index, cx.waker())
+    }
+}
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        Pin::new(&mut self.inner).poll(cx)
+impl Drop for TimerSleep {
+    fn drop(&mut self) {
+        self.group.borrow_mut().remove(self.index);
     }
 }
 
-impl<'a> !Send for TimerSleep<'a> {}
-impl<'a> !Sync for TimerSleep<'a> {}
+impl !Send for TimerSleep {}
+impl !Sync for TimerSleep {}
@@ -1,49 +1,57 @@
-use super::{Timer, TimerSleep};
-use executor::Result;
-use futures_common::{Select, SelectResult};
+use super::TimerSleep;
+use crate::time::Elapsed;
 use std::{
     future::Future,
     pin::Pin,
     task::{Context, Poll},
-    time::Duration,
 };
 
-/// A [`Future`] which yields when either the contained [`Future`] yields or a timeout passes
-pub struct TimerTimeout<'a, F: Future>(Select<F, TimerSleep<'a>>);
+/// A [`Future`] which yields when either the contained [`Future`] yields or a timeout on a
+/// [`Timer`](super::Timer)'s clock passes
+///
+/// If the timeout is cancelled, this only yields once the contained [`Future`] does.
+pub struct TimerTimeout<F: Future> {
+    /// The future being limited
+    future: F,
 
-impl<'a, F: Future> TimerTimeout<'a, F> {
-    /// Creates a new [`TimerTimeout`]
-    pub(super) fn new(timer: &'a mut Timer, future: F, timeout: Duration) -> Result<Self> {
-        let sleep = timer.sleep(timeout)?;
-        let inner = Select::new(future, sleep);
+    /// The sleep until the timeout passes
+    sleep: TimerSleep,
+}
 
-        Ok(TimerTimeout(inner))
+impl<F: Future> TimerTimeout<F> {
+    /// Creates a new [`TimerTimeout`]
+    pub(super) fn new(future: F, sleep: TimerSleep) -> Self {
+        TimerTimeout { future, sleep }
     }
 
-    /// Gets the contained [`Select`]
+    /// Projects pinned self into `(self.future, self.sleep)`
     ///
     /// # SAFTEY
-    /// This is the only way to access the contained [`Select`], do not acces it directly.
-    unsafe fn project(self: Pin<&mut Self>) -> Pin<&mut Select<F, TimerSleep<'a>>> {
-        self.map_unchecked_mut(|timeout| &mut timeout.0)
+    /// This is the only way to access the contained `future`, do not access it directly.
+    unsafe fn project(self: Pin<&mut Self>) -> (Pin<&mut F>, &mut TimerSleep) {
+        let this = self.get_unchecked_mut();
+
+        (Pin::new_unchecked(&mut this.future), &mut this.sleep)
     }
 }
 
-impl<'a, F: Future> Future for TimerTimeout<'a, F> {
-    type Output = Option<F::Output>;
+impl<F: Future> Future for TimerTimeout<F> {
+    type Output = Result<F::Output, Elapsed>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if let Poll::Ready(result) = unsafe { self.project() }.poll(cx) {
-            if let SelectResult::A(value) = result {
-                return Poll::Ready(Some(value));
-            }
+        let (future, sleep) = unsafe { self.project() };
 
-            return Poll::Ready(None);
+        if let Poll::Ready(value) = future.poll(cx) {
+            return Poll::Ready(Ok(value));
         }
 
-        Poll::Pending
+        // A cancelled timeout leaves the future's waker to wake this
+        match Pin::new(&mut *sleep).poll(cx) {
+            Poll::Ready(Ok(())) => Poll::Ready(Err(Elapsed::new(sleep.deadline()))),
+            Poll::Ready(Err(_)) | Poll::Pending => Poll::Pending,
+        }
     }
 }
 
-impl<'a, F: Future> !Send for TimerTimeout<'a, F> {}
-impl<'a, F: Future> !Sync for TimerTimeout<'a, F> {}
+impl<F: Future> !Send for TimerTimeout<F> {}
+impl<F: Future> !Sync for TimerTimeout<F> {}
@@ -0,0 +1,29 @@
+use executor::{platform::TimerKey, EventManager};
+use std::{ops::Deref, time::Instant};
+
+/// A container for a [`TimerKey`] which removes the timer from the timer wheel on drop
+pub(crate) struct TimerRef(TimerKey);
+
+impl TimerRef {
+    /// Inserts a new timer firing at `deadline` into the local timer wheel and returns a
+    /// [`TimerRef`] to it
+    pub(crate) fn insert(deadline: Instant) -> Self {
+        TimerRef(EventManager::get_local_mut(|manager| {
+            manager.timers_mut().insert(deadline)
+        }))
+    }
+}
+
+impl Deref for TimerRef {
+    type Target = TimerKey;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for TimerRef {
+    fn drop(&mut self) {
+        EventManager::get_local_mut(|manager| manager.timers_mut().remove(self.0));
+    }
+}
@@ -1,4 +1,6 @@
-use std::future::Future;
+use executor::Result;
+use futures::net::{Incoming, RecvMultishot, TCPStream};
+use std::{future::Future, net::SocketAddr};
 
 /// An asynchronous iterator over a series of elements
 pub trait Iterator {
@@ -27,3 +29,19 @@ impl<I: std::iter::Iterator> Iterator for SyncIter<I> {
         self.0.next()
     }
 }
+
+impl<'a> Iterator for RecvMultishot<'a> {
+    type Item = Result<(Vec<u8>, SocketAddr)>;
+
+    fn next(&mut self) -> impl Future<Output = Option<Self::Item>> {
+        RecvMultishot::next(self)
+    }
+}
+
+impl<'a> Iterator for Incoming<'a> {
+    type Item = Result<(TCPStream, SocketAddr)>;
+
+    fn next(&mut self) -> impl Future<Output = Option<Self::Item>> {
+        Incoming::next(self)
+    }
+}
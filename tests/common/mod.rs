@@ -0,0 +1,12 @@
+use std::{future::Future, pin::Pin, task::Poll};
+
+/// Polls `future` once, returning its output if it was ready
+pub async fn poll_once<F: Future + Unpin>(mut future: F) -> Option<F::Output> {
+    std::future::poll_fn(|cx| {
+        Poll::Ready(match Pin::new(&mut future).poll(cx) {
+            Poll::Ready(output) => Some(output),
+            Poll::Pending => None,
+        })
+    })
+    .await
+}
@@ -0,0 +1,123 @@
+use lasync::sync::{LocalCondvar, LocalMutex};
+use std::{num::NonZeroUsize, rc::Rc, time::Duration};
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn condvar_notify_one() {
+    let queue = lasync::FutureQueue::new();
+    let state = Rc::new((LocalMutex::new(0), LocalCondvar::new()));
+
+    for _ in 0..3 {
+        let state = state.clone();
+        queue.push(async move {
+            let (mutex, condvar) = &*state;
+
+            let mut guard = condvar
+                .wait_while(mutex.lock().await, |ready| *ready == 0)
+                .await;
+            *guard -= 1;
+        });
+    }
+
+    let producer = state.clone();
+    queue.push(async move {
+        let (mutex, condvar) = &*producer;
+
+        // Each notification reaches a separate waiter, none are lost
+        for _ in 0..3 {
+            *mutex.lock().await += 1;
+            condvar.notify_one();
+        }
+    });
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    assert_eq!(*state.0.try_lock().unwrap(), 0);
+}
+
+#[test]
+fn condvar_notify_all() {
+    let queue = lasync::FutureQueue::new();
+    let state = Rc::new((LocalMutex::new(false), LocalCondvar::new()));
+    let woken = Rc::new(std::cell::Cell::new(0));
+
+    for _ in 0..3 {
+        let state = state.clone();
+        let woken = woken.clone();
+        queue.push(async move {
+            let (mutex, condvar) = &*state;
+
+            let guard = condvar
+                .wait_while(mutex.lock().await, |ready| !*ready)
+                .await;
+            assert!(*guard);
+            woken.set(woken.get() + 1);
+        });
+    }
+
+    let producer = state.clone();
+    queue.push(async move {
+        let (mutex, condvar) = &*producer;
+
+        *mutex.lock().await = true;
+        condvar.notify_all();
+    });
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    assert_eq!(woken.get(), 3);
+}
+
+#[test]
+fn condvar_wait_timeout() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+
+        let mutex = LocalMutex::new(());
+        let condvar = LocalCondvar::new();
+
+        let start = lasync::time::now();
+        let (guard, result) = condvar
+            .wait_timeout(mutex.lock().await, Duration::from_secs(5))
+            .await;
+        assert!(result.timed_out());
+        assert!(lasync::time::now() - start >= Duration::from_secs(5));
+
+        // The lock is held again after the wait, and the timed out waiter has left the queue
+        assert!(mutex.is_locked());
+        drop(guard);
+        condvar.notify_one();
+        assert!(!mutex.is_locked());
+    })
+    .unwrap();
+}
+
+#[test]
+fn condvar_wait_timeout_max_duration() {
+    let queue = lasync::FutureQueue::new();
+    let state = Rc::new((LocalMutex::new(false), LocalCondvar::new()));
+
+    let waiter = state.clone();
+    queue.push(async move {
+        let (mutex, condvar) = &*waiter;
+
+        // A timeout which would overflow an `Instant` is clamped, so the wait still ends on notify
+        let mut guard = mutex.lock().await;
+        while !*guard {
+            let (next, result) = condvar.wait_timeout(guard, Duration::MAX).await;
+            assert!(!result.timed_out());
+            guard = next;
+        }
+    });
+
+    let producer = state.clone();
+    queue.push(async move {
+        let (mutex, condvar) = &*producer;
+
+        *mutex.lock().await = true;
+        condvar.notify_one();
+    });
+
+    lasync::run_queue(SIZE, queue).unwrap();
+}
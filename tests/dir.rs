@@ -0,0 +1,111 @@
+use lasync::{fs::Dir, io::Read};
+use std::num::NonZeroUsize;
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+const DIR_PATH: &str = "./tests";
+const FILE_NAME: &str = "test_data.txt";
+
+const TEST_CONTENT: &[u8] = include_bytes!("./test_data.txt");
+
+#[test]
+fn dir_open_at() {
+    lasync::run(SIZE, async {
+        let dir = Dir::open(DIR_PATH).await.unwrap();
+        let mut file = dir.open_at(FILE_NAME).await.unwrap();
+
+        let mut buffer = [0; TEST_CONTENT.len()];
+        file.read_exact(&mut buffer).await.unwrap();
+
+        assert_eq!(buffer, TEST_CONTENT);
+
+        let metadata = dir.metadata_at(FILE_NAME).await.unwrap();
+        assert!(metadata.is_file());
+        assert_eq!(metadata.len(), TEST_CONTENT.len() as u64);
+    })
+    .unwrap();
+}
+
+#[test]
+fn dir_read_dir() {
+    lasync::run(SIZE, async {
+        let dir = Dir::open(DIR_PATH).await.unwrap();
+
+        let entry = dir
+            .read_dir()
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .find(|entry| entry.file_name() == FILE_NAME)
+            .unwrap();
+
+        assert!(entry.file_type().is_file());
+    })
+    .unwrap();
+}
+
+#[test]
+fn dir_create_rename_remove() {
+    let path = std::env::temp_dir().join(format!("lasync-dir-{}", std::process::id()));
+    std::fs::create_dir_all(&path).unwrap();
+
+    lasync::run(SIZE, async {
+        let dir = Dir::open(&path).await.unwrap();
+
+        dir.create_dir_at("child").await.unwrap();
+        assert!(dir.metadata_at("child").await.unwrap().is_dir());
+
+        dir.rename_at("child", "renamed").await.unwrap();
+        assert!(dir.metadata_at("child").await.is_err());
+
+        let child = dir.open_dir_at("renamed").await.unwrap();
+        assert_eq!(child.read_dir().unwrap().count(), 0);
+
+        dir.remove_dir_at("renamed").await.unwrap();
+        assert!(dir.metadata_at("renamed").await.is_err());
+    })
+    .unwrap();
+
+    std::fs::remove_dir(&path).unwrap();
+}
+
+#[test]
+fn dir_read_dir_file_types() {
+    let path = std::env::temp_dir().join(format!("lasync-dir-types-{}", std::process::id()));
+    std::fs::create_dir_all(&path).unwrap();
+    std::fs::write(path.join("file"), TEST_CONTENT).unwrap();
+    std::os::unix::fs::symlink("file", path.join("symlink")).unwrap();
+    let listener = std::os::unix::net::UnixListener::bind(path.join("socket")).unwrap();
+
+    lasync::run(SIZE, async {
+        let dir = Dir::open(&path).await.unwrap();
+
+        for entry in dir.read_dir().unwrap() {
+            let entry = entry.unwrap();
+            let file_type = entry.file_type();
+
+            match entry.file_name().to_str().unwrap() {
+                "file" => assert!(file_type.is_file()),
+                "symlink" => assert!(file_type.is_symlink()),
+                "socket" => {
+                    assert!(file_type.is_socket());
+                    assert!(!file_type.is_file());
+                }
+                name => panic!("unexpected entry {}", name),
+            }
+        }
+
+        let dev = Dir::open("/dev").await.unwrap();
+        let null = dev
+            .read_dir()
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .find(|entry| entry.file_name() == "null")
+            .unwrap();
+
+        assert!(null.file_type().is_char_device());
+        assert!(!dev.metadata_at("null").await.unwrap().is_file());
+    })
+    .unwrap();
+
+    drop(listener);
+    std::fs::remove_dir_all(&path).unwrap();
+}
@@ -0,0 +1,212 @@
+use lasync::{
+    net::{Resolver, TCPListener, TCPStream, ToSocketAddrs},
+    Error,
+};
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, TcpListener, UdpSocket},
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+const SOCKET_ADDRESS: SocketAddr =
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0));
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const ADDRESS: Ipv4Addr = Ipv4Addr::new(10, 0, 0, 1);
+
+/// The record type of an IPv4 address
+const TYPE_A: u16 = 1;
+
+/// Builds the stub server's response to `query`
+///
+/// "example.test" has a single IPv4 address and no IPv6 addresses, every other name doesn't exist.
+fn respond(query: &[u8], truncated: bool) -> Vec<u8> {
+    let question = &query[12..];
+    let name_length = question.len() - 4;
+    let kind = u16::from_be_bytes([question[name_length], question[name_length + 1]]);
+    let exists = &question[..name_length] == b"\x07example\x04test\x00";
+    let answered = exists && kind == TYPE_A && !truncated;
+
+    let mut flags: u16 = 0x8180;
+    if truncated {
+        flags |= 0x0200;
+    }
+    if !exists {
+        flags |= 3;
+    }
+
+    let mut response = Vec::new();
+    response.extend_from_slice(&query[..2]);
+    response.extend_from_slice(&flags.to_be_bytes());
+    response.extend_from_slice(&[0, 1, 0, answered as u8, 0, 0, 0, 0]);
+    response.extend_from_slice(question);
+    if answered {
+        response.extend_from_slice(&[0xC0, 0x0C, 0, 1, 0, 1, 0, 0, 0, 60, 0, 4]);
+        response.extend_from_slice(&ADDRESS.octets());
+    }
+
+    response
+}
+
+/// Starts a stub DNS server which answers `count` queries over UDP
+///
+/// If `tcp` is set, UDP responses are truncated and the queries are answered over TCP instead.
+fn start_stub_server(count: usize, tcp: bool) -> (SocketAddr, std::thread::JoinHandle<()>) {
+    let socket = UdpSocket::bind(SOCKET_ADDRESS).unwrap();
+    let address = socket.local_addr().unwrap();
+    let listener = tcp.then(|| TcpListener::bind(address).unwrap());
+
+    let child = std::thread::spawn(move || {
+        use std::io::{Read, Write};
+
+        for _ in 0..count {
+            let mut buffer = [0; 512];
+            let (length, from) = socket.recv_from(&mut buffer).unwrap();
+            let query = &buffer[..length];
+            socket.send_to(&respond(query, tcp), from).unwrap();
+
+            if let Some(listener) = &listener {
+                let (mut stream, _) = listener.accept().unwrap();
+
+                let mut length = [0; 2];
+                stream.read_exact(&mut length).unwrap();
+                let mut query = vec![0; u16::from_be_bytes(length) as usize];
+                stream.read_exact(&mut query).unwrap();
+
+                let response = respond(&query, false);
+                stream
+                    .write_all(&(response.len() as u16).to_be_bytes())
+                    .unwrap();
+                stream.write_all(&response).unwrap();
+            }
+        }
+    });
+
+    (address, child)
+}
+
+#[test]
+fn dns_lookup_udp() {
+    let (address, child) = start_stub_server(4, false);
+
+    lasync::run(SIZE, async move {
+        let mut resolver = Resolver::new(vec![address]);
+        resolver.set_timeout(Duration::from_secs(5));
+
+        let addresses = resolver.lookup("Example.Test.", 80).await.unwrap();
+        assert_eq!(addresses, [SocketAddr::new(ADDRESS.into(), 80)]);
+
+        assert!(resolver.lookup_ip("missing.test").await.unwrap_err() == Error::ENOENT);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn dns_lookup_aaaa_failure() {
+    let socket = UdpSocket::bind(SOCKET_ADDRESS).unwrap();
+    let address = socket.local_addr().unwrap();
+
+    // Answer the `AAAA` query with a server failure, and the `A` query normally
+    let child = std::thread::spawn(move || {
+        for _ in 0..2 {
+            let mut buffer = [0; 512];
+            let (length, from) = socket.recv_from(&mut buffer).unwrap();
+            let query = &buffer[..length];
+
+            let mut response = respond(query, false);
+            let kind = u16::from_be_bytes([query[length - 4], query[length - 3]]);
+            if kind != TYPE_A {
+                response[3] = (response[3] & 0xF0) | 2;
+            }
+
+            socket.send_to(&response, from).unwrap();
+        }
+    });
+
+    lasync::run(SIZE, async move {
+        let mut resolver = Resolver::new(vec![address]);
+        resolver.set_attempts(1);
+
+        let addresses = resolver.lookup_ip("example.test").await.unwrap();
+        assert_eq!(addresses, [IpAddr::V4(ADDRESS)]);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn dns_lookup_timeout() {
+    // A server which never answers
+    let socket = UdpSocket::bind(SOCKET_ADDRESS).unwrap();
+    let address = socket.local_addr().unwrap();
+
+    let start = std::time::Instant::now();
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+
+        // The timeout is clamped instead of overflowing, and the paused clock advances to it
+        let mut resolver = Resolver::new(vec![address]);
+        resolver.set_timeout(Duration::MAX);
+        resolver.set_attempts(1);
+
+        let error = resolver.lookup_ip("example.test.").await.unwrap_err();
+        assert!(error == Error::ETIMEDOUT);
+    })
+    .unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    drop(socket);
+}
+
+#[test]
+fn dns_lookup_tcp_fallback() {
+    let (address, child) = start_stub_server(2, true);
+
+    lasync::run(SIZE, async move {
+        let resolver = Resolver::new(vec![address]);
+
+        let addresses = resolver.lookup_ip("example.test").await.unwrap();
+        assert_eq!(addresses, [IpAddr::V4(ADDRESS)]);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn dns_lookup_hosts_and_literals() {
+    lasync::run(SIZE, async {
+        // Without any servers, names can only be resolved from the hosts table or as literals
+        let mut resolver = Resolver::new(Vec::new());
+        resolver.add_host("stub.test", ADDRESS.into());
+
+        assert_eq!(
+            resolver.lookup_ip("STUB.test").await.unwrap(),
+            [IpAddr::V4(ADDRESS)]
+        );
+        assert_eq!(
+            resolver.lookup_ip("127.0.0.1").await.unwrap(),
+            [IpAddr::V4(Ipv4Addr::LOCALHOST)]
+        );
+    })
+    .unwrap();
+}
+
+#[test]
+fn dns_to_socket_addrs() {
+    lasync::run(SIZE, async {
+        let listener = TCPListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        assert_eq!(address.to_socket_addrs().await.unwrap(), [address]);
+        assert!("127.0.0.1".to_socket_addrs().await.is_err());
+
+        let stream = TCPStream::connect(address.to_string()).await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), address);
+    })
+    .unwrap();
+}
@@ -1,5 +1,5 @@
-use lasync::{fs::File, io::Read};
-use std::num::NonZeroUsize;
+use lasync::{fs::File, io::Read, Error, SelectResult};
+use std::{ffi::OsString, num::NonZeroUsize, time::Duration};
 
 const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
 const READ_PATH: &str = "./tests/test_data.txt";
@@ -32,3 +32,147 @@ fn file_read_full() {
     })
     .unwrap();
 }
+
+#[test]
+fn file_sync_and_resize() {
+    use lasync::{fs::Advice, io::Write};
+
+    let path = std::env::temp_dir().join(format!("lasync-sync-{}", std::process::id()));
+
+    lasync::run(SIZE, async {
+        let mut file = File::create(&path).await.unwrap();
+        file.write_all(TEST_CONTENT).await.unwrap();
+        file.sync_all().await.unwrap();
+        file.sync_data().await.unwrap();
+        file.sync_range(0, 0).await.unwrap();
+
+        file.set_len(10).await.unwrap();
+        assert_eq!(file.metadata().await.unwrap().len(), 10);
+
+        file.allocate(0, 4096).await.unwrap();
+        assert_eq!(file.metadata().await.unwrap().len(), 4096);
+
+        file.punch_hole(0, 4096).await.unwrap();
+        assert_eq!(file.metadata().await.unwrap().len(), 4096);
+
+        file.advise(Advice::Sequential, 0, 0).await.unwrap();
+    })
+    .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_lock() {
+    let path = std::env::temp_dir().join(format!("lasync-lock-{}", std::process::id()));
+
+    lasync::run(SIZE, async {
+        let first = File::create(&path).await.unwrap();
+        let second = File::open(&path).await.unwrap();
+
+        first.lock_exclusive().await.unwrap();
+        assert!(!second.try_lock_shared().unwrap());
+
+        first.unlock().unwrap();
+        second.lock_shared().await.unwrap();
+        assert!(!first.try_lock().unwrap());
+        assert!(first.try_lock_shared().unwrap());
+    })
+    .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_lock_contended() {
+    let path = std::env::temp_dir().join(format!("lasync-lock-contended-{}", std::process::id()));
+
+    lasync::run(SIZE, async {
+        let first = File::create(&path).await.unwrap();
+        let second = File::open(&path).await.unwrap();
+
+        first.lock_exclusive().await.unwrap();
+
+        // Give up on a contended lock, which must not take the lock later
+        assert!(
+            lasync::time::timeout(second.lock_exclusive(), Duration::from_millis(10))
+                .await
+                .is_err()
+        );
+
+        first.unlock().unwrap();
+        second.lock_exclusive().await.unwrap();
+        lasync::time::sleep(Duration::from_millis(100))
+            .unwrap()
+            .await;
+        assert!(!first.try_lock_shared().unwrap());
+
+        // Wait for a lock which is released while waiting
+        let unlock = async {
+            lasync::time::sleep(Duration::from_millis(10))
+                .unwrap()
+                .await;
+            second.unlock().unwrap();
+            std::future::pending::<()>().await
+        };
+        match lasync::select(first.lock_exclusive(), unlock).await {
+            SelectResult::A(result) => result.unwrap(),
+            SelectResult::B(_) => unreachable!(),
+        }
+        assert!(!second.try_lock_shared().unwrap());
+    })
+    .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn file_xattr() {
+    let path = std::env::temp_dir().join(format!("lasync-xattr-{}", std::process::id()));
+
+    lasync::run(SIZE, async {
+        let file = File::create(&path).await.unwrap();
+
+        match file.set_xattr("user.lasync.file", b"file value").await {
+            Ok(()) => {}
+            Err(error) if error == Error::ENOTSUP => {
+                println!("Extended attributes are not supported, skipping");
+                return;
+            }
+            Err(error) => panic!("{:?}", error),
+        }
+
+        assert_eq!(
+            file.get_xattr("user.lasync.file").await.unwrap(),
+            b"file value"
+        );
+        assert!(file
+            .list_xattr()
+            .unwrap()
+            .iter()
+            .any(|name| name == "user.lasync.file"));
+
+        file.remove_xattr("user.lasync.file").unwrap();
+        assert!(file.get_xattr("user.lasync.file").await.unwrap_err() == Error::ENODATA);
+
+        lasync::fs::set_xattr(&path, "user.lasync.path", b"path value")
+            .await
+            .unwrap();
+        assert_eq!(
+            lasync::fs::get_xattr(&path, "user.lasync.path")
+                .await
+                .unwrap(),
+            b"path value"
+        );
+        assert_eq!(
+            lasync::fs::list_xattr(&path).unwrap(),
+            vec![OsString::from("user.lasync.path")]
+        );
+
+        lasync::fs::remove_xattr(&path, "user.lasync.path").unwrap();
+        assert!(lasync::fs::list_xattr(&path).unwrap().is_empty());
+    })
+    .unwrap();
+
+    std::fs::remove_file(&path).unwrap();
+}
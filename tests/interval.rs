@@ -1,16 +1,13 @@
-use std::{
-    num::NonZeroUsize,
-    time::{Duration, Instant},
-};
+use lasync::time::MissedTickBehavior;
+use std::{num::NonZeroUsize, time::Duration};
 
 const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
 
 fn run_interval(count: usize, tick: Duration) {
-    let target_duration = count as f64 * tick.as_secs_f64();
-
-    let start = Instant::now();
-
     lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
         let mut interval = lasync::time::interval(tick).unwrap();
 
         for i in 0..count {
@@ -18,15 +15,136 @@ fn run_interval(count: usize, tick: Duration) {
 
             println!("Tick {}/{count}", i + 1);
         }
+
+        assert!(lasync::time::now() - start >= tick * count as u32);
     })
     .unwrap();
-
-    let end = Instant::now();
-    let duration = end.duration_since(start);
-    assert!(duration.as_secs_f64() >= target_duration);
 }
 
 #[test]
 fn interval() {
     run_interval(5, Duration::from_secs(1))
 }
+
+#[test]
+fn interval_immediate_first_tick() {
+    let period = Duration::from_millis(200);
+
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let mut interval = lasync::time::interval_at(start, period).unwrap();
+        assert_eq!(interval.period(), period);
+
+        assert_eq!(interval.tick().await, start);
+        assert_eq!(interval.tick().await, start + period);
+        assert_eq!(interval.tick().await, start + period * 2);
+        assert!(lasync::time::now() >= start + period * 2);
+    })
+    .unwrap();
+}
+
+#[test]
+fn interval_missed_tick_skip() {
+    let period = Duration::from_millis(100);
+
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+
+        let mut interval = lasync::time::interval(period).unwrap();
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        let first = interval.tick().await;
+
+        // Stall for several periods so ticks are missed
+        lasync::time::sleep(period * 3 + period / 2).unwrap().await;
+
+        let late = interval.tick().await;
+        assert_eq!(late, first + period);
+
+        // The missed ticks are dropped, leaving the next tick on the original schedule
+        let next = interval.tick().await;
+        assert_eq!(next, first + period * 4);
+    })
+    .unwrap();
+}
+
+#[test]
+fn interval_missed_tick_burst() {
+    let period = Duration::from_millis(100);
+
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let mut interval = lasync::time::interval_at(start, period).unwrap();
+        assert_eq!(interval.missed_tick_behavior(), MissedTickBehavior::Burst);
+        assert_eq!(interval.tick().await, start);
+
+        // Stall for several periods so ticks are missed
+        lasync::time::advance(period * 3 + period / 2);
+        let stalled = lasync::time::now();
+
+        // The missed ticks yield straight away, keeping the original schedule
+        assert_eq!(interval.tick().await, start + period);
+        assert_eq!(interval.tick().await, start + period * 2);
+        assert_eq!(interval.tick().await, start + period * 3);
+        assert_eq!(lasync::time::now(), stalled);
+
+        assert_eq!(interval.tick().await, start + period * 4);
+        assert!(lasync::time::now() >= start + period * 4);
+    })
+    .unwrap();
+}
+
+#[test]
+fn interval_missed_tick_delay() {
+    let period = Duration::from_millis(100);
+
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let mut interval = lasync::time::interval_at(start, period).unwrap();
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        assert_eq!(interval.tick().await, start);
+
+        // Stall for several periods so ticks are missed
+        lasync::time::advance(period * 3 + period / 2);
+        let stalled = lasync::time::now();
+
+        // The late tick yields straight away, then the schedule restarts from when it did
+        assert_eq!(interval.tick().await, start + period);
+        assert_eq!(interval.tick().await, stalled + period);
+        assert_eq!(interval.tick().await, stalled + period * 2);
+    })
+    .unwrap();
+}
+
+#[test]
+fn interval_reset() {
+    let period = Duration::from_millis(100);
+
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let mut interval = lasync::time::interval_at(start, period).unwrap();
+        assert_eq!(interval.tick().await, start);
+
+        // Resetting restarts the schedule one period from now
+        lasync::time::advance(period / 2);
+        interval.reset().unwrap();
+        assert_eq!(interval.tick().await, start + period / 2 + period);
+        assert_eq!(interval.tick().await, start + period / 2 + period * 2);
+
+        // Resetting to a deadline continues the schedule from it
+        let deadline = start + period * 10;
+        interval.reset_at(deadline).unwrap();
+        assert_eq!(interval.tick().await, deadline);
+        assert!(lasync::time::now() >= deadline);
+        assert_eq!(interval.tick().await, deadline + period);
+    })
+    .unwrap();
+}
@@ -0,0 +1,112 @@
+use common::poll_once;
+use lasync::sync::{LocalMutex, LocalRwLock};
+use std::{cell::RefCell, num::NonZeroUsize, pin::pin, rc::Rc, time::Duration};
+
+mod common;
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn mutex_fifo() {
+    let queue = lasync::FutureQueue::new();
+    let mutex = Rc::new(LocalMutex::new(Vec::new()));
+
+    let holder = mutex.clone();
+    queue.push(async move {
+        lasync::time::pause();
+
+        let mut guard = holder.lock().await;
+        assert!(holder.try_lock().is_none());
+
+        // Held across an await, so the other tasks queue up behind it
+        lasync::time::sleep(Duration::from_millis(10))
+            .unwrap()
+            .await;
+        guard.push(0);
+    });
+
+    for i in 1..4 {
+        let mutex = mutex.clone();
+        queue.push(async move {
+            mutex.lock().await.push(i);
+        });
+    }
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    assert!(!mutex.is_locked());
+    assert_eq!(*mutex.try_lock().unwrap(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn mutex_owned_guard() {
+    lasync::run(SIZE, async {
+        let mutex = Rc::new(LocalMutex::new(0));
+
+        let mut guard = mutex.clone().lock_owned().await;
+        *guard += 1;
+        assert!(mutex.clone().try_lock_owned().is_none());
+        drop(guard);
+
+        assert_eq!(*mutex.clone().try_lock_owned().unwrap(), 1);
+    })
+    .unwrap();
+}
+
+#[test]
+fn mutex_dropped_waiter() {
+    lasync::run(SIZE, async {
+        let mutex = Rc::new(LocalMutex::new(()));
+        let guard = mutex.lock().await;
+
+        // Queue a waiter which is dropped before it gets the lock
+        {
+            let mut waiter = pin!(mutex.lock());
+            assert!(poll_once(waiter.as_mut()).await.is_none());
+        }
+
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let waiting = {
+            let mutex = mutex.clone();
+            let order = order.clone();
+            async move {
+                let _guard = mutex.lock().await;
+                order.borrow_mut().push("waiter");
+            }
+        };
+        let mut waiting = pin!(waiting);
+        assert!(poll_once(waiting.as_mut()).await.is_none());
+
+        // Unlocking must reach the live waiter, skipping the dropped one
+        drop(guard);
+        waiting.await;
+        assert_eq!(*order.borrow(), ["waiter"]);
+        assert!(!mutex.is_locked());
+    })
+    .unwrap();
+}
+
+#[test]
+fn rw_lock_readers_and_writers() {
+    lasync::run(SIZE, async {
+        let lock = Rc::new(LocalRwLock::new(0));
+
+        let first = lock.read().await;
+        let second = lock.try_read().unwrap();
+        assert!(lock.try_write().is_none());
+
+        let mut writer = pin!(lock.write());
+        assert!(poll_once(writer.as_mut()).await.is_none());
+
+        // A waiting writer blocks readers which arrive after it
+        assert!(lock.try_read().is_none());
+
+        drop(first);
+        drop(second);
+
+        *writer.await += 1;
+        assert_eq!(*lock.clone().read_owned().await, 1);
+        assert!(lock.clone().try_write_owned().is_some());
+    })
+    .unwrap();
+}
@@ -0,0 +1,54 @@
+use lasync::io::{pipe, splice, stdout, tee, Pipe, Read, Write};
+use std::num::NonZeroUsize;
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const DATA: &[u8] = include_bytes!("test_data.txt");
+
+#[test]
+fn pipe_splice_tee() {
+    lasync::run(SIZE, async {
+        let file = lasync::fs::File::open("./tests/test_data.txt")
+            .await
+            .unwrap();
+
+        let first = Pipe::new().unwrap();
+        let second = Pipe::new().unwrap();
+
+        let spliced = splice(&file, first.writer(), DATA.len() as u32)
+            .await
+            .unwrap();
+        assert_eq!(spliced, DATA.len());
+
+        let duplicated = tee(first.reader(), second.writer(), DATA.len() as u32)
+            .await
+            .unwrap();
+        assert_eq!(duplicated, DATA.len());
+    })
+    .unwrap();
+}
+
+#[test]
+fn pipe_read_write() {
+    lasync::run(SIZE, async {
+        let (mut reader, mut writer) = pipe().unwrap();
+
+        writer.write_all(DATA).await.unwrap();
+        drop(writer);
+
+        let mut buffer = [0; DATA.len()];
+        reader.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+
+        assert_eq!(reader.read(&mut buffer).await.unwrap(), 0);
+    })
+    .unwrap();
+}
+
+#[test]
+fn stdout_write() {
+    lasync::run(SIZE, async {
+        stdout().write_all(b"Hello from lasync\n").await.unwrap();
+    })
+    .unwrap();
+}
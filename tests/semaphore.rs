@@ -0,0 +1,98 @@
+use common::poll_once;
+use lasync::sync::{LocalSemaphore, TryAcquireError};
+use std::{cell::Cell, num::NonZeroUsize, pin::pin, rc::Rc, time::Duration};
+
+mod common;
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn semaphore_bounds_concurrency() {
+    const TASKS: usize = 10;
+    const LIMIT: usize = 3;
+
+    let queue = lasync::FutureQueue::new();
+    let semaphore = Rc::new(LocalSemaphore::new(LIMIT));
+    let running = Rc::new(Cell::new(0));
+    let finished = Rc::new(Cell::new(0));
+
+    queue.push(async { lasync::time::pause() });
+    for _ in 0..TASKS {
+        let semaphore = semaphore.clone();
+        let running = running.clone();
+        let finished = finished.clone();
+        queue.push(async move {
+            let _permit = semaphore.acquire(1).await.unwrap();
+
+            running.set(running.get() + 1);
+            assert!(running.get() <= LIMIT);
+
+            lasync::time::sleep(Duration::from_millis(10))
+                .unwrap()
+                .await;
+
+            running.set(running.get() - 1);
+            finished.set(finished.get() + 1);
+        });
+    }
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    assert_eq!(finished.get(), TASKS);
+    assert_eq!(semaphore.available_permits(), LIMIT);
+}
+
+#[test]
+fn semaphore_weighted_fifo() {
+    lasync::run(SIZE, async {
+        let semaphore = LocalSemaphore::new(4);
+
+        let permit = semaphore.try_acquire(3).unwrap();
+        assert_eq!(permit.count(), 3);
+        assert_eq!(semaphore.available_permits(), 1);
+
+        // The large request waits at the front, so later small requests can't jump ahead of it
+        let mut large = pin!(semaphore.acquire(4));
+        assert!(poll_once(large.as_mut()).await.is_none());
+        assert!(matches!(
+            semaphore.try_acquire(1),
+            Err(TryAcquireError::NoPermits)
+        ));
+
+        drop(permit);
+        let large = large.await.unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        large.forget();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        semaphore.add_permits(2);
+        assert!(semaphore.try_acquire(2).is_ok());
+        assert_eq!(semaphore.available_permits(), 2);
+    })
+    .unwrap();
+}
+
+#[test]
+fn semaphore_close() {
+    lasync::run(SIZE, async {
+        let semaphore = Rc::new(LocalSemaphore::new(1));
+        let permit = semaphore.clone().acquire_owned(1).await.unwrap();
+
+        let mut waiting = pin!(semaphore.acquire(1));
+        assert!(poll_once(waiting.as_mut()).await.is_none());
+
+        semaphore.close();
+        assert!(semaphore.is_closed());
+        assert!(waiting.await.is_err());
+        assert!(matches!(
+            semaphore.try_acquire(1),
+            Err(TryAcquireError::Closed)
+        ));
+
+        // Permits acquired before closing are still returned
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 1);
+    })
+    .unwrap();
+}
@@ -10,24 +10,33 @@ fn one_timer() {
     let start = Instant::now();
 
     lasync::run(SIZE, async {
+        lasync::time::pause();
+        let virtual_start = lasync::time::now();
+
         println!("Hello");
 
         lasync::time::sleep(Duration::from_secs(1)).unwrap().await;
 
         println!("World!");
+
+        assert!(lasync::time::now() - virtual_start >= Duration::from_secs(1));
     })
     .unwrap();
 
-    let end = Instant::now();
-    let duration = end.duration_since(start);
-    assert!(duration.as_secs_f64() >= 1.);
+    // The paused clock advances straight to the timer instead of waiting for it
+    assert!(start.elapsed() < Duration::from_secs(1));
 }
 
 #[test]
 fn two_timers() {
     let queue = lasync::FutureQueue::new();
+    let start = std::rc::Rc::new(std::cell::Cell::new(None));
+
+    let task_start = start.clone();
+    queue.push(async move {
+        lasync::time::pause();
+        task_start.set(Some(lasync::time::now()));
 
-    queue.push(async {
         println!("Task 1 - Start");
 
         lasync::time::sleep(Duration::from_millis(1500))
@@ -37,7 +46,10 @@ fn two_timers() {
         println!("Task 1 - End");
     });
 
-    queue.push(async {
+    let task_start = start.clone();
+    queue.push(async move {
+        let start = task_start.get().unwrap();
+
         println!("Task 2 - Start");
 
         lasync::time::sleep(Duration::from_millis(500))
@@ -51,13 +63,132 @@ fn two_timers() {
             .await;
 
         println!("Task 2 - End");
+
+        assert!(lasync::time::now() - start >= Duration::from_secs(2));
     });
 
-    let start = Instant::now();
+    lasync::run_queue(SIZE, queue).unwrap();
+}
+
+#[test]
+fn sleep_until_deadline() {
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let deadline = lasync::time::now() + Duration::from_millis(500);
+
+        let sleep = lasync::time::sleep_until(deadline).unwrap();
+        assert_eq!(sleep.deadline(), deadline);
+        assert!(!sleep.is_elapsed());
+
+        sleep.await;
+        assert!(lasync::time::now() >= deadline);
+    })
+    .unwrap();
+}
+
+#[test]
+fn sleep_reset() {
+    lasync::run(SIZE, async move {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let mut sleep = std::pin::pin!(lasync::time::sleep(Duration::from_millis(100)).unwrap());
+        sleep.as_mut().await;
+        assert!(sleep.is_elapsed());
+
+        let deadline = start + Duration::from_millis(600);
+        sleep.as_mut().reset(deadline).unwrap();
+        assert!(!sleep.is_elapsed());
+
+        sleep.as_mut().await;
+        assert!(lasync::time::now() >= deadline);
+    })
+    .unwrap();
+}
+
+#[test]
+fn sleep_advance() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        assert!(lasync::time::is_paused());
+
+        let start = lasync::time::now();
+        let mut sleep = std::pin::pin!(lasync::time::sleep(Duration::from_secs(60)).unwrap());
+
+        lasync::time::advance(Duration::from_secs(30));
+        assert_eq!(lasync::time::now(), start + Duration::from_secs(30));
+        assert!(!sleep.is_elapsed());
+
+        lasync::time::advance(Duration::from_secs(30));
+        assert!(sleep.is_elapsed());
+        sleep.as_mut().await;
+
+        lasync::time::resume();
+        assert!(!lasync::time::is_paused());
+        assert!(lasync::time::now() >= start + Duration::from_secs(60));
+    })
+    .unwrap();
+}
+
+#[test]
+fn sleep_realtime_clock() {
+    let deadline = Instant::now() + Duration::from_millis(500);
+
+    lasync::run(SIZE, async move {
+        lasync::time::Sleep::with_clock(deadline, lasync::time::Clock::Realtime)
+            .unwrap()
+            .await;
+    })
+    .unwrap();
+
+    assert!(Instant::now() >= deadline);
+}
+
+#[test]
+fn many_timers() {
+    const TIMERS: u64 = 1000;
+
+    let queue = lasync::FutureQueue::new();
+    let fired = std::rc::Rc::new(std::cell::Cell::new(0));
+
+    // Far more timers than the executor has event slots
+    queue.push(async { lasync::time::pause() });
+    for i in 0..TIMERS {
+        let fired = fired.clone();
+        queue.push(async move {
+            lasync::time::sleep(Duration::from_millis(100 + i % 100))
+                .unwrap()
+                .await;
+
+            fired.set(fired.get() + 1);
+        });
+    }
 
     lasync::run_queue(SIZE, queue).unwrap();
 
-    let end = Instant::now();
-    let duration = end.duration_since(start);
-    assert!(duration.as_secs_f64() >= 2.);
+    assert_eq!(fired.get(), TIMERS);
+}
+
+#[test]
+fn sleep_max_duration() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        // Durations which would overflow an `Instant` are clamped instead of panicking
+        let forever = lasync::time::sleep(Duration::MAX).unwrap();
+        let mut interval = lasync::time::interval(Duration::MAX).unwrap();
+        interval.reset().unwrap();
+        let timer = lasync::time::Timer::new();
+        let timer_forever = timer.sleep(Duration::MAX);
+
+        let short = lasync::time::sleep(Duration::from_secs(1)).unwrap();
+        match lasync::select!(forever, timer_forever, short).await {
+            lasync::SelectResult::A(_) => panic!("a clamped sleep yielded early"),
+            lasync::SelectResult::B(()) => {}
+        }
+
+        assert!(lasync::time::now() - start < Duration::from_secs(2));
+    })
+    .unwrap();
 }
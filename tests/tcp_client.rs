@@ -0,0 +1,349 @@
+use common::poll_once;
+use lasync::{
+    io::{Read, Write},
+    net::{MessageFlags, TCPState, TCPStream},
+};
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6, TcpListener},
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+mod common;
+
+const SOCKET_ADDRESS: SocketAddr =
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0));
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const DATA: &[u8] = include_bytes!("test_data.txt");
+
+/// Starts a server which echos a single message back to the client
+fn start_echo_server() -> (SocketAddr, std::thread::JoinHandle<()>) {
+    let listener = TcpListener::bind(SOCKET_ADDRESS).unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let child = std::thread::spawn(move || {
+        use std::io::{Read, Write};
+
+        let (mut stream, _) = listener.accept().unwrap();
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).unwrap();
+        stream.write_all(&buffer).unwrap();
+    });
+
+    (address, child)
+}
+
+/// Gets an address with nothing listening on it
+fn closed_address() -> SocketAddr {
+    let listener = TcpListener::bind(SOCKET_ADDRESS).unwrap();
+    listener.local_addr().unwrap()
+}
+
+#[test]
+fn tcp_client_connect() {
+    let (address, child) = start_echo_server();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), address);
+
+        stream.write_all(DATA).await.unwrap();
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_connect_refused() {
+    let address = closed_address();
+
+    lasync::run(SIZE, async move {
+        assert!(TCPStream::connect(address).await.is_err());
+        assert!(TCPStream::connect_timeout(address, Duration::from_secs(1))
+            .await
+            .is_err());
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_client_connect_to_any() {
+    let (address, child) = start_echo_server();
+
+    let unreachable = SocketAddr::V6(SocketAddrV6::new(Ipv6Addr::LOCALHOST, 1, 0, 0));
+    let addrs = [unreachable, closed_address(), address];
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect_to_any(addrs).await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), address);
+
+        stream.write_all(DATA).await.unwrap();
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+
+    lasync::run(SIZE, async move {
+        assert!(TCPStream::connect_to_any(Vec::new()).await.is_err());
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_client_socket_options() {
+    let (address, child) = start_echo_server();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+
+        stream.set_keepalive(true).unwrap();
+        stream.set_keepalive_idle(Duration::from_secs(30)).unwrap();
+        stream
+            .set_keepalive_interval(Duration::from_secs(5))
+            .unwrap();
+        stream.set_keepalive_count(3).unwrap();
+        assert!(stream.keepalive().unwrap());
+        assert_eq!(stream.keepalive_idle().unwrap(), Duration::from_secs(30));
+        assert_eq!(stream.keepalive_interval().unwrap(), Duration::from_secs(5));
+        assert_eq!(stream.keepalive_count().unwrap(), 3);
+
+        assert_eq!(stream.linger().unwrap(), None);
+        stream.set_linger(Some(Duration::from_secs(1))).unwrap();
+        assert_eq!(stream.linger().unwrap(), Some(Duration::from_secs(1)));
+
+        stream.set_ttl(42).unwrap();
+        assert_eq!(stream.ttl().unwrap(), 42);
+
+        stream
+            .set_user_timeout(Duration::from_millis(1500))
+            .unwrap();
+        assert_eq!(stream.user_timeout().unwrap(), Duration::from_millis(1500));
+
+        stream.set_cork(true).unwrap();
+        assert!(stream.cork().unwrap());
+        stream.set_cork(false).unwrap();
+
+        stream.set_quickack(true).unwrap();
+        assert!(!stream.congestion().unwrap().is_empty());
+        assert!(stream.take_error().unwrap().is_none());
+
+        stream.write_all(DATA).await.unwrap();
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+
+        let info = stream.tcp_info().unwrap();
+        assert_eq!(info.state(), TCPState::Established);
+        assert!(info.bytes_acked() > 0);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_split() {
+    let (address, child) = start_echo_server();
+    let other_listener = TcpListener::bind(SOCKET_ADDRESS).unwrap();
+    let other_address = other_listener.local_addr().unwrap();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+
+        let (mut reader, mut writer) = stream.split();
+        writer.write_all(DATA).await.unwrap();
+
+        let mut buffer = [0; DATA.len()];
+        reader.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+
+        let (reader, writer) = stream.into_split();
+        let (other_reader, other_writer) = TCPStream::connect(other_address)
+            .await
+            .unwrap()
+            .into_split();
+
+        let error = reader.reunite(other_writer).unwrap_err();
+        let stream = error.0.reunite(writer).unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), address);
+
+        drop(other_reader);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_into_split_tasks() {
+    let (address, child) = start_echo_server();
+
+    let queue = lasync::FutureQueue::new();
+    let tasks = queue.clone();
+    queue.push(async move {
+        let stream = TCPStream::connect(address).await.unwrap();
+        let (mut reader, mut writer) = stream.into_split();
+
+        tasks.push(async move {
+            let mut buffer = [0; DATA.len()];
+            reader.read_exact(&mut buffer).await.unwrap();
+            assert_eq!(buffer, DATA);
+        });
+
+        tasks.push(async move {
+            writer.write_all(DATA).await.unwrap();
+        });
+    });
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_send_zc() {
+    let (address, child) = start_echo_server();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+
+        let mut buf = DATA.to_vec();
+        while !buf.is_empty() {
+            let (sent, returned) = stream.send_zc(buf).await;
+            let sent = sent.unwrap();
+            assert!(sent > 0);
+            buf = returned[sent..].to_vec();
+        }
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_send_zc_drop() {
+    let (address, child) = start_echo_server();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+
+        // Drop the send after the bytes have been sent, which can be before the kernel releases the
+        // buffer. The executor only stops once the buffer has been released.
+        let mut send = stream.send_zc(DATA.to_vec());
+        assert!(poll_once(&mut send).await.is_none());
+        lasync::time::sleep(Duration::from_millis(10))
+            .unwrap()
+            .await;
+        drop(send);
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_peek() {
+    let (address, child) = start_echo_server();
+
+    lasync::run(SIZE, async move {
+        let mut stream = TCPStream::connect(address).await.unwrap();
+
+        let mut buf = DATA;
+        while !buf.is_empty() {
+            let sent = stream
+                .send_with_flags(buf, MessageFlags::NOSIGNAL)
+                .await
+                .unwrap();
+            buf = &buf[sent..];
+        }
+
+        // Peeking leaves the data in the receive queue
+        let mut peeked = [0; DATA.len()];
+        let length = stream.peek(&mut peeked).await.unwrap();
+        assert!(length > 0);
+        assert_eq!(peeked[..length], DATA[..length]);
+
+        let mut peeked = [0; DATA.len()];
+        let length = stream
+            .recv_with_flags(&mut peeked, MessageFlags::PEEK | MessageFlags::WAITALL)
+            .await
+            .unwrap();
+        assert_eq!(length, DATA.len());
+        assert_eq!(peeked, DATA);
+
+        let mut buffer = [0; DATA.len()];
+        stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    child.join().unwrap();
+}
+
+#[test]
+fn tcp_client_msg() {
+    lasync::run(SIZE, async {
+        let listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let mut client = TCPStream::connect(address).await.unwrap();
+        let (mut server, _) = listener.accept().await.unwrap();
+
+        let sent = client
+            .send_msg(DATA, &[], MessageFlags::NOSIGNAL)
+            .await
+            .unwrap();
+        assert_eq!(sent, DATA.len());
+
+        let mut buffer = [0; DATA.len()];
+        let info = server
+            .recv_msg(&mut buffer, &mut [], MessageFlags::WAITALL)
+            .await
+            .unwrap();
+        assert_eq!(info.length(), DATA.len());
+        assert_eq!(info.control_length(), 0);
+        assert!(!info.flags().contains(MessageFlags::TRUNC));
+        assert!(!info.flags().contains(MessageFlags::CTRUNC));
+        assert_eq!(buffer, DATA);
+
+        // Urgent data is received out of band, separately from the rest of the stream
+        client
+            .send_with_flags(b"!", MessageFlags::OOB)
+            .await
+            .unwrap();
+        client.write_all(b"x").await.unwrap();
+
+        let mut urgent = [0; 1];
+        let length = server
+            .recv_with_flags(&mut urgent, MessageFlags::OOB)
+            .await
+            .unwrap();
+        assert_eq!(&urgent[..length], b"!");
+
+        let mut buffer = [0; 1];
+        server.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"x");
+    })
+    .unwrap();
+}
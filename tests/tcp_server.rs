@@ -13,7 +13,9 @@ const DATA: &[u8] = include_bytes!("test_data.txt");
 #[test]
 fn tcp_server_accept() {
     lasync::run(SIZE, async {
-        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS).unwrap();
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
         let address = tcp_listener.local_addr().unwrap();
 
         let child = std::thread::spawn(move || tcp_server_accept_client(address));
@@ -47,7 +49,9 @@ fn tcp_server_read() {
     use futures::io::Read;
 
     lasync::run(SIZE, async {
-        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS).unwrap();
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
         let address = tcp_listener.local_addr().unwrap();
 
         let child = std::thread::spawn(move || tcp_server_read_client(address));
@@ -86,7 +90,9 @@ fn tcp_server_write() {
     use futures::io::Write;
 
     lasync::run(SIZE, async {
-        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS).unwrap();
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
         let address = tcp_listener.local_addr().unwrap();
 
         let child = std::thread::spawn(move || tcp_server_write_client(address));
@@ -120,3 +126,164 @@ fn tcp_server_write_client(address: SocketAddr) {
 
     println!("{}", String::from_utf8_lossy(&buffer));
 }
+
+#[test]
+fn tcp_server_send_file() {
+    lasync::run(SIZE, async {
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+
+        let child = std::thread::spawn(move || tcp_server_write_client(address));
+
+        let (mut stream, _) = tcp_listener.accept().await.unwrap();
+
+        let file = lasync::fs::File::open("./tests/test_data.txt")
+            .await
+            .unwrap();
+        let sent = stream.send_file(&file, 0, DATA.len() as u64).await.unwrap();
+
+        assert_eq!(sent, DATA.len() as u64);
+
+        child.join().unwrap();
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_server_incoming() {
+    use lasync::Iterator;
+
+    const CLIENTS: usize = 8;
+
+    lasync::run(SIZE, async {
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+
+        let child = std::thread::spawn(move || {
+            (0..CLIENTS)
+                .map(|_| std::net::TcpStream::connect(address).unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        let mut incoming = tcp_listener.incoming();
+        let mut addresses = Vec::new();
+        for _ in 0..CLIENTS {
+            let (stream, address) = Iterator::next(&mut incoming).await.unwrap().unwrap();
+            assert_eq!(stream.peer_addr().unwrap(), address);
+            addresses.push(address);
+        }
+
+        let clients = child.join().unwrap();
+        for client in clients {
+            assert!(addresses.contains(&client.local_addr().unwrap()));
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_server_incoming_drop() {
+    use lasync::Iterator;
+    use std::{io::Read, time::Duration};
+
+    lasync::run(SIZE, async {
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+
+        // Arm the multishot accept, then drop it while it is still waiting
+        let mut incoming = tcp_listener.incoming();
+        assert!(
+            lasync::time::timeout(Iterator::next(&mut incoming), Duration::from_millis(10))
+                .await
+                .is_err()
+        );
+        drop(incoming);
+
+        let child = std::thread::spawn(move || {
+            let mut client = std::net::TcpStream::connect(address).unwrap();
+            client
+                .set_read_timeout(Some(Duration::from_secs(5)))
+                .unwrap();
+            client.read(&mut [0; 16]).unwrap()
+        });
+
+        // The cancelled accept must not take the connection
+        let (stream, _) = tcp_listener.accept().await.unwrap();
+        drop(stream);
+
+        assert_eq!(child.join().unwrap(), 0);
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_server_deadline() {
+    use lasync::time::DeadlineError;
+    use std::time::{Duration, Instant};
+
+    lasync::run(SIZE, async {
+        let tcp_listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = tcp_listener.local_addr().unwrap();
+
+        let deadline = Instant::now() + Duration::from_millis(100);
+        let error = tcp_listener
+            .accept_with_deadline(deadline)
+            .await
+            .unwrap_err();
+        assert!(error == DeadlineError::Elapsed);
+        assert!(Instant::now() >= deadline);
+
+        // Deadlines are measured against the executor's clock, but count down in real time
+        lasync::time::pause();
+        lasync::time::advance(Duration::from_secs(60 * 60));
+        let start = Instant::now();
+        let deadline = lasync::time::now() + Duration::from_millis(100);
+        let error = tcp_listener
+            .accept_with_deadline(deadline)
+            .await
+            .unwrap_err();
+        assert!(error == DeadlineError::Elapsed);
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        lasync::time::resume();
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let child = std::thread::spawn(move || tcp_server_deadline_client(address, receiver));
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let (mut stream, _) = tcp_listener.accept_with_deadline(deadline).await.unwrap();
+
+        // The client never sends anything, so the read is cancelled at the deadline
+        let mut buffer = [0; 16];
+        let deadline = Instant::now() + Duration::from_millis(100);
+        let error = stream
+            .read_with_deadline(&mut buffer, deadline)
+            .await
+            .unwrap_err();
+        assert!(error == DeadlineError::Elapsed);
+
+        sender.send(()).unwrap();
+        child.join().unwrap();
+
+        // Completing before the deadline yields the result as normal
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let read = stream.read_with_deadline(&mut buffer, deadline).await;
+        assert!(read == Ok(0));
+    })
+    .unwrap();
+}
+
+fn tcp_server_deadline_client(address: SocketAddr, receiver: std::sync::mpsc::Receiver<()>) {
+    let stream = std::net::TcpStream::connect(address).unwrap();
+
+    receiver.recv().unwrap();
+    drop(stream);
+}
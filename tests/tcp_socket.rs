@@ -0,0 +1,82 @@
+use lasync::net::{TCPSocket, TCPStream};
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::NonZeroUsize,
+};
+
+const SOCKET_ADDRESS: SocketAddr =
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0));
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn tcp_socket_reuse_port() {
+    lasync::run(SIZE, async {
+        let mut first = TCPSocket::new_v4().unwrap();
+        first.set_reuse_port(true).unwrap();
+        assert!(first.reuse_port().unwrap());
+        first.bind(SOCKET_ADDRESS).unwrap();
+        let address = first.local_addr().unwrap();
+
+        let mut second = TCPSocket::new_for_addr(address).unwrap();
+        second.set_reuse_port(true).unwrap();
+        second.bind(address).unwrap();
+
+        let first = first.listen(16).unwrap();
+        let second = second.listen(16).unwrap();
+        assert_eq!(first.local_addr().unwrap(), second.local_addr().unwrap());
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_socket_dual_stack() {
+    lasync::run(SIZE, async {
+        let mut socket = TCPSocket::new_v6().unwrap();
+        socket.set_only_v6(false).unwrap();
+        assert!(!socket.only_v6().unwrap());
+        socket
+            .bind(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::UNSPECIFIED,
+                0,
+                0,
+                0,
+            )))
+            .unwrap();
+
+        let listener = socket.listen(1).unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let stream =
+            TCPStream::connect(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, port)))
+                .await
+                .unwrap();
+        let (_, address) = listener.accept().await.unwrap();
+
+        let mapped = Ipv4Addr::LOCALHOST.to_ipv6_mapped();
+        assert_eq!(address.ip(), mapped);
+        assert_eq!(address.port(), stream.local_addr().unwrap().port());
+    })
+    .unwrap();
+}
+
+#[test]
+fn tcp_socket_connect() {
+    lasync::run(SIZE, async {
+        let listener = lasync::net::TCPListener::bind(SOCKET_ADDRESS)
+            .await
+            .unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let mut socket = TCPSocket::new_for_addr(address).unwrap();
+        socket.set_recv_buffer_size(65536).unwrap();
+        socket.set_send_buffer_size(65536).unwrap();
+        assert!(socket.recv_buffer_size().unwrap() >= 65536);
+        assert!(socket.send_buffer_size().unwrap() >= 65536);
+
+        let stream = socket.connect(address).await.unwrap();
+        let (_, peer) = listener.accept().await.unwrap();
+        assert_eq!(stream.local_addr().unwrap(), peer);
+    })
+    .unwrap();
+}
@@ -0,0 +1,53 @@
+use lasync::time::{Timeout, TimeoutExt};
+use std::{
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn timeout_elapsed() {
+    lasync::run(SIZE, async {
+        let deadline = Instant::now() + Duration::from_millis(50);
+        let elapsed = std::future::pending::<()>()
+            .deadline(deadline)
+            .await
+            .unwrap_err();
+        assert_eq!(elapsed.deadline(), deadline);
+        assert!(Instant::now() >= deadline);
+    })
+    .unwrap();
+}
+
+#[test]
+fn timeout_ready() {
+    lasync::run(SIZE, async {
+        // An output which is itself an option stays distinguishable from the timeout
+        let value = async { None::<u32> }.timeout(Duration::from_secs(1)).await;
+        assert_eq!(value, Ok(None));
+
+        let sleep = lasync::time::sleep(Duration::from_millis(10)).unwrap();
+        assert!(Timeout::new(sleep, Duration::from_secs(10)).await.is_ok());
+    })
+    .unwrap();
+}
+
+#[test]
+fn timeout_max_duration() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+
+        // Timeouts which would overflow an `Instant` are clamped instead of panicking
+        let timeout = async { 1 }.timeout(Duration::MAX);
+        assert_eq!(
+            timeout.deadline(),
+            lasync::time::now() + lasync::platform::TimerWheel::MAX_DURATION
+        );
+        assert_eq!(timeout.await, Ok(1));
+
+        let sleep = lasync::time::sleep(Duration::from_millis(10)).unwrap();
+        assert!(Timeout::new(sleep, Duration::MAX).await.is_ok());
+    })
+    .unwrap();
+}
@@ -0,0 +1,114 @@
+use lasync::{time::Timer, Error};
+use std::{
+    cell::Cell,
+    num::NonZeroUsize,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+#[test]
+fn timer_cancel_all() {
+    lasync::run(SIZE, async {
+        let timer = Timer::new();
+
+        let short = timer.sleep(Duration::from_millis(10));
+        let long = timer.timeout(
+            lasync::time::sleep(Duration::from_millis(200)).unwrap(),
+            Duration::from_secs(10),
+        );
+        assert_eq!(timer.pending(), 2);
+
+        short.await.unwrap();
+        assert_eq!(timer.pending(), 1);
+
+        // The cancelled timeout no longer limits its future
+        timer.cancel_all();
+        assert_eq!(timer.pending(), 0);
+        assert!(long.await.is_ok());
+    })
+    .unwrap();
+}
+
+#[test]
+fn timer_cancel_all_wakes() {
+    let queue = lasync::FutureQueue::new();
+    let cancelled = Rc::new(Cell::new(0));
+
+    let task_queue = queue.clone();
+    let task_cancelled = cancelled.clone();
+    queue.push(async move {
+        lasync::time::pause();
+        let timer = Rc::new(Timer::new());
+
+        let task_timer = timer.clone();
+        let cancelled = task_cancelled.clone();
+        task_queue.push(async move {
+            let mut sleep = task_timer.sleep(Duration::from_secs(60));
+            assert_eq!((&mut sleep).await, Err(Error::ECANCELED));
+            assert!(sleep.is_cancelled());
+
+            // A reset sleep yields normally again
+            sleep.reset(task_timer.now() + Duration::from_millis(10));
+            sleep.await.unwrap();
+
+            cancelled.set(cancelled.get() + 1);
+        });
+
+        let task_timer = timer.clone();
+        let cancelled = task_cancelled;
+        task_queue.push(async move {
+            let mut interval = task_timer.interval(Duration::from_secs(60));
+            assert_eq!(interval.tick().await, Err(Error::ECANCELED));
+            assert_eq!(interval.tick().await, Err(Error::ECANCELED));
+
+            interval.reset();
+            interval.tick().await.unwrap();
+
+            cancelled.set(cancelled.get() + 1);
+        });
+
+        // Cancel the timers while both tasks are waiting on them
+        lasync::time::sleep(Duration::from_millis(10))
+            .unwrap()
+            .await;
+
+        timer.cancel_all();
+        assert_eq!(timer.pending(), 0);
+    });
+
+    lasync::run_queue(SIZE, queue).unwrap();
+
+    assert_eq!(cancelled.get(), 2);
+}
+
+#[test]
+fn timer_pause_advance() {
+    let start = Instant::now();
+
+    lasync::run(SIZE, async {
+        let timer = Timer::new();
+        let mut sleep = timer.sleep(Duration::from_secs(60));
+
+        timer.pause();
+        assert!(timer.is_paused());
+
+        let paused_at = timer.now();
+        timer.advance(Duration::from_secs(30));
+        assert_eq!(timer.now(), paused_at + Duration::from_secs(30));
+        assert!(!sleep.is_elapsed());
+
+        timer.advance(Duration::from_secs(30));
+        assert!(sleep.is_elapsed());
+        (&mut sleep).await.unwrap();
+
+        // A reset sleep waits for the clock to be resumed
+        sleep.reset(timer.now() + Duration::from_millis(10));
+        timer.resume();
+        sleep.await.unwrap();
+    })
+    .unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
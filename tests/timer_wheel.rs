@@ -0,0 +1,186 @@
+use lasync::{
+    platform::{TimerKey, TimerWheel},
+    EventManager,
+};
+use std::{
+    num::NonZeroUsize,
+    time::{Duration, Instant},
+};
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const TICK: Duration = Duration::from_millis(1);
+
+fn timers<T, F: FnOnce(&mut TimerWheel) -> T>(f: F) -> T {
+    EventManager::get_local_mut(|manager| f(manager.timers_mut()))
+}
+
+fn advance_to(instant: Instant) {
+    lasync::time::advance(instant - lasync::time::now())
+}
+
+fn is_fired(key: TimerKey) -> bool {
+    timers(|timers| timers.is_fired(key))
+}
+
+/// Checks that the timer for `key` fires at `deadline` and not before
+fn assert_fires_at(key: TimerKey, deadline: Instant) {
+    advance_to(deadline - TICK);
+    assert!(!is_fired(key));
+
+    advance_to(deadline + TICK);
+    assert!(is_fired(key));
+}
+
+#[test]
+fn timer_wheel_cascade() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        // Each deadline starts out in a higher level and is cascaded down as it approaches
+        let deadlines = [
+            Duration::from_millis(4097),
+            Duration::from_secs(10),
+            Duration::from_secs(5 * 60),
+            Duration::from_secs(6 * 60 * 60),
+            Duration::from_secs(20 * 24 * 60 * 60),
+        ]
+        .map(|duration| start + duration);
+
+        let keys = deadlines.map(|deadline| timers(|timers| timers.insert(deadline)));
+        assert_eq!(timers(|timers| timers.len()), deadlines.len());
+
+        for (i, (key, deadline)) in keys.into_iter().zip(deadlines).enumerate() {
+            assert_fires_at(key, deadline);
+
+            // Later timers aren't fired by cascading earlier ones
+            assert!(keys[i + 1..].iter().all(|key| !is_fired(*key)));
+            assert_eq!(timers(|timers| timers.len()), deadlines.len() - i - 1);
+        }
+
+        for key in keys {
+            timers(|timers| timers.remove(key));
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn timer_wheel_cascade_jump() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let near = timers(|timers| timers.insert(start + Duration::from_secs(70)));
+        let far = timers(|timers| timers.insert(start + Duration::from_secs(90 * 60)));
+
+        // Advancing past several levels at once fires everything passed and nothing else
+        advance_to(start + Duration::from_secs(60 * 60));
+        assert!(is_fired(near));
+        assert!(!is_fired(far));
+
+        assert_fires_at(far, start + Duration::from_secs(90 * 60));
+
+        timers(|timers| {
+            timers.remove(near);
+            timers.remove(far);
+        });
+    })
+    .unwrap();
+}
+
+#[test]
+fn timer_wheel_reset_pending() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let earlier = timers(|timers| timers.insert(start + Duration::from_secs(10)));
+        let later = timers(|timers| timers.insert(start + Duration::from_secs(1)));
+
+        // Move a pending timer to a lower level and another to a higher level
+        timers(|timers| {
+            timers.reset(earlier, start + Duration::from_millis(500));
+            timers.reset(later, start + Duration::from_secs(100));
+        });
+        assert_eq!(timers(|timers| timers.len()), 2);
+
+        assert_fires_at(earlier, start + Duration::from_millis(500));
+
+        // The old deadline no longer fires it
+        advance_to(start + Duration::from_secs(5));
+        assert!(!is_fired(later));
+        assert_fires_at(later, start + Duration::from_secs(100));
+
+        // A fired timer can be reset to fire again
+        let deadline = start + Duration::from_secs(200);
+        timers(|timers| timers.reset(later, deadline));
+        assert!(!is_fired(later));
+        assert_fires_at(later, deadline);
+
+        timers(|timers| {
+            timers.remove(earlier);
+            timers.remove(later);
+        });
+        assert_eq!(timers(|timers| timers.len()), 0);
+    })
+    .unwrap();
+}
+
+#[test]
+fn timer_wheel_remove_pending() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        let removed = timers(|timers| timers.insert(start + Duration::from_secs(10)));
+        let kept = timers(|timers| timers.insert(start + Duration::from_secs(10)));
+
+        timers(|timers| timers.remove(removed));
+        assert_eq!(timers(|timers| timers.len()), 1);
+
+        // The freed entry is reused without the old key referring to the new timer
+        let reused = timers(|timers| timers.insert(start + Duration::from_secs(1)));
+        advance_to(start + Duration::from_secs(2));
+        assert!(is_fired(reused));
+        assert!(!is_fired(removed));
+
+        // Removing a timer doesn't disturb others in the same slot
+        assert_fires_at(kept, start + Duration::from_secs(10));
+        assert!(!is_fired(removed));
+        assert_eq!(timers(|timers| timers.len()), 0);
+
+        timers(|timers| {
+            timers.remove(reused);
+            timers.remove(kept);
+        });
+    })
+    .unwrap();
+}
+
+#[test]
+fn timer_wheel_max_ticks() {
+    lasync::run(SIZE, async {
+        lasync::time::pause();
+        let start = lasync::time::now();
+
+        // Deadlines beyond the end of the wheel are moved down once it reaches them
+        let deadline = start + TimerWheel::MAX_DURATION * 3 / 2;
+        let key = timers(|timers| timers.insert(deadline));
+
+        advance_to(start + TimerWheel::MAX_DURATION);
+        assert!(!is_fired(key));
+        assert_eq!(timers(|timers| timers.len()), 1);
+
+        assert_fires_at(key, deadline);
+
+        // Far deadlines also fire when reached in a single step
+        let deadline = lasync::time::now() + TimerWheel::MAX_DURATION * 3;
+        timers(|timers| timers.reset(key, deadline));
+        assert_fires_at(key, deadline);
+
+        timers(|timers| timers.remove(key));
+    })
+    .unwrap();
+}
@@ -0,0 +1,93 @@
+use lasync::{net::UDPSocket, Iterator};
+use std::{
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket},
+    num::NonZeroUsize,
+};
+
+const SOCKET_ADDRESS: SocketAddr =
+    SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 0));
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const DATA: &[u8] = b"Hello, datagram!";
+
+#[test]
+fn udp_send_to_recv_from() {
+    lasync::run(SIZE, async {
+        let a = UDPSocket::bind(SOCKET_ADDRESS).await.unwrap();
+        let b = UDPSocket::bind(SOCKET_ADDRESS).await.unwrap();
+        let a_address = a.local_addr().unwrap();
+        let b_address = b.local_addr().unwrap();
+
+        assert_eq!(a.send_to(DATA, b_address).await.unwrap(), DATA.len());
+
+        let mut buffer = [0; 64];
+        let (length, from) = b.recv_from(&mut buffer).await.unwrap();
+        assert_eq!(&buffer[..length], DATA);
+        assert_eq!(from, a_address);
+    })
+    .unwrap();
+}
+
+#[test]
+fn udp_connected_send_recv() {
+    let peer = UdpSocket::bind(SOCKET_ADDRESS).unwrap();
+    let peer_address = peer.local_addr().unwrap();
+
+    lasync::run(SIZE, async move {
+        let mut socket = UDPSocket::bind(SOCKET_ADDRESS).await.unwrap();
+        socket.connect(peer_address).await.unwrap();
+        assert_eq!(socket.peer_addr().unwrap(), peer_address);
+
+        socket.send(DATA).await.unwrap();
+
+        let mut buffer = [0; 64];
+        let (length, from) = peer.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[..length], DATA);
+
+        peer.send_to(&buffer[..length], from).unwrap();
+
+        let mut buffer = [0; 64];
+        let length = socket.recv(&mut buffer).await.unwrap();
+        assert_eq!(&buffer[..length], DATA);
+    })
+    .unwrap();
+}
+
+#[test]
+fn udp_recv_multishot() {
+    const COUNT: u8 = 16;
+
+    let sender = UdpSocket::bind(SOCKET_ADDRESS).unwrap();
+    let sender_address = sender.local_addr().unwrap();
+
+    lasync::run(SIZE, async move {
+        let socket = UDPSocket::bind(SOCKET_ADDRESS).await.unwrap();
+        let address = socket.local_addr().unwrap();
+
+        let mut datagrams = socket.recv_multishot(4, 64).unwrap();
+
+        for i in 0..COUNT {
+            sender.send_to(&[i; 8], address).unwrap();
+        }
+
+        for i in 0..COUNT {
+            let (datagram, from) = Iterator::next(&mut datagrams).await.unwrap().unwrap();
+            assert_eq!(datagram, [i; 8]);
+            assert_eq!(from, sender_address);
+        }
+    })
+    .unwrap();
+}
+
+#[test]
+fn udp_broadcast() {
+    lasync::run(SIZE, async {
+        let mut socket = UDPSocket::bind(SOCKET_ADDRESS).await.unwrap();
+
+        assert!(!socket.broadcast().unwrap());
+        socket.set_broadcast(true).unwrap();
+        assert!(socket.broadcast().unwrap());
+    })
+    .unwrap();
+}
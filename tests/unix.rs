@@ -0,0 +1,131 @@
+use lasync::{
+    io::{Read, Write},
+    net::unix::{UnixDatagram, UnixListener, UnixSocketAddr, UnixStream},
+};
+use std::{
+    fs::File,
+    io::{Read as _, Seek, SeekFrom, Write as _},
+    num::NonZeroUsize,
+    os::fd::AsRawFd,
+    path::PathBuf,
+};
+
+const SIZE: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(32) };
+
+const DATA: &[u8] = include_bytes!("test_data.txt");
+
+/// Gets a unique path in the temporary directory for a socket
+fn socket_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("lasync-{}-{}.sock", name, std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn unix_stream_pathname() {
+    let path = socket_path("stream");
+
+    lasync::run(SIZE, async {
+        let listener = UnixListener::bind(path.as_path()).unwrap();
+        assert_eq!(
+            listener.local_addr().unwrap(),
+            UnixSocketAddr::Pathname(path.clone())
+        );
+
+        let mut client = UnixStream::connect(path.as_path()).await.unwrap();
+        let (mut server, client_address) = listener.accept().await.unwrap();
+        assert!(client_address.is_unnamed());
+
+        let credentials = server.peer_cred().unwrap();
+        assert_eq!(credentials.pid(), std::process::id() as i32);
+
+        client.write_all(DATA).await.unwrap();
+
+        let mut buffer = [0; DATA.len()];
+        server.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(buffer, DATA);
+    })
+    .unwrap();
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn unix_stream_abstract() {
+    let address =
+        UnixSocketAddr::from_abstract_name(format!("lasync-abstract-{}", std::process::id()));
+
+    lasync::run(SIZE, async {
+        let listener = UnixListener::bind(address.clone()).unwrap();
+        assert_eq!(listener.local_addr().unwrap(), address);
+
+        let client = UnixStream::connect(address.clone()).await.unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+
+        assert_eq!(client.peer_addr().unwrap(), address);
+        assert_eq!(server.local_addr().unwrap(), address);
+    })
+    .unwrap();
+}
+
+#[test]
+fn unix_stream_pass_fd() {
+    let mut file = tempfile();
+    file.write_all(DATA).unwrap();
+
+    lasync::run(SIZE, async move {
+        let (mut a, mut b) = UnixStream::pair().unwrap();
+
+        a.send_with_fds(b"f", &[file.as_raw_fd()]).await.unwrap();
+        drop(file);
+
+        let mut buffer = [0; 1];
+        let (length, mut fds) = b.recv_with_fds(&mut buffer, 4).await.unwrap();
+        assert_eq!(length, 1);
+        assert_eq!(fds.len(), 1);
+
+        let mut file = File::from(fds.pop().unwrap());
+
+        let mut contents = Vec::new();
+        file.seek(SeekFrom::Start(0)).unwrap();
+        file.read_to_end(&mut contents).unwrap();
+        assert_eq!(contents, DATA);
+    })
+    .unwrap();
+}
+
+#[test]
+fn unix_datagram() {
+    let a_path = socket_path("datagram-a");
+    let b_path = socket_path("datagram-b");
+
+    lasync::run(SIZE, async {
+        let a = UnixDatagram::bind(a_path.as_path()).unwrap();
+        let b = UnixDatagram::bind(b_path.as_path()).unwrap();
+
+        a.send_to(b"hello", b_path.as_path()).await.unwrap();
+
+        let mut buffer = [0; 16];
+        let (length, from) = b.recv_from(&mut buffer).await.unwrap();
+        assert_eq!(&buffer[..length], b"hello");
+        assert_eq!(from, UnixSocketAddr::Pathname(a_path.clone()));
+    })
+    .unwrap();
+
+    std::fs::remove_file(a_path).unwrap();
+    std::fs::remove_file(b_path).unwrap();
+}
+
+/// Creates an anonymous temporary file
+fn tempfile() -> File {
+    let path = std::env::temp_dir().join(format!("lasync-fd-{}", std::process::id()));
+    let file = File::options()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    std::fs::remove_file(path).unwrap();
+    file
+}